@@ -5,34 +5,90 @@ use axum::{
     routing::get,
     Router,
 };
-use calendar_core::{Calendar, LiturgicalDay};
+use calendar_core::designation::{Designation, DesignationError};
+use calendar_core::i18n::{self, Locale};
+use calendar_core::julian;
+use calendar_core::latin::{self, LatinStyle};
+use calendar_core::occurrences;
+use calendar_core::{Calendar, CalendarOptions, LiturgicalDay, OrationRegistry};
 use chrono::{Datelike, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
-/// Shared calendar cache (year -> Calendar)
+/// Shared calendar cache ((year, Latin style) -> Calendar)
+#[derive(Default)]
 pub struct AppState {
-    cache: Mutex<HashMap<i32, Calendar>>,
+    cache: Mutex<HashMap<(i32, LatinStyle), Arc<Calendar>>>,
+    /// Anniversaries and commanded collects of the diocese served
+    orations: OrationRegistry,
 }
 
 impl AppState {
     pub fn new() -> Self {
+        Self::with_orations(OrationRegistry::default())
+    }
+
+    pub fn with_orations(orations: OrationRegistry) -> Self {
         Self {
             cache: Mutex::new(HashMap::new()),
+            orations,
+        }
+    }
+
+    fn get_calendar(&self, year: i32, latin: LatinStyle) -> Arc<Calendar> {
+        if let Some(cal) = self.cache.lock().unwrap().get(&(year, latin)) {
+            return cal.clone();
         }
+        // Built without holding the lock; a concurrent build of the same
+        // year is discarded
+        let cal = Arc::new(Calendar::with_options(year, CalendarOptions { latin, ..self.options() }));
+        self.cache.lock().unwrap().entry((year, latin)).or_insert(cal).clone()
     }
 
-    fn get_calendar(&self, year: i32) -> Calendar {
-        let mut cache = self.cache.lock().unwrap();
-        cache.entry(year).or_insert_with(|| Calendar::new(year));
-        Calendar::new(year)
+    /// Calendar options for the diocese served
+    fn options(&self) -> CalendarOptions {
+        CalendarOptions { orations: self.orations.clone(), ..Default::default() }
     }
 }
 
+/// Read an oration registry from a JSON file, e.g.
+/// `{"anniversaries": [{"of": "pope_election", "month": 5, "day": 8}]}`
+pub fn load_orations(path: &str) -> Result<OrationRegistry, Box<dyn std::error::Error>> {
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+/// Serve reading texts from complete `vulgate.txt` and `douay.txt` files
+/// in `dir` instead of the partial embedded corpus
+pub fn load_bible(dir: &str) -> Result<(), Box<dyn std::error::Error>> {
+    Ok(calendar_data::bible::load_dir(dir)?)
+}
+
 #[derive(Deserialize)]
 pub struct TodayQuery {
     tz: Option<String>,
+    explain: Option<bool>,
+    lang: Option<String>,
+    latin: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct DayQuery {
+    explain: Option<bool>,
+    lang: Option<String>,
+    latin: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ResolveQuery {
+    q: String,
+    tz: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct RangeQuery {
+    from: Option<i32>,
+    to: Option<i32>,
 }
 
 #[derive(Serialize)]
@@ -59,23 +115,86 @@ fn resolve_today(tz_str: &str) -> Result<NaiveDate, (StatusCode, Json<serde_json
     }
 }
 
-fn season_display_name(s: calendar_core::LiturgicalSeason) -> &'static str {
-    match s {
-        calendar_core::LiturgicalSeason::Advent => "Advent",
-        calendar_core::LiturgicalSeason::Christmas => "Christmastide",
-        calendar_core::LiturgicalSeason::AfterEpiphany => "Time after Epiphany",
-        calendar_core::LiturgicalSeason::Septuagesima => "Septuagesima",
-        calendar_core::LiturgicalSeason::Lent => "Lent",
-        calendar_core::LiturgicalSeason::Passiontide => "Passiontide",
-        calendar_core::LiturgicalSeason::HolyWeek => "Holy Week",
-        calendar_core::LiturgicalSeason::Easter => "Eastertide",
-        calendar_core::LiturgicalSeason::Ascensiontide => "Ascensiontide",
-        calendar_core::LiturgicalSeason::AfterPentecost => "Time after Pentecost",
+fn resolve_locale(lang: Option<&str>) -> Result<Locale, (StatusCode, Json<serde_json::Value>)> {
+    match lang {
+        None => Ok(Locale::En),
+        Some(code) => code.parse().map_err(|e: String| bad_request("invalid_lang", e)),
+    }
+}
+
+/// Last year served: beyond it the date arithmetic leaves chrono's range
+const LAST_YEAR: i32 = 9999;
+
+/// Most years one occurrences query may span
+const MAX_RANGE_YEARS: i32 = 50;
+
+/// The Roman calendar is computed from the first Gregorian year; earlier
+/// years are served only by /easter, which gives the Julian reckoning
+fn gregorian_year(year: i32) -> Result<i32, (StatusCode, Json<serde_json::Value>)> {
+    if year < julian::FIRST_GREGORIAN_YEAR {
+        return Err(bad_request(
+            "before_gregorian_reform",
+            format!("The Roman calendar is computed from {}; see /easter/{year} for the Julian reckoning", julian::FIRST_GREGORIAN_YEAR),
+        ));
+    }
+    if year > LAST_YEAR {
+        return Err(bad_request("year_out_of_range", format!("Years up to {LAST_YEAR} are supported")));
+    }
+    Ok(year)
+}
+
+fn resolve_latin(style: Option<&str>) -> Result<LatinStyle, (StatusCode, Json<serde_json::Value>)> {
+    match style {
+        None => Ok(LatinStyle::default()),
+        Some(s) => s.parse().map_err(|e: String| bad_request("invalid_latin", e)),
     }
 }
 
+/// Readings of a day with their Latin and English texts where the corpus
+/// has them
+fn readings_json(day: &LiturgicalDay, style: LatinStyle) -> serde_json::Value {
+    use calendar_data::bible::{resolve, Translation};
+
+    let items: Vec<_> = day
+        .readings
+        .iter()
+        .flat_map(|r| &r.items)
+        .map(|item| {
+            serde_json::json!({
+                "kind": item.kind,
+                "reference": item.reference,
+                "parsed": item.parsed,
+                "text_latin": resolve(&item.reference, Translation::Vulgate).map(|t| latin::render(&t, style)),
+                "text_vernacular": resolve(&item.reference, Translation::DouayRheims),
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "date": day.date,
+        "celebration": day.celebration.id,
+        "items": items,
+    })
+}
+
+/// Serialize a day in the locale, keeping the precedence trace only when
+/// explain=true
+fn day_json(day: &LiturgicalDay, explain: bool, locale: Locale, style: LatinStyle) -> serde_json::Value {
+    let mut value = serde_json::to_value(i18n::localize_in(day, locale, style)).unwrap();
+    if !explain {
+        if let Some(obj) = value.as_object_mut() {
+            obj.remove("trace");
+        }
+    }
+    value
+}
+
 pub fn create_router() -> Router {
-    let state = std::sync::Arc::new(AppState::new());
+    router(AppState::new())
+}
+
+/// Build the API router over a given state
+pub fn router(state: AppState) -> Router {
+    let state = Arc::new(state);
 
     Router::new()
         // GET /today
@@ -83,9 +202,11 @@ pub fn create_router() -> Router {
             let state = state.clone();
             move |query: Query<TodayQuery>| async move {
                 let today = resolve_today(query.tz.as_deref().unwrap_or("UTC"))?;
-                let cal = state.get_calendar(today.year());
+                let locale = resolve_locale(query.lang.as_deref())?;
+                let style = resolve_latin(query.latin.as_deref())?;
+                let cal = state.get_calendar(today.year(), style);
                 match cal.get(today) {
-                    Some(day) => Ok(Json(serde_json::to_value(day).unwrap())),
+                    Some(day) => Ok(Json(day_json(day, query.explain.unwrap_or(false), locale, style))),
                     None => Err(not_found("Date not in calendar")),
                 }
             }
@@ -93,20 +214,139 @@ pub fn create_router() -> Router {
         // GET /date/{YYYY-MM-DD}
         .route("/date/{date}", get({
             let state = state.clone();
-            move |Path(date_str): Path<String>| async move {
+            move |Path(date_str): Path<String>, query: Query<DayQuery>| async move {
+                let date = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
+                    .map_err(|_| bad_request("invalid_date", "Expected YYYY-MM-DD format".into()))?;
+                gregorian_year(date.year())?;
+                let locale = resolve_locale(query.lang.as_deref())?;
+                let style = resolve_latin(query.latin.as_deref())?;
+                let cal = state.get_calendar(date.year(), style);
+                match cal.get(date) {
+                    Some(day) => Ok(Json(day_json(day, query.explain.unwrap_or(false), locale, style))),
+                    None => Err(not_found("Date not in calendar")),
+                }
+            }
+        }))
+        // GET /propers/{YYYY-MM-DD}
+        .route("/propers/{date}", get({
+            let state = state.clone();
+            move |Path(date_str): Path<String>, query: Query<DayQuery>| async move {
                 let date = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
                     .map_err(|_| bad_request("invalid_date", "Expected YYYY-MM-DD format".into()))?;
-                let cal = state.get_calendar(date.year());
+                gregorian_year(date.year())?;
+                let style = resolve_latin(query.latin.as_deref())?;
+                let cal = state.get_calendar(date.year(), style);
+                let day = cal.get(date).ok_or_else(|| not_found("Date not in calendar"))?;
+                match calendar_core::propers::mass_texts_in(day, style) {
+                    Some(texts) => Ok(Json(serde_json::to_value(&texts).unwrap())),
+                    None => Err(not_found(&format!("No propers seeded yet for {}", day.celebration.id))),
+                }
+            }
+        }))
+        // GET /readings/{YYYY-MM-DD}
+        .route("/readings/{date}", get({
+            let state = state.clone();
+            move |Path(date_str): Path<String>, query: Query<DayQuery>| async move {
+                let date = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
+                    .map_err(|_| bad_request("invalid_date", "Expected YYYY-MM-DD format".into()))?;
+                gregorian_year(date.year())?;
+                let style = resolve_latin(query.latin.as_deref())?;
+                let cal = state.get_calendar(date.year(), style);
                 match cal.get(date) {
-                    Some(day) => Ok(Json(serde_json::to_value(day).unwrap())),
+                    Some(day) => Ok(Json(readings_json(day, style))),
                     None => Err(not_found("Date not in calendar")),
                 }
             }
         }))
+        // GET /martyrology/{YYYY-MM-DD} — the Martyrology announcing the date
+        .route("/martyrology/{date}", get(
+            |Path(date_str): Path<String>, query: Query<DayQuery>| async move {
+                let date = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
+                    .map_err(|_| bad_request("invalid_date", "Expected YYYY-MM-DD format".into()))?;
+                gregorian_year(date.year())?;
+                let style = resolve_latin(query.latin.as_deref())?;
+                let martyrology = calendar_data::martyrology::martyrology(date, style);
+                Ok::<_, (StatusCode, Json<serde_json::Value>)>(Json(serde_json::to_value(&martyrology).unwrap()))
+            }
+        ))
+        // GET /noveritis/{year} — the Epiphany proclamation of the moveable feasts
+        .route("/noveritis/{year}", get(
+            |Path(year): Path<String>, query: Query<DayQuery>| async move {
+                let year: i32 = year.parse().map_err(|_| bad_request("invalid_year", "Expected numeric year".into()))?;
+                let year = gregorian_year(year)?;
+                let style = resolve_latin(query.latin.as_deref())?;
+                let noveritis = calendar_core::noveritis::noveritis(year, style);
+                Ok::<_, (StatusCode, Json<serde_json::Value>)>(Json(serde_json::to_value(&noveritis).unwrap()))
+            }
+        ))
+        // GET /easter/{year} — Roman, Julian and Orthodox Easter
+        .route("/easter/{year}", get(|Path(year): Path<String>| async move {
+            let year: i32 = year.parse().map_err(|_| bad_request("invalid_year", "Expected numeric year".into()))?;
+            if !(1..=LAST_YEAR).contains(&year) {
+                return Err(bad_request("year_out_of_range", format!("Expected a year from 1 to {LAST_YEAR}")));
+            }
+            let julian_easter = julian::easter(year);
+            Ok::<_, (StatusCode, Json<serde_json::Value>)>(Json(serde_json::json!({
+                "year": year,
+                "reckoning": julian::reckoning(year),
+                "gregorian": calendar_core::easter(year),
+                "julian": julian_easter,
+                "orthodox": julian_easter.to_gregorian(),
+                "historical": julian::historical_easter(year),
+            })))
+        }))
+        // GET /resolve?q=Dominica+III+Adventus+2027 — date of a designation
+        .route("/resolve", get({
+            let state = state.clone();
+            move |query: Query<ResolveQuery>| async move {
+                let today = resolve_today(query.tz.as_deref().unwrap_or("UTC"))?;
+                let designation: Designation = query.q.parse()
+                    .map_err(|e: DesignationError| bad_request("invalid_designation", e.to_string()))?;
+                let year = gregorian_year(designation.year(today))?;
+                let cal = state.get_calendar(year, LatinStyle::default());
+                match designation.resolve_in(&cal) {
+                    Ok(resolution) => Ok(Json(serde_json::to_value(&resolution).unwrap())),
+                    Err(e @ DesignationError::Ambiguous(_)) => Err(bad_request("ambiguous_designation", e.to_string())),
+                    Err(e) => Err(not_found(&e.to_string())),
+                }
+            }
+        }))
+        // GET /v1/feasts/{id}/occurrences?from=2024&to=2060
+        .route("/v1/feasts/{id}/occurrences", get({
+            let state = state.clone();
+            move |Path(id): Path<String>, query: Query<RangeQuery>| async move {
+                let from = gregorian_year(query.from.unwrap_or_else(|| Utc::now().year()))?;
+                let to = gregorian_year(query.to.unwrap_or(from))?;
+                if to < from || to - from >= MAX_RANGE_YEARS {
+                    return Err(bad_request(
+                        "invalid_range",
+                        format!("Expected from <= to, at most {} years", MAX_RANGE_YEARS),
+                    ));
+                }
+                // Off the async workers: each uncached year builds a Calendar
+                let found = tokio::task::spawn_blocking({
+                    let (state, id) = (state.clone(), id.clone());
+                    move || {
+                        (from..=to)
+                            .filter_map(|year| occurrences::occurrence(&state.get_calendar(year, LatinStyle::default()), &id))
+                            .collect::<Vec<_>>()
+                    }
+                })
+                .await
+                .map_err(|_| (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(serde_json::json!({"error": "internal", "message": "Occurrence search failed"})),
+                ))?;
+                if found.is_empty() {
+                    return Err(not_found("Celebration not found in range"));
+                }
+                Ok(Json(serde_json::json!({ "id": id, "occurrences": found })))
+            }
+        }))
         // GET /month/{YYYY-MM}
         .route("/month/{year_month}", get({
             let state = state.clone();
-            move |Path(ym): Path<String>| async move {
+            move |Path(ym): Path<String>, query: Query<DayQuery>| async move {
                 let parts: Vec<&str> = ym.split('-').collect();
                 if parts.len() != 2 {
                     return Err(bad_request("invalid_format", "Expected YYYY-MM format".into()));
@@ -116,14 +356,44 @@ pub fn create_router() -> Router {
                 if !(1..=12).contains(&month) {
                     return Err(bad_request("invalid_month", "Month must be 1-12".into()));
                 }
-                let cal = state.get_calendar(year);
+                gregorian_year(year)?;
+                let locale = resolve_locale(query.lang.as_deref())?;
+                let style = resolve_latin(query.latin.as_deref())?;
+                let cal = state.get_calendar(year, style);
                 let mut days: Vec<&LiturgicalDay> = cal
                     .days()
                     .values()
                     .filter(|d| d.date.month() == month)
                     .collect();
                 days.sort_by_key(|d| d.date);
-                Ok(Json(serde_json::to_value(&days).unwrap()))
+                let explain = query.explain.unwrap_or(false);
+                let days: Vec<serde_json::Value> = days.into_iter().map(|d| day_json(d, explain, locale, style)).collect();
+                Ok(Json(serde_json::Value::Array(days)))
+            }
+        }))
+        // GET /v1/seasons/{year} — season ranges of the civil and liturgical year
+        .route("/v1/seasons/{year}", get({
+            let state = state.clone();
+            move |Path(year): Path<String>, query: Query<DayQuery>| async move {
+                let year: i32 = year.parse().map_err(|_| bad_request("invalid_year", "Expected numeric year".into()))?;
+                let year = gregorian_year(year)?;
+                let locale = resolve_locale(query.lang.as_deref())?;
+                let cal = state.get_calendar(year, LatinStyle::default());
+                let ranges = |ranges: Vec<calendar_core::SeasonRange>| -> Vec<serde_json::Value> {
+                    ranges
+                        .into_iter()
+                        .map(|r| {
+                            let mut value = serde_json::to_value(&r).unwrap();
+                            value["name"] = i18n::season_name(r.season, locale).into();
+                            value
+                        })
+                        .collect()
+                };
+                Ok::<_, (StatusCode, Json<serde_json::Value>)>(Json(serde_json::json!({
+                    "year": year,
+                    "civil": ranges(cal.seasons()),
+                    "liturgical": ranges(cal.liturgical_seasons()),
+                })))
             }
         }))
         // GET /season — current liturgical season
@@ -131,13 +401,14 @@ pub fn create_router() -> Router {
             let state = state.clone();
             move |query: Query<TodayQuery>| async move {
                 let today = resolve_today(query.tz.as_deref().unwrap_or("UTC"))?;
-                let cal = state.get_calendar(today.year());
+                let locale = resolve_locale(query.lang.as_deref())?;
+                let cal = state.get_calendar(today.year(), resolve_latin(query.latin.as_deref())?);
                 match cal.get(today) {
                     Some(day) => {
                         let resp = SeasonResponse {
                             date: today,
                             season: day.season,
-                            season_name: season_display_name(day.season).to_string(),
+                            season_name: i18n::season_name(day.season, locale).to_string(),
                             week: day.week,
                             color: day.color,
                         };
@@ -150,8 +421,8 @@ pub fn create_router() -> Router {
 }
 
 /// Start the API server on the given port.
-pub async fn serve(port: u16) -> Result<(), Box<dyn std::error::Error>> {
-    let app = create_router();
+pub async fn serve(port: u16, orations: OrationRegistry) -> Result<(), Box<dyn std::error::Error>> {
+    let app = router(AppState::with_orations(orations));
 
     let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", port)).await?;
     tracing::info!("Liturgical Calendar API listening on port {}", port);
@@ -1,26 +1,75 @@
 use chrono::{Datelike, NaiveDate, Weekday};
 use std::collections::BTreeMap;
 
-use crate::computus::moveable_feasts;
-use crate::precedence::resolve_precedence;
-use crate::sanctoral::build_sanctoral_cycle;
-use crate::temporal::build_temporal_cycle;
+use crate::chants;
+use crate::computus::{computus_data, moveable_feasts};
+use crate::holy_week;
+use crate::latin::{self, LatinStyle};
+use crate::ordinary;
+use crate::stations;
+use crate::orations::OrationRegistry;
+use crate::precedence::{candidate, resolve_day, Transfer};
+use crate::readings;
+use crate::rubrics::{self, RubricTables};
+use crate::sanctoral::{build_sanctoral_cycle, is_free_saturday, saturday_of_our_lady};
+use crate::temporal::build_temporal_cycle_for;
 use crate::types::*;
 
+/// Local options applied when building a calendar.
+#[derive(Debug, Clone)]
+pub struct CalendarOptions {
+    /// Rubrical system whose tables of precedence are applied
+    pub rubrics: RubricalSystem,
+    /// Anniversaries of the Pope and the bishop, and commanded collects
+    pub orations: OrationRegistry,
+    /// Keep Sancta Maria in Sabbato as the celebration of free Saturdays, as
+    /// the rubrics direct for the Office. When false the feria is kept and
+    /// Our Lady is offered as an option.
+    pub saturday_of_our_lady: bool,
+    /// How Latin titles are written: classical, with ligatures, or accented
+    pub latin: LatinStyle,
+}
+
+impl Default for CalendarOptions {
+    fn default() -> Self {
+        Self {
+            rubrics: RubricalSystem::default(),
+            orations: OrationRegistry::default(),
+            saturday_of_our_lady: true,
+            latin: LatinStyle::default(),
+        }
+    }
+}
+
 /// The main calendar for a given year under the 1962 rubrics.
 pub struct Calendar {
     year: i32,
+    rubrics: RubricalSystem,
     days: BTreeMap<NaiveDate, LiturgicalDay>,
 }
 
 impl Calendar {
     /// Build the complete calendar for a given year.
     pub fn new(year: i32) -> Self {
-        let temporal = build_temporal_cycle(year);
+        Self::with_options(year, CalendarOptions::default())
+    }
+
+    /// Build the calendar for a given year with local options.
+    ///
+    /// The calendar follows the Gregorian rules in every year, so one built
+    /// for a year before 1583 is proleptic; see `julian` for how such years
+    /// were actually reckoned.
+    pub fn with_options(year: i32, options: CalendarOptions) -> Self {
+        let temporal = build_temporal_cycle_for(year, options.rubrics);
         let sanctoral = build_sanctoral_cycle(year);
-        let _mf = moveable_feasts(year);
+        let mf = moveable_feasts(year);
+        let tables = rubrics::tables(options.rubrics);
 
         let mut days = BTreeMap::new();
+        // Impeded Class I feasts waiting for a day not occupied by Class I or II
+        let mut pending: Vec<Transfer> = Vec::new();
+        // Readings of the Mass repeated on ferias without a Mass of their own
+        let mut ferial_readings: Option<Readings> = None;
 
         for (date, (entry, special_celebration)) in &temporal {
             // Build the temporal celebration for this day
@@ -35,28 +84,97 @@ impl Calendar {
             // Get sanctoral celebrations for this date
             let sanctoral_celebs = sanctoral.get(date).cloned().unwrap_or_default();
 
+            let occupied = std::iter::once(&temporal_celeb)
+                .chain(sanctoral_celebs.iter())
+                .any(|c| c.rank <= CelebrationRank::ClassII);
+            let transfer = if occupied { None } else { next_transfer(tables, &mut pending) };
+
             // Resolve precedence
-            let (winner, commemorations) = resolve_precedence(&temporal_celeb, &sanctoral_celebs);
+            let resolution = resolve_day(tables, &temporal_celeb, &sanctoral_celebs, transfer.as_ref());
+            let mut winner = resolution.winner;
+            let commemorations = resolution.commemorations;
+            let mut trace = resolution.trace;
+            pending.extend(resolution.transferred.into_iter().map(|celebration| Transfer {
+                from: *date,
+                celebration,
+            }));
 
-            let readings = crate::readings::get_readings(&winner.id);
-            let notes = crate::readings::get_notes(&winner.id);
+            let mut options_for_day = Vec::new();
+            if is_free_saturday(*date, entry.season, &winner, &mf) {
+                let bvm = saturday_of_our_lady(*date, &mf);
+                if options.saturday_of_our_lady {
+                    trace.candidates[0].outcome = Outcome::Omitted;
+                    trace.candidates[0].reason =
+                        "Free Saturday; replaced by Sancta Maria in Sabbato".into();
+                    trace.candidates.insert(0, candidate(
+                        tables,
+                        &bvm,
+                        Outcome::Celebrated,
+                        "Office of Our Lady on a free Saturday".into(),
+                        "RG, de S. Maria in Sabbato",
+                    ));
+                    options_for_day.push(std::mem::replace(&mut winner, bvm));
+                } else {
+                    options_for_day.push(bvm);
+                }
+            }
 
-            let day = LiturgicalDay {
+            if date.weekday() == Weekday::Sun {
+                // The ferias after Trinity Sunday take the Mass of the 1st
+                // Sunday after Pentecost
+                let sunday = match temporal_celeb.id.as_str() {
+                    "trinity-sunday" => readings::get_readings("sunday-after-pentecost-2"),
+                    _ => readings::readings_for(&temporal_celeb, *date, entry.season, &mf, options.rubrics),
+                };
+                if sunday.is_some() {
+                    ferial_readings = sunday;
+                }
+            }
+            let mut readings = readings::readings_for(&winner, *date, entry.season, &mf, options.rubrics);
+            if readings.is_none() && winner.category == CelebrationCategory::Feria {
+                readings = ferial_readings.clone();
+            }
+            // Feasts whose Mass is repeated on the following ferias
+            if matches!(winner.id.as_str(), "circumcision" | "epiphany" | "ascension") {
+                ferial_readings = readings.clone();
+            }
+            let notes = holy_week::notes(&winner.id, options.rubrics)
+                .or_else(|| readings::get_notes(&winner.id));
+            let rite = holy_week::rite(&winner.id, options.rubrics);
+            let orations = options.orations.orations_for(*date, &winner, &commemorations);
+            let chants = chants::chants(&winner, entry.season, date.weekday());
+            let station = stations::station(*date, &mf).map(String::from);
+            let ordinary = ordinary::ordinary(*date, &winner, &commemorations, entry.season, options.rubrics);
+
+            let mut day = LiturgicalDay {
                 date: *date,
                 season: entry.season,
                 week: entry.week,
                 day_of_week: format!("{:?}", date.weekday()),
+                date_latin: None,
                 celebration: winner.clone(),
                 commemorations,
+                options: options_for_day,
                 color: winner.color,
                 readings,
                 notes,
+                rite,
+                chants,
+                station,
+                ordinary,
+                orations,
+                trace: Some(trace),
             };
+            latin::render_day(&mut day, options.latin);
 
             days.insert(*date, day);
         }
 
-        Self { year, days }
+        // Feasts still waiting at the end of the year find no day in it. Each
+        // year is built on its own, so they are not carried into January
+        omit_unplaced(&mut days, pending);
+
+        Self { year, rubrics: options.rubrics, days }
     }
 
     /// Get the liturgical day for a specific date.
@@ -74,10 +192,84 @@ impl Calendar {
         self.year
     }
 
+    /// Get the rubrical system the calendar was built under.
+    pub fn rubrics(&self) -> RubricalSystem {
+        self.rubrics
+    }
+
+    /// Vespers of a day, from the concurrence of its office with the next
+    /// day's. `None` for the last day of the year.
+    pub fn vespers(&self, date: NaiveDate) -> Option<Vespers> {
+        let today = self.days.get(&date)?;
+        let tomorrow = self.days.get(&date.succ_opt()?)?;
+        Some(rubrics::tables(self.rubrics).concurrence(&today.celebration, &tomorrow.celebration))
+    }
+
     /// Get the moveable feasts for this year.
     pub fn moveable_feasts(&self) -> MoveableFeasts {
         moveable_feasts(self.year)
     }
+
+    /// Get the golden number, epact and other tables for this year.
+    pub fn computus(&self) -> ComputusData {
+        computus_data(self.year)
+    }
+
+    /// Get the seasons of the civil year in order, from the 1st of January
+    /// to the 31st of December; Christmas opens and closes the list.
+    pub fn seasons(&self) -> Vec<SeasonRange> {
+        season_ranges(self.days.values().map(|d| (d.date, d.season)))
+    }
+
+    /// Get the seasons of the liturgical year ending in this year, from the
+    /// First Sunday of Advent of the year before to the eve of this year's.
+    pub fn liturgical_seasons(&self) -> Vec<SeasonRange> {
+        let advent = moveable_feasts(self.year).advent_1;
+        let previous_advent = moveable_feasts(self.year - 1).advent_1;
+        let previous = build_temporal_cycle_for(self.year - 1, self.rubrics);
+        let before = previous.range(previous_advent..).map(|(date, (entry, _))| (*date, entry.season));
+        let after = self.days.range(..advent).map(|(date, d)| (*date, d.season));
+        season_ranges(before.chain(after))
+    }
+}
+
+/// The waiting feast a free day takes: the highest in precedence, and of
+/// equals the one impeded first
+fn next_transfer(tables: &RubricTables, pending: &mut Vec<Transfer>) -> Option<Transfer> {
+    let next = pending
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| tables.order(&a.celebration, &b.celebration).then(a.from.cmp(&b.from)))
+        .map(|(i, _)| i)?;
+    Some(pending.remove(next))
+}
+
+/// Record transfers that found no free day before the end of the year as
+/// omitted on the day they were impeded
+fn omit_unplaced(days: &mut BTreeMap<NaiveDate, LiturgicalDay>, pending: impl IntoIterator<Item = Transfer>) {
+    for transfer in pending {
+        let Some(trace) = days.get_mut(&transfer.from).and_then(|d| d.trace.as_mut()) else { continue };
+        if let Some(c) = trace.candidates.iter_mut().find(|c| c.id == transfer.celebration.id) {
+            c.outcome = Outcome::Omitted;
+            c.reason = "Impeded; no day free of Class I and II celebrations remained in the year".into();
+        }
+    }
+}
+
+/// Group consecutive days of the same season
+fn season_ranges(days: impl Iterator<Item = (NaiveDate, LiturgicalSeason)>) -> Vec<SeasonRange> {
+    let mut ranges: Vec<SeasonRange> = Vec::new();
+    for (date, season) in days {
+        let sunday = u8::from(date.weekday() == Weekday::Sun);
+        match ranges.last_mut() {
+            Some(last) if last.season == season && last.end.succ_opt() == Some(date) => {
+                last.end = date;
+                last.weeks += sunday;
+            }
+            _ => ranges.push(SeasonRange { season, start: date, end: date, weeks: sunday }),
+        }
+    }
+    ranges
 }
 
 #[cfg(test)]
@@ -92,6 +284,24 @@ mod tests {
         assert_eq!(cal.days().len(), 365);
     }
 
+    #[test]
+    fn test_seasons() {
+        let d = |y, m, day| NaiveDate::from_ymd_opt(y, m, day).unwrap();
+        let cal = Calendar::new(2026);
+        let civil = cal.seasons();
+        assert_eq!((civil[0].season, civil[0].end), (LiturgicalSeason::Christmas, d(2026, 1, 5)));
+        assert_eq!((civil[1].start, civil[1].weeks), (d(2026, 1, 6), 3));
+        let lent = civil.iter().find(|r| r.season == LiturgicalSeason::Lent).unwrap();
+        assert_eq!((lent.start, lent.end, lent.weeks), (d(2026, 2, 18), d(2026, 3, 21), 4));
+        assert_eq!(civil.last().unwrap().start, d(2026, 12, 25));
+
+        let liturgical = cal.liturgical_seasons();
+        assert_eq!((liturgical[0].season, liturgical[0].start, liturgical[0].weeks), (LiturgicalSeason::Advent, d(2025, 11, 30), 4));
+        // Christmas runs across the civil year
+        assert_eq!((liturgical[1].start, liturgical[1].end), (d(2025, 12, 25), d(2026, 1, 5)));
+        assert_eq!(liturgical.last().unwrap().end, d(2026, 11, 28));
+    }
+
     #[test]
     fn test_calendar_leap_year() {
         let cal = Calendar::new(2024);
@@ -125,10 +335,140 @@ mod tests {
         assert_eq!(day.color, LiturgicalColor::Violet);
     }
 
+    #[test]
+    fn test_pope_anniversary_added() {
+        let options = CalendarOptions {
+            orations: OrationRegistry::new()
+                .with_anniversary(crate::orations::AnniversaryOf::PopeCoronation, 1, 28),
+            ..Default::default()
+        };
+        let cal = Calendar::with_options(2026, options);
+        // St. Thomas Aquinas, Class III
+        let day = cal.get(NaiveDate::from_ymd_opt(2026, 1, 28).unwrap()).unwrap();
+        assert_eq!(day.orations.len(), 1);
+        assert_eq!(day.orations[0].kind, OrationKind::PopeAnniversary);
+        // Epiphany, Class I
+        let cal = Calendar::with_options(2026, CalendarOptions {
+            orations: OrationRegistry::new()
+                .with_anniversary(crate::orations::AnniversaryOf::PopeCoronation, 1, 6),
+            ..Default::default()
+        });
+        assert!(cal.get(NaiveDate::from_ymd_opt(2026, 1, 6).unwrap()).unwrap().orations.is_empty());
+    }
+
+    #[test]
+    fn test_saturday_of_our_lady_default_or_option() {
+        // Saturday in the 8th week after Pentecost, no feast
+        let sat = NaiveDate::from_ymd_opt(2026, 7, 18).unwrap();
+        let cal = Calendar::new(2026);
+        let day = cal.get(sat).unwrap();
+        assert_eq!(day.celebration.id, "saturday-of-our-lady-salve-sancta-parens");
+        assert_eq!(day.options[0].category, CelebrationCategory::Feria);
+
+        let cal = Calendar::with_options(2026, CalendarOptions {
+            saturday_of_our_lady: false,
+            ..Default::default()
+        });
+        let day = cal.get(sat).unwrap();
+        assert_eq!(day.celebration.category, CelebrationCategory::Feria);
+        assert_eq!(day.options[0].id, "saturday-of-our-lady-salve-sancta-parens");
+
+        // The Vigil of Pentecost is not a free Saturday
+        let cal = Calendar::new(2026);
+        let day = cal.get(NaiveDate::from_ymd_opt(2026, 5, 23).unwrap()).unwrap();
+        assert!(!day.celebration.id.starts_with("saturday-of-our-lady-"));
+        assert!(day.options.is_empty());
+    }
+
+    #[test]
+    fn test_annunciation_transferred_after_low_sunday() {
+        // 2024: Mar 25 is Monday of Holy Week
+        let cal = Calendar::new(2024);
+        let day = cal.get(NaiveDate::from_ymd_opt(2024, 3, 25).unwrap()).unwrap();
+        assert_eq!(day.season, LiturgicalSeason::HolyWeek);
+        assert_ne!(day.celebration.id, "annunciation");
+        let trace = day.trace.as_ref().unwrap();
+        assert!(trace.candidates.iter().any(|c| c.id == "annunciation" && c.outcome == Outcome::Transferred));
+        // Monday after Low Sunday
+        let day = cal.get(NaiveDate::from_ymd_opt(2024, 4, 8).unwrap()).unwrap();
+        assert_eq!(day.celebration.id, "annunciation");
+    }
+
+    #[test]
+    fn test_pre1955_transfers_impeded_class_ii() {
+        // 2026: Chair of St. Peter (Feb 22) falls on the 1st Sunday of Lent
+        let options = CalendarOptions { rubrics: RubricalSystem::PrePius, ..Default::default() };
+        let cal = Calendar::with_options(2026, options);
+        let day = cal.get(NaiveDate::from_ymd_opt(2026, 2, 22).unwrap()).unwrap();
+        assert_eq!(day.celebration.id, "sunday-lent-1");
+        assert!(day.commemorations.is_empty());
+        let day = cal.get(NaiveDate::from_ymd_opt(2026, 2, 23).unwrap()).unwrap();
+        assert_eq!(day.celebration.id, "chair-of-st-peter");
+
+        let cal = Calendar::new(2026);
+        let day = cal.get(NaiveDate::from_ymd_opt(2026, 2, 22).unwrap()).unwrap();
+        assert_eq!(day.commemorations[0].id, "chair-of-st-peter");
+    }
+
+    #[test]
+    fn test_vespers_of_sunday() {
+        let cal = Calendar::new(2026);
+        // Saturday Jul 18 before the 8th Sunday after Pentecost
+        let v = cal.vespers(NaiveDate::from_ymd_opt(2026, 7, 18).unwrap()).unwrap();
+        assert_eq!(v.of, VespersOf::Following);
+        assert_eq!(v.celebration, "sunday-after-pentecost-9");
+        assert!(cal.vespers(NaiveDate::from_ymd_opt(2026, 12, 31).unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_holy_saturday_rite_per_system() {
+        let date = NaiveDate::from_ymd_opt(2026, 4, 4).unwrap();
+        let cal = Calendar::new(2026);
+        let rite = cal.get(date).unwrap().rite.as_ref().unwrap();
+        assert_eq!(rite.lessons.len(), 4);
+        let cal = Calendar::with_options(2026, CalendarOptions {
+            rubrics: RubricalSystem::PrePius,
+            ..Default::default()
+        });
+        let day = cal.get(date).unwrap();
+        assert_eq!(day.rite.as_ref().unwrap().lessons.len(), 12);
+        assert!(day.notes.as_ref().unwrap().contains("twelve prophecies"));
+        // The readings give the prophecies of the rite
+        let lessons: Vec<String> = day.readings.as_ref().unwrap().items.iter()
+            .filter(|i| i.kind == ReadingKind::Lesson)
+            .map(|i| i.reference.clone())
+            .collect();
+        assert_eq!(lessons, day.rite.as_ref().unwrap().lessons);
+    }
+
+    #[test]
+    fn test_transfers_placed_by_precedence() {
+        // 2035: St. Joseph falls in Holy Week and the Annunciation on Easter
+        // Sunday; the Annunciation, a feast of the Lord, is placed first
+        let cal = Calendar::new(2035);
+        let id = |m, d| cal.get(NaiveDate::from_ymd_opt(2035, m, d).unwrap()).unwrap().celebration.id.clone();
+        assert_eq!(id(4, 2), "annunciation");
+        assert_eq!(id(4, 3), "st-joseph");
+    }
+
+    #[test]
+    fn test_transfer_left_at_year_end_is_omitted() {
+        let mut cal = Calendar::new(2024);
+        let impeded = NaiveDate::from_ymd_opt(2024, 3, 25).unwrap();
+        // As if no free day had followed Holy Week
+        let celebration = Celebration::new(
+            "annunciation", "In Annuntiatione B.M.V.", "Annunciation",
+            CelebrationRank::ClassI, CelebrationCategory::Feast, LiturgicalColor::White,
+        );
+        omit_unplaced(&mut cal.days, [Transfer { from: impeded, celebration }]);
+        let trace = cal.days[&impeded].trace.as_ref().unwrap();
+        assert!(trace.candidates.iter().any(|c| c.id == "annunciation" && c.outcome == Outcome::Omitted));
+    }
+
     #[test]
     fn test_all_days_have_color() {
         let cal = Calendar::new(2026);
-        for (_, day) in cal.days() {
+        for day in cal.days().values() {
             // Color should be set
             let _ = day.color;
         }
@@ -137,7 +477,7 @@ mod tests {
     #[test]
     fn test_all_days_have_one_celebration() {
         let cal = Calendar::new(2026);
-        for (_, day) in cal.days() {
+        for day in cal.days().values() {
             assert!(!day.celebration.id.is_empty());
         }
     }
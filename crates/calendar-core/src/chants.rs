@@ -0,0 +1,107 @@
+use chrono::Weekday;
+
+use crate::types::*;
+
+/// Chants between the Epistle and the Gospel for a celebration.
+///
+/// Gradual and Alleluia through the year; Gradual and Tract from
+/// Septuagesima to Holy Week, with the Tract on Lenten ferias only on
+/// Monday, Wednesday and Friday, and none on the ferias of Septuagesima;
+/// a Greater Alleluia in Paschaltide after the Easter Octave. Sequences
+/// are added on Easter and its Octave, Pentecost and its Octave, Corpus
+/// Christi, the Seven Sorrows and at Requiems.
+pub fn chants(celebration: &Celebration, season: LiturgicalSeason, weekday: Weekday) -> Vec<Chant> {
+    use ChantForm::*;
+
+    let (forms, sequence): (&[ChantForm], Option<&str>) = match celebration.id.as_str() {
+        "all-souls" => (&[Gradual, Tract], Some("Dies irae")),
+        "easter-sunday" => (&[Gradual, Alleluia], Some("Víctimae pascháli laudes")),
+        id if id.starts_with("easter-octave-") => (&[Gradual, Alleluia], Some("Víctimae pascháli laudes")),
+        "pentecost" => (&[GreaterAlleluia], Some("Veni, Sancte Spíritus")),
+        id if id.starts_with("pentecost-octave-") => (&[GreaterAlleluia], Some("Veni, Sancte Spíritus")),
+        "corpus-christi" => (&[Gradual, Alleluia], Some("Lauda Sion")),
+        "seven-sorrows-bvm" => (&[Gradual, Alleluia], Some("Stabat Mater")),
+        "holy-thursday" => (&[Gradual], None),
+        "good-friday" => (&[Tract, Tract], None),
+        "holy-saturday" => (&[Alleluia, Tract], None),
+        _ => (by_season(celebration, season, weekday), None),
+    };
+
+    let mut out: Vec<Chant> = forms.iter().map(|&form| Chant { form, name: None }).collect();
+    if let Some(name) = sequence {
+        out.push(Chant { form: Sequence, name: Some(name.into()) });
+    }
+    out
+}
+
+fn by_season(celebration: &Celebration, season: LiturgicalSeason, weekday: Weekday) -> &'static [ChantForm] {
+    use ChantForm::*;
+
+    let ferial = matches!(
+        celebration.category,
+        CelebrationCategory::Feria | CelebrationCategory::EmberDay
+    );
+    match season {
+        LiturgicalSeason::Easter | LiturgicalSeason::Ascensiontide => &[GreaterAlleluia],
+        LiturgicalSeason::Septuagesima if ferial => &[Gradual],
+        LiturgicalSeason::Lent | LiturgicalSeason::Passiontide | LiturgicalSeason::HolyWeek if ferial => {
+            match weekday {
+                Weekday::Mon | Weekday::Wed | Weekday::Fri => &[Gradual, Tract],
+                _ => &[Gradual],
+            }
+        }
+        LiturgicalSeason::Septuagesima
+        | LiturgicalSeason::Lent
+        | LiturgicalSeason::Passiontide
+        | LiturgicalSeason::HolyWeek => &[Gradual, Tract],
+        // Ember days outside Lent are penitential: no Alleluia, and a Tract
+        // after the lessons on Saturday
+        _ if celebration.category == CelebrationCategory::EmberDay => match weekday {
+            Weekday::Sat => &[Gradual, Tract],
+            _ => &[Gradual],
+        },
+        _ => &[Gradual, Alleluia],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forms(c: &[Chant]) -> Vec<ChantForm> {
+        c.iter().map(|c| c.form).collect()
+    }
+
+    #[test]
+    fn test_tract_from_septuagesima() {
+        let sunday = Celebration::sunday(LiturgicalSeason::Septuagesima, 1);
+        let c = chants(&sunday, LiturgicalSeason::Septuagesima, Weekday::Sun);
+        assert_eq!(forms(&c), vec![ChantForm::Gradual, ChantForm::Tract]);
+        // Lenten ferias have the Tract on Monday, Wednesday and Friday only
+        let tue = Celebration::feria(LiturgicalSeason::Lent, 2, Weekday::Tue);
+        assert_eq!(forms(&chants(&tue, LiturgicalSeason::Lent, Weekday::Tue)), vec![ChantForm::Gradual]);
+        let wed = Celebration::feria(LiturgicalSeason::Lent, 2, Weekday::Wed);
+        assert!(forms(&chants(&wed, LiturgicalSeason::Lent, Weekday::Wed)).contains(&ChantForm::Tract));
+    }
+
+    #[test]
+    fn test_greater_alleluia_in_paschaltide() {
+        let sunday = Celebration::sunday(LiturgicalSeason::Easter, 3);
+        let c = chants(&sunday, LiturgicalSeason::Easter, Weekday::Sun);
+        assert_eq!(forms(&c), vec![ChantForm::GreaterAlleluia]);
+    }
+
+    #[test]
+    fn test_sequences() {
+        let requiem = Celebration::new(
+            "all-souls", "In Commemoratione Omnium Fidelium Defunctorum", "All Souls Day",
+            CelebrationRank::ClassI, CelebrationCategory::Solemnity,
+            LiturgicalColor::Black,
+        );
+        let c = chants(&requiem, LiturgicalSeason::AfterPentecost, Weekday::Mon);
+        assert_eq!(c.last().unwrap().name.as_deref(), Some("Dies irae"));
+        let sunday = Celebration::sunday(LiturgicalSeason::AfterPentecost, 5);
+        let c = chants(&sunday, LiturgicalSeason::AfterPentecost, Weekday::Sun);
+        assert_eq!(forms(&c), vec![ChantForm::Gradual, ChantForm::Alleluia]);
+    }
+}
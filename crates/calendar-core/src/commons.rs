@@ -0,0 +1,338 @@
+use crate::latin::{self, LatinStyle};
+use crate::propers::{last_gospel, tp};
+use crate::types::*;
+
+/// Get the texts of a Common of the Saints.
+/// Orations keep the Missal's "N." where the saint's name is said; see
+/// `with_name`.
+pub fn get_common(common: Common) -> ProperTexts {
+    match common {
+        Common::ConfessorBishop => ProperTexts {
+            introit: Some(tp(
+                Some("Ecclus 45:30; Ps 131:1"),
+                "Státuit ei Dóminus testaméntum pacis, et príncipem fecit eum: ut sit illi sacerdótii dígnitas in aetérnum. Ps. Meménto, Dómine, David: et omnis mansuetúdinis ejus.",
+                "The Lord made to him a covenant of peace, and made him a prince: that the dignity of priesthood should be to him for ever. Ps. O Lord, remember David, and all his meekness.",
+            )),
+            collect: Some(tp(
+                None,
+                "Da, quáesumus, omnípotens Deus: ut beáti N. Confessóris tui atque Pontíficis veneránda solémnitas, et devotiónem nobis áugeat et salútem.",
+                "Grant, we beseech Thee, almighty God, that the venerable festival of blessed N., Thy Confessor and Bishop, may increase in us both devotion and salvation.",
+            )),
+            gradual: Some(tp(
+                Some("Ecclus 44:16, 20; Ps 109:4"),
+                "Ecce sacérdos magnus, qui in diébus suis plácuit Deo. V. Non est invéntus símilis illi, qui conserváret legem Excélsi. Allelúja, allelúja. V. Tu es sacérdos in aetérnum, secúndum órdinem Melchísedech. Allelúja.",
+                "Behold a great priest, who in his days pleased God. V. There was not found the like to him, who kept the law of the Most High. Alleluia, alleluia. V. Thou art a priest for ever according to the order of Melchisedech. Alleluia.",
+            )),
+            offertory: Some(tp(
+                Some("Ps 88:21-22"),
+                "Invéni David servum meum, óleo sancto meo unxi eum: manus enim mea auxiliábitur ei, et bráchium meum confortábit eum.",
+                "I have found David My servant, with My holy oil I have anointed him: for My hand shall help him, and My arm shall strengthen him.",
+            )),
+            secret: Some(tp(
+                None,
+                "Sancti N. Confessóris tui atque Pontíficis, quáesumus, Dómine, ánnua solémnitas pietáti tuae nos reddat accéptos: ut, per haec piae oblatiónis offícia, et illum beáta retribútio comitétur, et nobis grátiae tuae dona concíliet.",
+                "May the yearly festival of blessed N., Thy Confessor and Bishop, make us acceptable to Thy mercy, O Lord, we beseech Thee: that through these offices of loving oblation a blessed recompense may attend him, and the gifts of Thy grace be won for us.",
+            )),
+            communion: Some(tp(
+                Some("Luke 12:42"),
+                "Fidélis servus et prudens, quem constítuit dóminus super famíliam suam: ut det illis in témpore trítici mensúram.",
+                "The faithful and wise servant, whom his lord setteth over his family, to give them their measure of wheat in due season.",
+            )),
+            postcommunion: Some(tp(
+                None,
+                "Praesta, quáesumus, omnípotens Deus: ut, de percéptis munéribus grátias exhibéntes, intercedénte beáto N. Confessóre tuo atque Pontífice, benefícia potióra sumámus.",
+                "Grant, we beseech Thee, almighty God, that we who give thanks for the gifts we have received may, by the intercession of blessed N., Thy Confessor and Bishop, obtain still greater benefits.",
+            )),
+            last_gospel: Some(last_gospel()),
+            ..Default::default()
+        },
+
+        Common::Doctors => ProperTexts {
+            introit: Some(tp(
+                Some("Ecclus 15:5; Ps 91:2"),
+                "In médio Ecclésiae apéruit os ejus: et implévit eum Dóminus spíritu sapiéntiae et intelléctus: stolam glóriae índuit eum. Ps. Bonum est confitéri Dómino: et psállere nómini tuo, Altíssime.",
+                "In the midst of the Church the Lord opened his mouth, and filled him with the spirit of wisdom and understanding: He clothed him with a robe of glory. Ps. It is good to give praise to the Lord, and to sing to Thy name, O Most High.",
+            )),
+            collect: Some(tp(
+                None,
+                "Deus, qui pópulo tuo aetérnae salútis beátum N. minístrum tribuísti: praesta, quáesumus; ut, quem Doctórem vitae habúimus in terris, intercessórem habére mereámur in caelis.",
+                "O God, who didst give blessed N. to Thy people as a minister of eternal salvation: grant, we beseech Thee, that we may deserve to have him as our intercessor in heaven, whom we had as a teacher of life on earth.",
+            )),
+            gradual: Some(tp(
+                Some("Ps 36:30-31"),
+                "Os justi meditábitur sapiéntiam, et lingua ejus loquétur judícium. V. Lex Dei ejus in corde ipsíus: et non supplantabúntur gressus ejus. Allelúja, allelúja. V. Amávit eum Dóminus, et ornávit eum: stolam glóriae índuit eum. Allelúja.",
+                "The mouth of the just shall meditate wisdom, and his tongue shall speak judgment. V. The law of his God is in his heart, and his steps shall not be supplanted. Alleluia, alleluia. V. The Lord loved him and adorned him: He clothed him with a robe of glory. Alleluia.",
+            )),
+            offertory: Some(tp(
+                Some("Ps 91:13"),
+                "Justus ut palma florébit: sicut cedrus, quae in Líbano est, multiplicábitur.",
+                "The just shall flourish like the palm tree: he shall grow up like the cedar of Libanus.",
+            )),
+            secret: Some(tp(
+                None,
+                "Sancti N. Confessóris tui atque Doctóris nobis, Dómine, pia non desit orátio: quae et múnera nostra concíliet, et tuam nobis indulgéntiam semper obtíneat.",
+                "May the loving prayer of blessed N., Thy Confessor and Doctor, never fail us, O Lord: may it make our offerings acceptable, and ever obtain for us Thy forgiveness.",
+            )),
+            communion: Some(tp(
+                Some("Luke 12:42"),
+                "Fidélis servus et prudens, quem constítuit dóminus super famíliam suam: ut det illis in témpore trítici mensúram.",
+                "The faithful and wise servant, whom his lord setteth over his family, to give them their measure of wheat in due season.",
+            )),
+            postcommunion: Some(tp(
+                None,
+                "Ut nobis, Dómine, tua sacrifícia dent salútem: beátus N. Conféssor tuus et Doctor egrégius, quáesumus, precátor accédat.",
+                "That Thy sacrifices, O Lord, may bring us salvation, may blessed N., Thy Confessor and illustrious Doctor, we beseech Thee, intercede for us.",
+            )),
+            last_gospel: Some(last_gospel()),
+            ..Default::default()
+        },
+
+        Common::Confessor => ProperTexts {
+            introit: Some(tp(
+                Some("Ps 36:30-31, 1"),
+                "Os justi meditábitur sapiéntiam, et lingua ejus loquétur judícium: lex Dei ejus in corde ipsíus. Ps. Noli aemulári in malignántibus: neque zeláveris faciéntes iniquitátem.",
+                "The mouth of the just shall meditate wisdom, and his tongue shall speak judgment: the law of his God is in his heart. Ps. Be not emulous of evildoers, nor envy them that work iniquity.",
+            )),
+            collect: Some(tp(
+                None,
+                "Adésto, Dómine, supplicatiónibus nostris, quas in beáti N. Confessóris tui solemnitáte deférimus: ut, qui nostrae justítiae fidúciam non habémus, ejus, qui tibi plácuit, précibus adjuvémur.",
+                "Give ear, O Lord, to the prayers we offer on the festival of blessed N., Thy Confessor: that we who have no confidence in our own righteousness may be helped by the prayers of him who pleased Thee.",
+            )),
+            gradual: Some(tp(
+                Some("Ps 91:13, 3; James 1:12"),
+                "Justus ut palma florébit: sicut cedrus Líbani multiplicábitur in domo Dómini. V. Ad annuntiándum mane misericórdiam tuam, et veritátem tuam per noctem. Allelúja, allelúja. V. Beátus vir, qui suffert tentatiónem: quóniam, cum probátus fúerit, accípiet corónam vitae. Allelúja.",
+                "The just shall flourish like the palm tree: he shall grow up like the cedar of Libanus in the house of the Lord. V. To show forth Thy mercy in the morning, and Thy truth in the night. Alleluia, alleluia. V. Blessed is the man that endureth temptation: for when he hath been proved, he shall receive the crown of life. Alleluia.",
+            )),
+            offertory: Some(tp(
+                Some("Ps 88:25"),
+                "Véritas mea et misericórdia mea cum ipso: et in nómine meo exaltábitur cornu ejus.",
+                "My truth and My mercy shall be with him: and in My name shall his horn be exalted.",
+            )),
+            secret: Some(tp(
+                None,
+                "Laudis tibi, Dómine, hóstias immolámus in tuórum commemoratióne Sanctórum: quibus nos et praeséntibus éxui malis confídimus et futúris.",
+                "We offer Thee, O Lord, sacrifices of praise in memory of Thy Saints, by which we trust to be delivered from evils both present and to come.",
+            )),
+            communion: Some(tp(
+                Some("Matt 24:46-47"),
+                "Beátus servus, quem, cum vénerit dóminus, invénerit vigilántem: amen dico vobis, super ómnia bona sua constítuet eum.",
+                "Blessed is that servant whom, when his lord shall come, he shall find watching: amen I say to you, he shall set him over all his goods.",
+            )),
+            postcommunion: Some(tp(
+                None,
+                "Refécti cibo potúque caelésti, Deus noster, te súpplices deprecámur: ut, in cujus haec commemoratióne percépimus, ejus muniámur et précibus.",
+                "Refreshed with heavenly food and drink, we humbly pray Thee, O our God, that we may be strengthened by the prayers of him in whose commemoration we have received them.",
+            )),
+            last_gospel: Some(last_gospel()),
+            ..Default::default()
+        },
+
+        Common::Virgin => ProperTexts {
+            introit: Some(tp(
+                Some("Ps 44:8, 2"),
+                "Dilexísti justítiam, et odísti iniquitátem: proptérea unxit te Deus, Deus tuus, óleo laetítiae prae consórtibus tuis. Ps. Eructávit cor meum verbum bonum: dico ego ópera mea Regi.",
+                "Thou hast loved justice and hated iniquity: therefore God, thy God, hath anointed thee with the oil of gladness above thy fellows. Ps. My heart hath uttered a good word: I speak my works to the King.",
+            )),
+            collect: Some(tp(
+                None,
+                "Exáudi nos, Deus, salutáris noster: ut, sicut de beátae N. Vírginis festivitáte gaudémus; ita piae devotiónis erudiámur afféctu.",
+                "Hear us, O God our Saviour: that as we rejoice in the festival of blessed N., Thy Virgin, so we may be taught the affection of loving devotion.",
+            )),
+            gradual: Some(tp(
+                Some("Ps 44:8, 15"),
+                "Dilexísti justítiam, et odísti iniquitátem. V. Proptérea unxit te Deus, Deus tuus, óleo laetítiae. Allelúja, allelúja. V. Adducéntur regi vírgines post eam: próximae ejus afferéntur tibi in laetítia. Allelúja.",
+                "Thou hast loved justice and hated iniquity. V. Therefore God, thy God, hath anointed thee with the oil of gladness. Alleluia, alleluia. V. After her shall virgins be brought to the King: her neighbours shall be brought to Thee with gladness. Alleluia.",
+            )),
+            offertory: Some(tp(
+                Some("Ps 44:10"),
+                "Fíliae regum in honóre tuo, ástitit regína a dextris tuis in vestítu deauráto, circúmdata varietáte.",
+                "The daughters of kings are in thy glory; the queen stood on thy right hand in gilded clothing, surrounded with variety.",
+            )),
+            secret: Some(tp(
+                None,
+                "Accépta tibi sit, Dómine, sacrátae plebis oblátio pro tuórum honóre Sanctórum: quorum se méritis de tribulatióne percepísse cognóscit auxílium.",
+                "May the offering of Thy consecrated people be acceptable to Thee, O Lord, in honour of Thy Saints, by whose merits it knows it has received help in tribulation.",
+            )),
+            communion: Some(tp(
+                Some("Matt 25:4, 6"),
+                "Quinque prudéntes vírgines accepérunt óleum in vasis suis cum lampádibus: média autem nocte clamor factus est: Ecce, sponsus venit: exíte óbviam Christo Dómino.",
+                "The five wise virgins took oil in their vessels with the lamps: and at midnight there was a cry made: Behold the bridegroom cometh, go ye forth to meet Christ the Lord.",
+            )),
+            postcommunion: Some(tp(
+                None,
+                "Satiásti, Dómine, famíliam tuam munéribus sacris: ejus, quáesumus, semper interventióne nos réfove, cujus solémnia celebrámus.",
+                "Thou hast filled Thy household, O Lord, with sacred gifts: ever refresh us, we beseech Thee, by the intercession of her whose festival we celebrate.",
+            )),
+            last_gospel: Some(last_gospel()),
+            ..Default::default()
+        },
+
+        Common::Martyrs => ProperTexts {
+            introit: Some(tp(
+                Some("Ps 78:11, 12, 10, 1"),
+                "Intret in conspéctu tuo, Dómine, gémitus compeditórum: redde vicínis nostris séptuplum in sinu eórum: víndica sánguinem Sanctórum tuórum, qui effúsus est. Ps. Deus, venérunt gentes in hereditátem tuam: polluérunt templum sanctum tuum: posuérunt Jerúsalem in pomórum custódiam.",
+                "Let the sighing of the prisoners come in before Thee, O Lord: render to our neighbours sevenfold in their bosom: revenge the blood of Thy Saints which hath been shed. Ps. O God, the heathens are come into Thy inheritance: they have defiled Thy holy temple: they have made Jerusalem as a place to keep fruit.",
+            )),
+            collect: Some(tp(
+                None,
+                "Deus, qui nos concédis sanctórum Mártyrum tuórum N. et N. natalítia cólere: da nobis in aetérna beatitúdine de eórum societáte gaudére.",
+                "O God, who grantest us to celebrate the birthday of Thy holy Martyrs N. and N.: grant us to rejoice in their fellowship in everlasting bliss.",
+            )),
+            gradual: Some(tp(
+                Some("Exod 15:11, 6"),
+                "Gloriósus Deus in Sanctis suis, mirábilis in majestáte, fáciens prodígia. V. Déxtera tua, Dómine, glorificáta est in virtúte: déxtera manus tua confrégit inimícos. Allelúja, allelúja. V. Te Mártyrum candidátus laudat exércitus, Dómine. Allelúja.",
+                "God is glorious in His Saints, wonderful in majesty, doing wonders. V. Thy right hand, O Lord, is magnified in strength: Thy right hand hath slain the enemy. Alleluia, alleluia. V. The white-robed army of Martyrs praiseth Thee, O Lord. Alleluia.",
+            )),
+            offertory: Some(tp(
+                Some("Ps 67:36"),
+                "Mirábilis Deus in Sanctis suis: Deus Israël ipse dabit virtútem et fortitúdinem plebi suae: benedíctus Deus, allelúja.",
+                "God is wonderful in His Saints: the God of Israel is He who will give power and strength to His people: blessed be God, alleluia.",
+            )),
+            secret: Some(tp(
+                None,
+                "Múnera tibi, Dómine, nostrae devotiónis offérimus: quae et pro tuórum tibi grata sint honóre Justórum, et nobis salutária, te miseránte, reddántur.",
+                "We offer Thee, O Lord, the gifts of our devotion: may they be pleasing to Thee for the honour of Thy just ones, and by Thy mercy be made profitable to us for salvation.",
+            )),
+            communion: Some(tp(
+                Some("Wis 3:1, 2, 3"),
+                "Justórum ánimae in manu Dei sunt, et non tanget illos torméntum malítiae: visi sunt óculis insipiéntium mori: illi autem sunt in pace.",
+                "The souls of the just are in the hand of God, and the torment of malice shall not touch them: in the sight of the unwise they seemed to die, but they are in peace.",
+            )),
+            postcommunion: Some(tp(
+                None,
+                "Praesta nobis, quáesumus, Dómine: intercedéntibus sanctis Martýribus tuis N. et N.; ut, quod ore contíngimus, pura mente capiámus.",
+                "Grant us, we beseech Thee, O Lord, by the intercession of Thy holy Martyrs N. and N., that what we receive with our lips we may take with a pure mind.",
+            )),
+            last_gospel: Some(last_gospel()),
+            ..Default::default()
+        },
+
+        Common::HolyWoman => ProperTexts {
+            introit: Some(tp(
+                Some("Ps 118:75, 120, 1"),
+                "Cognóvi, Dómine, quia aéquitas judícia tua, et in veritáte tua humiliásti me: confíge timóre tuo carnes meas, a mandátis tuis tímui. Ps. Beáti immaculáti in via: qui ámbulant in lege Dómini.",
+                "I knew, O Lord, that Thy judgments are equity, and in Thy truth Thou hast humbled me: pierce Thou my flesh with Thy fear: I am afraid of Thy judgments. Ps. Blessed are the undefiled in the way, who walk in the law of the Lord.",
+            )),
+            collect: Some(tp(
+                None,
+                "Exáudi nos, Deus, salutáris noster: ut, sicut de beátae N. festivitáte gaudémus; ita piae devotiónis erudiámur afféctu.",
+                "Hear us, O God our Saviour: that as we rejoice in the festival of blessed N., so we may be taught the affection of loving devotion.",
+            )),
+            gradual: Some(tp(
+                Some("Ps 44:3, 5"),
+                "Diffúsa est grátia in lábiis tuis: proptérea benedíxit te Deus in aetérnum. V. Propter veritátem, et mansuetúdinem, et justítiam: et dedúcet te mirabíliter déxtera tua. Allelúja, allelúja. V. Spécie tua et pulchritúdine tua inténde, próspere procéde, et regna. Allelúja.",
+                "Grace is poured abroad in thy lips: therefore hath God blessed thee for ever. V. Because of truth, and meekness, and justice: and thy right hand shall conduct thee wonderfully. Alleluia, alleluia. V. With thy comeliness and thy beauty set out, proceed prosperously, and reign. Alleluia.",
+            )),
+            offertory: Some(tp(
+                Some("Ps 44:3"),
+                "Diffúsa est grátia in lábiis tuis: proptérea benedíxit te Deus in aetérnum, et in sáeculum sáeculi.",
+                "Grace is poured abroad in thy lips: therefore hath God blessed thee for ever, and for ages of ages.",
+            )),
+            secret: Some(tp(
+                None,
+                "Accépta tibi sit, Dómine, sacrátae plebis oblátio pro tuórum honóre Sanctórum: quorum se méritis de tribulatióne percepísse cognóscit auxílium.",
+                "May the offering of Thy consecrated people be acceptable to Thee, O Lord, in honour of Thy Saints, by whose merits it knows it has received help in tribulation.",
+            )),
+            communion: Some(tp(
+                Some("Ps 44:8"),
+                "Dilexísti justítiam, et odísti iniquitátem: proptérea unxit te Deus, Deus tuus, óleo laetítiae prae consórtibus tuis.",
+                "Thou hast loved justice and hated iniquity: therefore God, thy God, hath anointed thee with the oil of gladness above thy fellows.",
+            )),
+            postcommunion: Some(tp(
+                None,
+                "Satiásti, Dómine, famíliam tuam munéribus sacris: ejus, quáesumus, semper interventióne nos réfove, cujus solémnia celebrámus.",
+                "Thou hast filled Thy household, O Lord, with sacred gifts: ever refresh us, we beseech Thee, by the intercession of her whose festival we celebrate.",
+            )),
+            last_gospel: Some(last_gospel()),
+            ..Default::default()
+        },
+    }
+}
+
+/// The texts of a Common with the saint's name said for each "N.", and for
+/// "N. et N." where there are two. The Latin case is that the word before
+/// calls for: "beátus N." nominative, "beátum N." accusative, "beáto N."
+/// and "tuis N. et N." ablative, and otherwise genitive.
+pub fn with_name(texts: ProperTexts, name: &SaintName) -> ProperTexts {
+    let named = |part: Option<TextPair>| {
+        part.map(|p| TextPair {
+            latin: put_name(&p.latin, name),
+            vernacular: p.vernacular.map(|v| v.replace("N. and N.", "N.").replace("N.", &name.english)),
+            ..p
+        })
+    };
+    ProperTexts {
+        introit: named(texts.introit),
+        collect: named(texts.collect),
+        epistle: named(texts.epistle),
+        gradual: named(texts.gradual),
+        sequence: named(texts.sequence),
+        gospel: named(texts.gospel),
+        offertory: named(texts.offertory),
+        secret: named(texts.secret),
+        communion: named(texts.communion),
+        postcommunion: named(texts.postcommunion),
+        last_gospel: texts.last_gospel,
+    }
+}
+
+fn put_name(latin: &str, name: &SaintName) -> String {
+    let mut out = String::new();
+    let mut rest = latin;
+    while let Some(i) = rest.find(" N.") {
+        let (before, after) = rest.split_at(i + 1);
+        let word = before.split_whitespace().last().unwrap_or_default();
+        let form = match latin::render(word, LatinStyle::Classical).to_lowercase().as_str() {
+            "beatus" => &name.nominative,
+            "beatum" => &name.accusative,
+            "beato" | "tuis" => &name.ablative,
+            _ => &name.genitive,
+        };
+        out.push_str(before);
+        out.push_str(form);
+        rest = after.strip_prefix("N. et N.").unwrap_or(&after[2..]);
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commons_are_complete() {
+        for c in [
+            Common::ConfessorBishop,
+            Common::Doctors,
+            Common::Confessor,
+            Common::Virgin,
+            Common::Martyrs,
+            Common::HolyWoman,
+        ] {
+            let p = get_common(c);
+            assert!(p.introit.is_some() && p.collect.is_some() && p.gradual.is_some(), "{:?}", c);
+            assert!(p.offertory.is_some() && p.secret.is_some(), "{:?}", c);
+            assert!(p.communion.is_some() && p.postcommunion.is_some(), "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn test_name_in_each_case() {
+        let augustine = SaintName {
+            nominative: "Augustínus".into(),
+            genitive: "Augustíni".into(),
+            accusative: "Augustínum".into(),
+            ablative: "Augustíno".into(),
+            english: "Augustine".into(),
+        };
+        let p = with_name(get_common(Common::Doctors), &augustine);
+        assert!(p.collect.unwrap().latin.contains("beátum Augustínum minístrum"));
+        assert!(p.secret.unwrap().latin.starts_with("Sancti Augustíni Confessóris"));
+        let post = p.postcommunion.unwrap();
+        assert!(post.latin.contains("beátus Augustínus Conféssor"));
+        assert!(post.vernacular.unwrap().contains("blessed Augustine, Thy Confessor"));
+        let bishop = with_name(get_common(Common::ConfessorBishop), &augustine);
+        assert!(bishop.postcommunion.unwrap().latin.contains("beáto Augustíno Confessóre"));
+    }
+}
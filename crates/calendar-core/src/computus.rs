@@ -1,8 +1,8 @@
 use chrono::NaiveDate;
 
 /// Computes the date of Easter Sunday for a given year using the Anonymous
-/// Gregorian algorithm (Meeus/Jones/Butcher). Valid for all years in the
-/// Gregorian calendar.
+/// Gregorian algorithm (Meeus/Jones/Butcher). Before 1583 the result is
+/// proleptic: Rome then kept the Julian Easter of `julian::easter`.
 pub fn easter(year: i32) -> NaiveDate {
     let a = year % 19;
     let b = year / 100;
@@ -22,7 +22,7 @@ pub fn easter(year: i32) -> NaiveDate {
         .expect("Easter computation produced invalid date")
 }
 
-use crate::types::MoveableFeasts;
+use crate::types::{roman, ComputusData, MoveableFeasts};
 use chrono::{Datelike, Duration};
 
 /// Compute all moveable feasts for a given year.
@@ -126,6 +126,118 @@ pub fn moveable_feasts(year: i32) -> MoveableFeasts {
     }
 }
 
+/// Golden number and Gregorian epact of a year. The epact is the Julian one
+/// (11 days a year through the lunar cycle) corrected by the solar equation
+/// for the dropped leap days and the lunar equation for the drift of the
+/// nineteen-year cycle, eight days in twenty-five centuries.
+fn golden_number_and_epact(year: i32) -> (i32, i32) {
+    let golden = year.rem_euclid(19) + 1;
+    let century = year.div_euclid(100) + 1;
+    let solar = 3 * century / 4 - 12;
+    let lunar = (8 * century + 5) / 25 - 5;
+    let epact = (11 * golden + 20 + lunar - solar).rem_euclid(30);
+    (golden, epact)
+}
+
+/// Days of the calendarium on which the epacts xxv and xxiv share one day,
+/// making the lunations that run through them hollow (29 days)
+const HOLLOW_DAYS: [(u32, u32); 6] = [(2, 5), (4, 5), (6, 3), (8, 1), (9, 29), (11, 27)];
+
+/// Epact written against a day in the calendarium: * on the 1st of January,
+/// then one less each day, two less after a day shared by xxv and xxiv.
+/// The bissextile day shares the epact of the 24th of February.
+fn calendarium_label(date: NaiveDate) -> i32 {
+    let mut day = date.ordinal0() as i32;
+    if date.leap_year() && date > NaiveDate::from_ymd_opt(date.year(), 2, 24).unwrap() {
+        day -= 1;
+    }
+    let doubled = HOLLOW_DAYS
+        .iter()
+        .filter(|&&(m, d)| (date.month(), date.day()) > (m, d))
+        .count() as i32;
+    (-day - doubled).rem_euclid(30)
+}
+
+/// Whether the new moon of the year's epact falls on this day
+fn is_new_moon(date: NaiveDate) -> bool {
+    let (golden, epact) = golden_number_and_epact(date.year());
+    let label = calendarium_label(date);
+    let hollow_day = HOLLOW_DAYS.contains(&(date.month(), date.day()));
+    match epact {
+        24 if hollow_day => label == 25,
+        // The Arabic 25 stands with xxvi before a shared day, with xxv elsewhere
+        25 if golden > 11 => {
+            let before_hollow = date.succ_opt().is_some_and(|n| HOLLOW_DAYS.contains(&(n.month(), n.day())));
+            if before_hollow { label == 26 } else { label == 25 && !hollow_day }
+        }
+        e => label == e,
+    }
+}
+
+/// Age of the moon on a date by the ecclesiastical tables, 1 on the day of
+/// the new moon, as the Martyrology announces it ("Luna decima quarta")
+pub fn lunar_age(date: NaiveDate) -> u8 {
+    let mut day = date;
+    for age in 1..=30 {
+        if is_new_moon(day) {
+            return age;
+        }
+        day = day.pred_opt().expect("date within chrono's range");
+    }
+    // Unreachable with the Lilian tables; keep the arithmetic age
+    let (_, epact) = golden_number_and_epact(date.year());
+    ((epact - calendarium_label(date)).rem_euclid(30) + 1) as u8
+}
+
+/// Letters of the first Sunday of the year, A for the 1st of January to G
+/// for the 7th; a leap year takes the letter before it from the bissextile
+fn dominical_letters(year: i32) -> String {
+    const LETTERS: [char; 7] = ['A', 'B', 'C', 'D', 'E', 'F', 'G'];
+    let jan1 = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+    let first = (7 - jan1.weekday().num_days_from_sunday() as usize) % 7;
+    let mut letters = LETTERS[first].to_string();
+    if jan1.leap_year() {
+        letters.push(LETTERS[(first + 6) % 7]);
+    }
+    letters
+}
+
+/// Golden number, epact, dominical letters, solar cycle and indiction of a
+/// Gregorian year, with the paschal full moon and Easter they give
+pub fn computus_data(year: i32) -> ComputusData {
+    let (golden, epact) = golden_number_and_epact(year);
+    // The paschal term is the 14th day of the moon whose new moon falls
+    // from the 8th of March to the 5th of April: the 21st of March with
+    // epact xxiii, one day later for each epact less, with epacts xxiv and
+    // xxv on the 18th and the Arabic 25 on the 17th of April
+    let mut term = 44 - epact;
+    if term < 21 {
+        term += 30;
+    }
+    if epact == 24 || (epact == 25 && golden > 11) {
+        term -= 1;
+    }
+    let march = NaiveDate::from_ymd_opt(year, 3, 1).unwrap();
+    let paschal_full_moon = march + Duration::days(term as i64 - 1);
+    let to_sunday = 7 - paschal_full_moon.weekday().num_days_from_sunday() as i64;
+    let epact_label = match epact {
+        0 => "*".to_string(),
+        25 if golden > 11 => "25".to_string(),
+        e => roman(e as u32).to_lowercase(),
+    };
+    ComputusData {
+        year,
+        golden_number: golden as u8,
+        epact: epact as u8,
+        epact_label,
+        dominical_letters: dominical_letters(year),
+        solar_cycle: ((year + 8).rem_euclid(28) + 1) as u8,
+        indiction: ((year + 2).rem_euclid(15) + 1) as u8,
+        paschal_full_moon,
+        easter: paschal_full_moon + Duration::days(to_sunday),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,4 +341,41 @@ mod tests {
         assert_eq!(mf.rogation_days[2].weekday(), Weekday::Wed);
         assert_eq!(mf.rogation_days[2] + Duration::days(1), mf.ascension);
     }
+
+    #[test]
+    fn test_computus_data() {
+        let c = computus_data(2026);
+        assert_eq!(c.golden_number, 13);
+        assert_eq!(c.epact, 11);
+        assert_eq!(c.epact_label, "xi");
+        assert_eq!(c.dominical_letters, "D");
+        assert_eq!(c.solar_cycle, 19);
+        assert_eq!(c.indiction, 4);
+        assert_eq!(c.paschal_full_moon, NaiveDate::from_ymd_opt(2026, 4, 2).unwrap());
+        assert_eq!(computus_data(2024).dominical_letters, "GF");
+        assert_eq!(computus_data(2025).epact_label, "*");
+        // The Arabic 25 moves the paschal term a day earlier
+        let c = computus_data(1954);
+        assert_eq!((c.golden_number, c.epact_label.as_str()), (17, "25"));
+        assert_eq!(c.paschal_full_moon, NaiveDate::from_ymd_opt(1954, 4, 17).unwrap());
+    }
+
+    #[test]
+    fn test_lilian_tables_agree_with_meeus() {
+        for year in 1583..=4200 {
+            assert_eq!(computus_data(year).easter, easter(year), "Easter {}", year);
+        }
+    }
+
+    #[test]
+    fn test_lunar_age() {
+        // The paschal full moon is the fourteenth day of the moon
+        for year in 1900..=2100 {
+            let c = computus_data(year);
+            assert_eq!(lunar_age(c.paschal_full_moon), 14, "{}", c.paschal_full_moon);
+        }
+        // Epact xi: the moon is twelve days old on the 1st of January
+        assert_eq!(lunar_age(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()), 12);
+        assert_eq!(lunar_age(NaiveDate::from_ymd_opt(2026, 1, 20).unwrap()), 1);
+    }
 }
@@ -0,0 +1,416 @@
+//! From a liturgical designation to a date: "Dominica III Adventus 2027",
+//! "Ember Saturday of September 2030", "5th Sunday after Pentecost next
+//! year", "st-joseph 2029".
+//!
+//! A designation names a celebration by its id, by its Latin or English
+//! title, or as an Ember day, and optionally a year. It is resolved in the
+//! calendar of that year, so a feast that is transferred is found where it
+//! is kept.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::Calendar;
+use crate::computus::moveable_feasts;
+use crate::latin::{self, LatinStyle};
+use crate::types::*;
+
+/// What a designation names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// A celebration id, e.g. "st-joseph"
+    Id(String),
+    /// Words of a title, normalized: unaccented, lowercase, numbers as digits
+    Title(Vec<String>),
+    /// An Ember day: the Ember week of Lent, Pentecost, September or Advent
+    /// (0 to 3) and Wednesday, Friday or Saturday
+    Ember { week: usize, day: Weekday },
+}
+
+/// The year of a designation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearRef {
+    Absolute(i32),
+    /// Years from the current one: "next year" is 1, no year at all 0
+    Relative(i32),
+}
+
+/// A parsed designation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Designation {
+    pub target: Target,
+    pub year: YearRef,
+}
+
+/// Where a designation was found
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Resolution {
+    pub date: NaiveDate,
+    pub id: String,
+    pub title: String,
+    /// Celebrated, or only commemorated or omitted when nowhere celebrated
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DesignationError {
+    Invalid(String),
+    NotFound(String),
+    Ambiguous(Vec<String>),
+}
+
+impl fmt::Display for DesignationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DesignationError::Invalid(text) => write!(f, "invalid designation: {}", text),
+            DesignationError::NotFound(text) => write!(f, "no celebration matches: {}", text),
+            DesignationError::Ambiguous(ids) => write!(f, "ambiguous designation: {}", ids.join(", ")),
+        }
+    }
+}
+
+impl std::error::Error for DesignationError {}
+
+const ORDINALS: [&str; 28] = [
+    "first", "second", "third", "fourth", "fifth", "sixth", "seventh", "eighth", "ninth", "tenth",
+    "eleventh", "twelfth", "thirteenth", "fourteenth", "fifteenth", "sixteenth", "seventeenth",
+    "eighteenth", "nineteenth", "twentieth", "twenty-first", "twenty-second", "twenty-third",
+    "twenty-fourth", "twenty-fifth", "twenty-sixth", "twenty-seventh", "twenty-eighth",
+];
+
+/// Value of a small Roman numeral such as "iii" or "xxiv"
+fn roman_value(token: &str) -> Option<u32> {
+    if token.is_empty() || !token.chars().all(|c| matches!(c, 'i' | 'v' | 'x' | 'l')) {
+        return None;
+    }
+    let value = |c| match c {
+        'i' => 1,
+        'v' => 5,
+        'x' => 10,
+        _ => 50,
+    };
+    let digits: Vec<u32> = token.chars().map(value).collect();
+    let mut total = 0;
+    for (i, d) in digits.iter().enumerate() {
+        match digits.get(i + 1) {
+            Some(next) if next > d => total -= *d as i32,
+            _ => total += *d as i32,
+        }
+    }
+    // Only numerals written the standard way
+    (total > 0 && roman(total as u32).eq_ignore_ascii_case(token)).then_some(total as u32)
+}
+
+/// Normalize a title or a query for comparison: no accents or ligatures,
+/// lowercase, ordinals and Roman numerals as digits, "Saint" as "st"
+fn words(text: &str) -> Vec<String> {
+    let text = latin::render(text, LatinStyle::Classical)
+        .to_lowercase()
+        .replace('æ', "ae")
+        .replace('œ', "oe");
+    let mut out: Vec<String> = Vec::new();
+    for token in text.split(|c: char| !(c.is_alphanumeric() || c == '-')) {
+        let token = token.trim_matches('-');
+        if token.is_empty() {
+            continue;
+        }
+        let digits = token.trim_end_matches(|c: char| c.is_alphabetic());
+        let word = if let Some(n) = ORDINALS.iter().position(|o| *o == token) {
+            (n + 1).to_string()
+        } else if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+            digits.to_string()
+        } else if let Some(n) = roman_value(token) {
+            n.to_string()
+        } else {
+            match token {
+                "saint" => "st".to_string(),
+                "saints" => "sts".to_string(),
+                "sancti" | "sanctae" | "sancta" => "s".to_string(),
+                _ => token.to_string(),
+            }
+        };
+        out.extend(word.split('-').map(String::from));
+    }
+    // "5th Sunday of the Time after Pentecost" reads "5th Sunday after Pentecost"
+    let joined = out.join(" ").replace("of the time after", "after");
+    joined.split_whitespace().map(String::from).collect()
+}
+
+/// Split off the year, from the end: "2027", "in 2027", "next year"
+fn split_year(tokens: &[&str]) -> (usize, YearRef) {
+    let n = tokens.len();
+    let last = |k: usize| tokens[n - k..].join(" ").to_lowercase();
+    if n >= 2 {
+        match last(2).as_str() {
+            "next year" | "anno proximo" | "anno sequenti" => return (n - 2, YearRef::Relative(1)),
+            "last year" | "anno praeterito" => return (n - 2, YearRef::Relative(-1)),
+            "this year" | "anno currenti" => return (n - 2, YearRef::Relative(0)),
+            _ => {}
+        }
+    }
+    if let Some(year) = tokens.last().filter(|t| t.len() == 4).and_then(|t| t.parse::<i32>().ok()) {
+        let before = match tokens.get(n.wrapping_sub(2)).map(|t| t.to_lowercase()) {
+            Some(t) if t == "in" || t == "anno" => n - 2,
+            _ => n - 1,
+        };
+        return (before, YearRef::Absolute(year));
+    }
+    (n, YearRef::Relative(0))
+}
+
+/// An Ember day named in English or Latin
+fn ember(words: &[String]) -> Option<Result<Target, ()>> {
+    let has = |w: &str| words.iter().any(|x| x == w);
+    let named = has("ember") || (has("quatuor") && has("temporum"));
+    if !named {
+        return None;
+    }
+    let day = if has("wednesday") || has("4") {
+        Weekday::Wed
+    } else if has("friday") || has("6") {
+        Weekday::Fri
+    } else if has("saturday") || has("sabbato") || has("sabbatum") {
+        Weekday::Sat
+    } else {
+        return Some(Err(()));
+    };
+    let weeks: [&[&str]; 4] = [
+        &["lent", "quadragesima", "quadragesimae", "february", "march"],
+        &["pentecost", "pentecostes", "whit", "whitsun", "may", "june"],
+        &["september", "septembris"],
+        &["advent", "adventus", "december", "decembris"],
+    ];
+    let week = weeks.iter().position(|names| names.iter().any(|n| has(n)));
+    Some(week.map(|week| Target::Ember { week, day }).ok_or(()))
+}
+
+impl FromStr for Designation {
+    type Err = DesignationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DesignationError::Invalid(s.to_string());
+        // Parenthetical remarks such as "(as transferred)" change nothing:
+        // a designation is always resolved where the feast is kept
+        let text = match s.split_once('(') {
+            Some((head, rest)) => format!("{} {}", head, rest.split_once(')').map(|(_, tail)| tail).unwrap_or("")),
+            None => s.to_string(),
+        };
+        let tokens: Vec<&str> = text.split_whitespace().collect();
+        let (end, year) = split_year(&tokens);
+        let body = &tokens[..end];
+        if body.is_empty() {
+            return Err(invalid());
+        }
+        if let [id] = body {
+            if id.contains('-') && id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+                return Ok(Designation { target: Target::Id(id.to_string()), year });
+            }
+        }
+        let mut words = words(&body.join(" "));
+        if let Some(target) = ember(&words) {
+            return Ok(Designation { target: target.map_err(|_| invalid())?, year });
+        }
+        strip_filler(&mut words);
+        if words.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Designation { target: Target::Title(words), year })
+    }
+}
+
+/// Drop the words a designation or title may open with: "Feast of the",
+/// "In festo"
+fn strip_filler(words: &mut Vec<String>) {
+    let filler = ["feast", "solemnity", "of", "the", "festum", "festo", "in"];
+    while words.len() > 1 && filler.contains(&words[0].as_str()) {
+        words.remove(0);
+    }
+}
+
+/// How well a celebration matches the query: by its whole title or id, by
+/// the part of the title before the first comma, by the first words of the
+/// title, or by words within it
+fn match_rank(query: &[String], id: &str, title: &str) -> Option<u8> {
+    let normalized = |text: &str| {
+        let mut w = words(text);
+        strip_filler(&mut w);
+        w
+    };
+    let full = normalized(title);
+    let head = normalized(title.split(',').next().unwrap_or(title));
+    let id_words: Vec<&str> = id.split('-').collect();
+    let with_sunday: Vec<String> = query.iter().cloned().chain(["sunday".to_string()]).collect();
+    if full == query || full == with_sunday || id_words == query {
+        Some(0)
+    } else if head == query {
+        Some(1)
+    } else if full.starts_with(query) {
+        Some(2)
+    } else if full.windows(query.len()).any(|w| w == query) {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+impl Designation {
+    /// The calendar year designated, counting relative years from `today`
+    pub fn year(&self, today: NaiveDate) -> i32 {
+        match self.year {
+            YearRef::Absolute(year) => year,
+            YearRef::Relative(offset) => today.year() + offset,
+        }
+    }
+
+    /// Find the designation in a calendar of its year
+    pub fn resolve_in(&self, calendar: &Calendar) -> Result<Resolution, DesignationError> {
+        let id = match &self.target {
+            Target::Id(id) => id.clone(),
+            Target::Ember { week, day } => {
+                let mf = moveable_feasts(calendar.year());
+                let offset = match day {
+                    Weekday::Wed => 0,
+                    Weekday::Fri => 1,
+                    _ => 2,
+                };
+                let date = mf.ember_days[week * 3 + offset];
+                // Found by date: the Whitsun Ember days are days of the
+                // Pentecost Octave, the others `ember-{MM-DD}`
+                let is_ember = |id: &&String| id.starts_with("ember-") || id.starts_with("pentecost-octave-");
+                calendar
+                    .get(date)
+                    .and_then(|day| {
+                        let mut ids = std::iter::once(&day.celebration.id)
+                            .chain(day.commemorations.iter().map(|c| &c.id))
+                            .chain(day.trace.iter().flat_map(|t| &t.candidates).map(|c| &c.id));
+                        ids.find(is_ember).cloned()
+                    })
+                    .ok_or_else(|| DesignationError::NotFound(format!("Ember day of {date}")))?
+            }
+            Target::Title(query) => self.match_title(calendar, query)?,
+        };
+        find(calendar, &id).ok_or(DesignationError::NotFound(id))
+    }
+
+    fn match_title(&self, calendar: &Calendar, query: &[String]) -> Result<String, DesignationError> {
+        let mut best: Option<u8> = None;
+        let mut ids: Vec<String> = Vec::new();
+        for day in calendar.days().values() {
+            let celebrations = std::iter::once(&day.celebration)
+                .chain(&day.commemorations)
+                .chain(&day.options)
+                .map(|c| (&c.id, vec![&c.title, c.title_vernacular.as_ref().unwrap_or(&c.title)]));
+            let candidates = day.trace.iter().flat_map(|t| &t.candidates).map(|c| (&c.id, vec![&c.title]));
+            for (id, titles) in celebrations.chain(candidates) {
+                let Some(rank) = titles.iter().filter_map(|t| match_rank(query, id, t)).min() else {
+                    continue;
+                };
+                if best.is_none_or(|b| rank < b) {
+                    best = Some(rank);
+                    ids.clear();
+                }
+                if best == Some(rank) && !ids.contains(id) {
+                    ids.push(id.clone());
+                }
+            }
+        }
+        match ids.len() {
+            0 => Err(DesignationError::NotFound(query.join(" "))),
+            1 => Ok(ids.remove(0)),
+            _ => Err(DesignationError::Ambiguous(ids)),
+        }
+    }
+}
+
+/// The day a celebration is kept, or failing that commemorated, or the day
+/// it was omitted
+fn find(calendar: &Calendar, id: &str) -> Option<Resolution> {
+    let days = calendar.days().values();
+    let found = |day: &LiturgicalDay, c: &Celebration, outcome| Resolution {
+        date: day.date,
+        id: c.id.clone(),
+        title: c.title.clone(),
+        outcome,
+    };
+    days.clone()
+        .find(|d| d.celebration.id == id)
+        .map(|d| found(d, &d.celebration, Outcome::Celebrated))
+        .or_else(|| {
+            days.clone().find_map(|d| {
+                d.commemorations.iter().find(|c| c.id == id).map(|c| found(d, c, Outcome::Commemorated))
+            })
+        })
+        .or_else(|| {
+            days.clone().find_map(|d| {
+                let candidate = d.trace.as_ref()?.candidates.iter().find(|c| c.id == id)?;
+                Some(Resolution {
+                    date: d.date,
+                    id: candidate.id.clone(),
+                    title: candidate.title.clone(),
+                    outcome: candidate.outcome,
+                })
+            })
+        })
+}
+
+/// Parse a designation and resolve it in the default calendar of its year,
+/// counting "next year" and the like from `today`
+pub fn resolve(text: &str, today: NaiveDate) -> Result<Resolution, DesignationError> {
+    let designation: Designation = text.parse()?;
+    designation.resolve_in(&Calendar::new(designation.year(today)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let des: Designation = "Dominica III Adventus 2027".parse().unwrap();
+        assert_eq!(des.target, Target::Title(vec!["dominica".into(), "3".into(), "adventus".into()]));
+        assert_eq!(des.year, YearRef::Absolute(2027));
+        let des: Designation = "5th Sunday after Pentecost next year".parse().unwrap();
+        assert_eq!(des.year, YearRef::Relative(1));
+        let des: Designation = "Ember Saturday of September 2030".parse().unwrap();
+        assert_eq!(des.target, Target::Ember { week: 2, day: Weekday::Sat });
+        let des: Designation = "st-joseph".parse().unwrap();
+        assert_eq!(des.target, Target::Id("st-joseph".into()));
+        assert!("Ember Day of Lent".parse::<Designation>().is_err());
+        assert!("2027".parse::<Designation>().is_err());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let today = d(2026, 10, 18);
+        let r = resolve("Dominica III Adventus 2027", today).unwrap();
+        assert_eq!((r.date, r.id.as_str()), (d(2027, 12, 12), "sunday-advent-3"));
+        assert_eq!(resolve("third Sunday of Advent 2027", today).unwrap().date, d(2027, 12, 12));
+        let r = resolve("Ember Saturday of September 2030", today).unwrap();
+        assert_eq!(r.date.weekday(), Weekday::Sat);
+        assert_eq!(r.date.month(), 9);
+        let r = resolve("Ember Saturday of Pentecost 2027", today).unwrap();
+        assert_eq!((r.date, r.id.as_str()), (d(2027, 5, 22), "pentecost-octave-6"));
+        assert_eq!(resolve("Ember Wednesday of Whitsun 2027", today).unwrap().date, d(2027, 5, 19));
+        assert_eq!(resolve("Easter next year", today).unwrap().date, d(2027, 3, 28));
+    }
+
+    #[test]
+    fn test_not_found_and_ambiguous() {
+        let today = d(2026, 10, 18);
+        assert!(matches!(resolve("Feast of St. Nobody", today), Err(DesignationError::NotFound(_))));
+        match resolve("Sunday of Advent", today) {
+            Err(DesignationError::Ambiguous(ids)) => assert_eq!(ids.len(), 4),
+            other => panic!("expected ambiguity, got {:?}", other),
+        }
+        // Words within a title, and "Feast of the" dropped
+        assert_eq!(resolve("Palm Sunday", today).unwrap().id, "palm-sunday");
+        assert_eq!(resolve("Feast of the Epiphany", today).unwrap().id, "epiphany");
+    }
+}
@@ -0,0 +1,181 @@
+use crate::types::*;
+
+/// Latin and English titles of a Holy Week day under a rubrical system.
+/// The restored Ordo of 1955 (kept in 1962) renamed Palm Sunday and
+/// Good Friday.
+pub fn titles(celebration_id: &str, system: RubricalSystem) -> Option<(&'static str, &'static str)> {
+    let restored = system != RubricalSystem::PrePius;
+    let t = match celebration_id {
+        "palm-sunday" if restored => ("Domínica II Passiónis seu in Palmis", "Second Sunday of the Passion or Palm Sunday"),
+        "palm-sunday" => ("Domínica in Palmis", "Palm Sunday"),
+        "holy-thursday" => ("Féria V in Cena Dómini", "Holy Thursday"),
+        "good-friday" if restored => ("Féria VI in Passióne et Morte Dómini", "Good Friday of the Passion and Death of the Lord"),
+        "good-friday" => ("Féria VI in Parascéve", "Good Friday"),
+        "holy-saturday" if restored => ("Sábbato Sancto", "Holy Saturday (Easter Vigil)"),
+        "holy-saturday" => ("Sábbato Sancto", "Holy Saturday"),
+        _ => return None,
+    };
+    Some(t)
+}
+
+/// Ceremonies, timing and Vigil lessons of a Holy Week day.
+pub fn rite(celebration_id: &str, system: RubricalSystem) -> Option<HolyWeekRite> {
+    let restored = system != RubricalSystem::PrePius;
+    let (ceremonies, timing, lessons): (&[&str], Option<&str>, &[&str]) = match celebration_id {
+        "palm-sunday" if restored => (
+            &[
+                "Blessing of palms in red vestments with a single prayer",
+                "Procession with Gloria, laus and Hymnus ad Christum Regem",
+                "Mass in violet with the Passion according to St. Matthew (Matt 26:36–27:54)",
+            ],
+            None,
+            &[],
+        ),
+        "palm-sunday" => (
+            &[
+                "Blessing of palms in violet after the manner of a Mass (Lesson, Gradual, Gospel, Preface, Sanctus)",
+                "Procession; Gloria, laus at the closed church door, struck with the processional cross",
+                "Mass with the Passion according to St. Matthew (Matt 26:1–27:66)",
+            ],
+            None,
+            &[],
+        ),
+        "holy-thursday" if restored => (
+            &[
+                "Evening Mass of the Lord's Supper",
+                "Mandatum after the Gospel",
+                "Procession to the altar of repose",
+                "Stripping of the altars",
+            ],
+            Some("Evening, between 5 and 8 p.m."),
+            &[],
+        ),
+        "holy-thursday" => (
+            &[
+                "Mass in the morning",
+                "Procession to the altar of repose",
+                "Stripping of the altars",
+                "Mandatum after the stripping of the altars",
+            ],
+            Some("Morning"),
+            &[],
+        ),
+        "good-friday" if restored => (
+            &[
+                "Lessons and the Passion according to St. John",
+                "Solemn Prayers",
+                "Adoration of the Cross",
+                "Holy Communion of the clergy and faithful",
+            ],
+            Some("Afternoon, about 3 p.m. and not later than 6 p.m."),
+            &[],
+        ),
+        "good-friday" => (
+            &[
+                "Lessons and the Passion according to St. John",
+                "Solemn Prayers",
+                "Adoration of the Cross",
+                "Mass of the Presanctified, at which the celebrant alone communicates",
+            ],
+            Some("Morning"),
+            &[],
+        ),
+        "holy-saturday" if restored => (
+            &[
+                "Blessing of the new fire",
+                "Paschal candle: Lumen Christi and Exsultet",
+                "Four lessons",
+                "First part of the Litany",
+                "Blessing of baptismal water",
+                "Renewal of baptismal promises",
+                "Second part of the Litany",
+                "Mass of the Easter Vigil, concluded by Lauds",
+            ],
+            Some("Night, so that the Mass begins about midnight"),
+            &["Gen 1:1–2:2", "Exod 14:24–15:1", "Isaias 4:1-6", "Deut 31:22-30"],
+        ),
+        "holy-saturday" => (
+            &[
+                "Blessing of the new fire and of the five grains of incense",
+                "Triple candle (Lumen Christi) and Exsultet",
+                "Twelve Prophecies",
+                "Blessing of the font",
+                "First part of the Litany",
+                "Mass of the Easter Vigil, concluded by Vespers",
+            ],
+            Some("Morning"),
+            &[
+                "Gen 1:1–2:2",
+                "Gen 5:31–8:21",
+                "Gen 22:1-19",
+                "Exod 14:24–15:1",
+                "Isaias 54:17–55:11",
+                "Baruch 3:9-38",
+                "Ezech 37:1-14",
+                "Isaias 4:1-6",
+                "Exod 12:1-11",
+                "Jonas 3:1-10",
+                "Deut 31:22-30",
+                "Dan 3:1-24",
+            ],
+        ),
+        _ => return None,
+    };
+    Some(HolyWeekRite {
+        ceremonies: ceremonies.iter().map(|s| s.to_string()).collect(),
+        timing: timing.map(String::from),
+        lessons: lessons.iter().map(|s| s.to_string()).collect(),
+    })
+}
+
+/// Notes for a Holy Week day under a rubrical system.
+pub fn notes(celebration_id: &str, system: RubricalSystem) -> Option<String> {
+    let restored = system != RubricalSystem::PrePius;
+    let n = match celebration_id {
+        "palm-sunday" if restored => "Blessing of palms and procession before Mass.",
+        "palm-sunday" => "Blessing of palms in the form of a dry Mass, and procession before Mass.",
+        "holy-thursday" if restored => "Evening Mass of the Lord's Supper. Mandatum. Repository. Stripping of the altars.",
+        "holy-thursday" => "Morning Mass. Repository. Stripping of the altars. Mandatum.",
+        "good-friday" if restored => "Solemn afternoon liturgy. Veneration of the Cross. Communion of the faithful. Fast and abstinence. No Mass celebrated.",
+        "good-friday" => "Mass of the Presanctified. Veneration of the Cross. Fast and abstinence.",
+        "holy-saturday" if restored => "Restored Easter Vigil at night: new fire, Paschal candle, four lessons, baptismal water, renewal of baptismal promises. First Mass of Easter.",
+        "holy-saturday" => "Morning Vigil: new fire, Paschal candle, twelve prophecies, blessing of the font. First Mass of Easter.",
+        _ => return None,
+    };
+    Some(n.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vigil_lessons_per_system() {
+        let old = rite("holy-saturday", RubricalSystem::PrePius).unwrap();
+        let restored = rite("holy-saturday", RubricalSystem::Rubrics1955).unwrap();
+        assert_eq!(old.lessons.len(), 12);
+        assert_eq!(restored.lessons.len(), 4);
+        // The four lessons kept in 1955 are prophecies 1, 4, 8 and 11
+        for i in [0, 3, 7, 10] {
+            assert!(restored.lessons.contains(&old.lessons[i]));
+        }
+    }
+
+    #[test]
+    fn test_presanctified_only_before_1955() {
+        let old = rite("good-friday", RubricalSystem::PrePius).unwrap();
+        let new = rite("good-friday", RubricalSystem::Rubrics1962).unwrap();
+        assert!(old.ceremonies.iter().any(|c| c.contains("Presanctified")));
+        assert!(!new.ceremonies.iter().any(|c| c.contains("Presanctified")));
+    }
+
+    #[test]
+    fn test_titles_per_system() {
+        assert_eq!(titles("good-friday", RubricalSystem::PrePius).unwrap().0, "Féria VI in Parascéve");
+        assert_eq!(
+            titles("good-friday", RubricalSystem::Rubrics1962).unwrap().0,
+            "Féria VI in Passióne et Morte Dómini"
+        );
+        assert!(titles("easter-sunday", RubricalSystem::Rubrics1962).is_none());
+    }
+}
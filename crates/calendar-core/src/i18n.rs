@@ -0,0 +1,470 @@
+use std::str::FromStr;
+
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+
+use crate::latin::LatinStyle;
+use crate::latin_date;
+use crate::types::*;
+
+/// Language of the vernacular titles, season names and notes.
+///
+/// Latin is always carried in `Celebration::title`; a missing translation
+/// falls back to English and then to Latin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    La,
+    #[default]
+    En,
+    Fr,
+    De,
+    Es,
+    Pt,
+    It,
+    Pl,
+}
+
+impl Locale {
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::La => "la",
+            Locale::En => "en",
+            Locale::Fr => "fr",
+            Locale::De => "de",
+            Locale::Es => "es",
+            Locale::Pt => "pt",
+            Locale::It => "it",
+            Locale::Pl => "pl",
+        }
+    }
+
+    /// Column of the translation tables, which hold fr, de, es, pt, it, pl
+    fn slot(self) -> Option<usize> {
+        match self {
+            Locale::Fr => Some(0),
+            Locale::De => Some(1),
+            Locale::Es => Some(2),
+            Locale::Pt => Some(3),
+            Locale::It => Some(4),
+            Locale::Pl => Some(5),
+            Locale::La | Locale::En => None,
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Accept region tags such as "pt-BR" or "fr_CA"
+        let lang = s.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+        match lang.as_str() {
+            "la" => Ok(Locale::La),
+            "en" => Ok(Locale::En),
+            "fr" => Ok(Locale::Fr),
+            "de" => Ok(Locale::De),
+            "es" => Ok(Locale::Es),
+            "pt" => Ok(Locale::Pt),
+            "it" => Ok(Locale::It),
+            "pl" => Ok(Locale::Pl),
+            _ => Err(format!("Unsupported language: {}", s)),
+        }
+    }
+}
+
+/// Titles of fixed and moveable feasts: fr, de, es, pt, it, pl
+const TITLES: &[(&str, [&str; 6])] = &[
+    // Temporal cycle
+    ("easter-sunday", ["Dimanche de Pâques", "Ostersonntag", "Domingo de Pascua", "Domingo de Páscoa", "Domenica di Pasqua", "Niedziela Wielkanocna"]),
+    ("low-sunday", ["Dimanche de Quasimodo (octave de Pâques)", "Weißer Sonntag (Oktavtag von Ostern)", "Domingo in Albis (octava de Pascua)", "Domingo in Albis (oitava da Páscoa)", "Domenica in Albis (ottava di Pasqua)", "Niedziela Przewodnia (oktawa Wielkanocy)"]),
+    ("ash-wednesday", ["Mercredi des Cendres", "Aschermittwoch", "Miércoles de Ceniza", "Quarta-feira de Cinzas", "Mercoledì delle Ceneri", "Środa Popielcowa"]),
+    ("palm-sunday", ["Dimanche des Rameaux", "Palmsonntag", "Domingo de Ramos", "Domingo de Ramos", "Domenica delle Palme", "Niedziela Palmowa"]),
+    ("holy-thursday", ["Jeudi saint", "Gründonnerstag", "Jueves Santo", "Quinta-feira Santa", "Giovedì santo", "Wielki Czwartek"]),
+    ("good-friday", ["Vendredi saint", "Karfreitag", "Viernes Santo", "Sexta-feira Santa", "Venerdì santo", "Wielki Piątek"]),
+    ("holy-saturday", ["Samedi saint", "Karsamstag", "Sábado Santo", "Sábado Santo", "Sabato santo", "Wielka Sobota"]),
+    ("ascension", ["Ascension de Notre-Seigneur", "Christi Himmelfahrt", "Ascensión del Señor", "Ascensão do Senhor", "Ascensione del Signore", "Wniebowstąpienie Pańskie"]),
+    ("pentecost", ["Dimanche de la Pentecôte", "Pfingstsonntag", "Domingo de Pentecostés", "Domingo de Pentecostes", "Domenica di Pentecoste", "Zesłanie Ducha Świętego"]),
+    ("trinity-sunday", ["Très Sainte Trinité", "Dreifaltigkeitssonntag", "Santísima Trinidad", "Santíssima Trindade", "Santissima Trinità", "Najświętszej Trójcy"]),
+    ("corpus-christi", ["Fête-Dieu", "Fronleichnam", "Corpus Christi", "Corpo de Deus", "Corpus Domini", "Boże Ciało"]),
+    ("sacred-heart", ["Sacré-Cœur de Jésus", "Heiligstes Herz Jesu", "Sagrado Corazón de Jesús", "Sagrado Coração de Jesus", "Sacratissimo Cuore di Gesù", "Najświętszego Serca Pana Jezusa"]),
+    ("christ-the-king", ["Notre-Seigneur Jésus-Christ Roi", "Christkönigsfest", "Nuestro Señor Jesucristo Rey", "Nosso Senhor Jesus Cristo Rei", "Nostro Signore Gesù Cristo Re", "Chrystusa Króla"]),
+    ("last-sunday-after-pentecost", ["Dernier dimanche après la Pentecôte", "Letzter Sonntag nach Pfingsten", "Último domingo después de Pentecostés", "Último domingo depois de Pentecostes", "Ultima domenica dopo Pentecoste", "Ostatnia Niedziela po Zesłaniu Ducha Świętego"]),
+    // Sanctoral cycle
+    ("holy-name-of-jesus", ["Très Saint Nom de Jésus", "Heiligster Name Jesu", "Santísimo Nombre de Jesús", "Santíssimo Nome de Jesus", "Santissimo Nome di Gesù", "Najświętszego Imienia Jezus"]),
+    ("holy-family", ["La Sainte Famille", "Heilige Familie", "La Sagrada Familia", "A Sagrada Família", "Santa Famiglia", "Świętej Rodziny"]),
+    ("circumcision", ["Circoncision de Notre-Seigneur", "Beschneidung des Herrn", "Circuncisión del Señor", "Circuncisão do Senhor", "Circoncisione del Signore", "Obrzezanie Pańskie"]),
+    ("epiphany", ["Épiphanie de Notre-Seigneur", "Erscheinung des Herrn", "Epifanía del Señor", "Epifania do Senhor", "Epifania del Signore", "Objawienie Pańskie"]),
+    ("conversion-of-st-paul", ["Conversion de saint Paul", "Pauli Bekehrung", "Conversión de san Pablo", "Conversão de São Paulo", "Conversione di san Paolo", "Nawrócenie św. Pawła"]),
+    ("ss-fabian-sebastian", ["Saints Fabien et Sébastien, martyrs", "Hll. Fabian und Sebastian, Märtyrer", "Santos Fabián y Sebastián, mártires", "São Fabião e São Sebastião, mártires", "Santi Fabiano e Sebastiano, martiri", "Świętych Fabiana i Sebastiana, męczenników"]),
+    ("st-thomas-aquinas", ["Saint Thomas d'Aquin", "Hl. Thomas von Aquin", "Santo Tomás de Aquino", "São Tomás de Aquino", "San Tommaso d'Aquino", "Św. Tomasza z Akwinu"]),
+    ("purification-bvm", ["Purification de la Sainte Vierge (Chandeleur)", "Mariä Lichtmess", "Purificación de la Santísima Virgen (Candelaria)", "Purificação de Nossa Senhora (Candelária)", "Purificazione della Beata Vergine (Candelora)", "Oczyszczenie NMP (Matki Bożej Gromnicznej)"]),
+    ("chair-of-st-peter", ["Chaire de saint Pierre", "Petri Stuhlfeier", "Cátedra de san Pedro", "Cátedra de São Pedro", "Cattedra di san Pietro", "Katedra św. Piotra"]),
+    ("st-matthias", ["Saint Matthias, apôtre", "Hl. Matthias, Apostel", "San Matías, apóstol", "São Matias, apóstolo", "San Mattia, apostolo", "Św. Macieja, apostoła"]),
+    ("st-perpetua-felicity", ["Saintes Perpétue et Félicité, martyres", "Hll. Perpetua und Felizitas, Märtyrinnen", "Santas Perpetua y Felicidad, mártires", "Santas Perpétua e Felicidade, mártires", "Sante Perpetua e Felicita, martiri", "Świętych Perpetuy i Felicyty, męczennic"]),
+    ("st-gregory-great", ["Saint Grégoire le Grand, pape et docteur", "Hl. Gregor der Große, Papst und Kirchenlehrer", "San Gregorio Magno, papa y doctor", "São Gregório Magno, papa e doutor", "San Gregorio Magno, papa e dottore", "Św. Grzegorza Wielkiego, papieża i doktora Kościoła"]),
+    ("st-patrick", ["Saint Patrick, évêque et confesseur", "Hl. Patrick, Bischof und Bekenner", "San Patricio, obispo y confesor", "São Patrício, bispo e confessor", "San Patrizio, vescovo e confessore", "Św. Patryka, biskupa i wyznawcy"]),
+    ("st-joseph", ["Saint Joseph, époux de la Sainte Vierge", "Hl. Joseph, Bräutigam der Gottesmutter", "San José, esposo de la Santísima Virgen", "São José, esposo de Nossa Senhora", "San Giuseppe, sposo della Beata Vergine", "Św. Józefa, Oblubieńca NMP"]),
+    ("annunciation", ["Annonciation de la Sainte Vierge", "Mariä Verkündigung", "Anunciación de la Santísima Virgen", "Anunciação de Nossa Senhora", "Annunciazione della Beata Vergine", "Zwiastowanie NMP"]),
+    ("st-francis-of-paola", ["Saint François de Paule, confesseur", "Hl. Franz von Paola, Bekenner", "San Francisco de Paula, confesor", "São Francisco de Paula, confessor", "San Francesco di Paola, confessore", "Św. Franciszka z Paoli, wyznawcy"]),
+    ("st-mark", ["Saint Marc, évangéliste", "Hl. Markus, Evangelist", "San Marcos, evangelista", "São Marcos, evangelista", "San Marco, evangelista", "Św. Marka, ewangelisty"]),
+    ("st-joseph-worker", ["Saint Joseph artisan", "Hl. Joseph der Arbeiter", "San José Obrero", "São José Operário", "San Giuseppe artigiano", "Św. Józefa Robotnika"]),
+    ("finding-holy-cross", ["Invention de la Sainte Croix", "Kreuzauffindung", "Invención de la Santa Cruz", "Invenção da Santa Cruz", "Invenzione della Santa Croce", "Znalezienie Krzyża Świętego"]),
+    ("st-monica", ["Sainte Monique, veuve", "Hl. Monika, Witwe", "Santa Mónica, viuda", "Santa Mônica, viúva", "Santa Monica, vedova", "Św. Moniki, wdowy"]),
+    ("ss-philip-james", ["Saints Philippe et Jacques, apôtres", "Hll. Philippus und Jakobus, Apostel", "Santos Felipe y Santiago, apóstoles", "São Filipe e São Tiago, apóstolos", "Santi Filippo e Giacomo, apostoli", "Świętych Filipa i Jakuba, apostołów"]),
+    ("queenship-of-mary", ["Marie Reine", "Maria Königin", "Santa María Reina", "Nossa Senhora Rainha", "Maria Regina", "NMP Królowej"]),
+    ("nativity-of-st-john-baptist", ["Nativité de saint Jean-Baptiste", "Geburt Johannes des Täufers", "Natividad de san Juan Bautista", "Natividade de São João Batista", "Natività di san Giovanni Battista", "Narodzenie św. Jana Chrzciciela"]),
+    ("ss-peter-paul", ["Saints Pierre et Paul, apôtres", "Hll. Petrus und Paulus, Apostel", "Santos Pedro y Pablo, apóstoles", "São Pedro e São Paulo, apóstolos", "Santi Pietro e Paolo, apostoli", "Świętych Apostołów Piotra i Pawła"]),
+    ("visitation-bvm", ["Visitation de la Sainte Vierge", "Mariä Heimsuchung", "Visitación de la Santísima Virgen", "Visitação de Nossa Senhora", "Visitazione della Beata Vergine", "Nawiedzenie NMP"]),
+    ("st-james-greater", ["Saint Jacques le Majeur, apôtre", "Hl. Jakobus der Ältere, Apostel", "Santiago el Mayor, apóstol", "São Tiago Maior, apóstolo", "San Giacomo il Maggiore, apostolo", "Św. Jakuba Starszego, apostoła"]),
+    ("st-anne", ["Sainte Anne, mère de la Sainte Vierge", "Hl. Anna, Mutter der Gottesmutter", "Santa Ana, madre de la Santísima Virgen", "Sant'Ana, mãe de Nossa Senhora", "Sant'Anna, madre della Beata Vergine", "Św. Anny, Matki NMP"]),
+    ("transfiguration", ["Transfiguration de Notre-Seigneur", "Verklärung des Herrn", "Transfiguración del Señor", "Transfiguração do Senhor", "Trasfigurazione del Signore", "Przemienienie Pańskie"]),
+    ("st-lawrence", ["Saint Laurent, martyr", "Hl. Laurentius, Märtyrer", "San Lorenzo, mártir", "São Lourenço, mártir", "San Lorenzo, martire", "Św. Wawrzyńca, męczennika"]),
+    ("assumption-bvm", ["Assomption de la Sainte Vierge", "Mariä Himmelfahrt", "Asunción de la Santísima Virgen", "Assunção de Nossa Senhora", "Assunzione della Beata Vergine", "Wniebowzięcie NMP"]),
+    ("immaculate-heart-of-mary", ["Cœur Immaculé de Marie", "Unbeflecktes Herz Mariä", "Inmaculado Corazón de María", "Imaculado Coração de Maria", "Cuore Immacolato di Maria", "Niepokalanego Serca NMP"]),
+    ("st-bartholomew", ["Saint Barthélemy, apôtre", "Hl. Bartholomäus, Apostel", "San Bartolomé, apóstol", "São Bartolomeu, apóstolo", "San Bartolomeo, apostolo", "Św. Bartłomieja, apostoła"]),
+    ("st-augustine", ["Saint Augustin, évêque et docteur", "Hl. Augustinus, Bischof und Kirchenlehrer", "San Agustín, obispo y doctor", "Santo Agostinho, bispo e doutor", "Sant'Agostino, vescovo e dottore", "Św. Augustyna, biskupa i doktora Kościoła"]),
+    ("beheading-john-baptist", ["Décollation de saint Jean-Baptiste", "Enthauptung Johannes des Täufers", "Degollación de san Juan Bautista", "Degolação de São João Batista", "Decollazione di san Giovanni Battista", "Ścięcie św. Jana Chrzciciela"]),
+    ("nativity-bvm", ["Nativité de la Sainte Vierge", "Mariä Geburt", "Natividad de la Santísima Virgen", "Natividade de Nossa Senhora", "Natività della Beata Vergine", "Narodzenie NMP"]),
+    ("exaltation-holy-cross", ["Exaltation de la Sainte Croix", "Kreuzerhöhung", "Exaltación de la Santa Cruz", "Exaltação da Santa Cruz", "Esaltazione della Santa Croce", "Podwyższenie Krzyża Świętego"]),
+    ("seven-sorrows-bvm", ["Sept Douleurs de la Sainte Vierge", "Sieben Schmerzen Mariä", "Siete Dolores de la Santísima Virgen", "Sete Dores de Nossa Senhora", "Sette Dolori della Beata Vergine", "Siedmiu Boleści NMP"]),
+    ("st-matthew", ["Saint Matthieu, apôtre et évangéliste", "Hl. Matthäus, Apostel und Evangelist", "San Mateo, apóstol y evangelista", "São Mateus, apóstolo e evangelista", "San Matteo, apostolo ed evangelista", "Św. Mateusza, apostoła i ewangelisty"]),
+    ("st-michael", ["Saint Michel archange", "Hl. Erzengel Michael", "San Miguel arcángel", "São Miguel Arcanjo", "San Michele arcangelo", "Św. Michała Archanioła"]),
+    ("holy-rosary", ["Notre-Dame du Rosaire", "Rosenkranzfest", "Nuestra Señora del Rosario", "Nossa Senhora do Rosário", "Beata Vergine del Rosario", "Matki Bożej Różańcowej"]),
+    ("divine-motherhood-bvm", ["Maternité de la Sainte Vierge", "Mutterschaft Mariens", "Maternidad de la Santísima Virgen", "Maternidade de Nossa Senhora", "Maternità della Beata Vergine", "Macierzyństwa NMP"]),
+    ("st-luke", ["Saint Luc, évangéliste", "Hl. Lukas, Evangelist", "San Lucas, evangelista", "São Lucas, evangelista", "San Luca, evangelista", "Św. Łukasza, ewangelisty"]),
+    ("ss-simon-jude", ["Saints Simon et Jude, apôtres", "Hll. Simon und Judas, Apostel", "Santos Simón y Judas, apóstoles", "São Simão e São Judas, apóstolos", "Santi Simone e Giuda, apostoli", "Świętych Szymona i Judy, apostołów"]),
+    ("all-saints", ["La Toussaint", "Allerheiligen", "Todos los Santos", "Todos os Santos", "Tutti i Santi", "Wszystkich Świętych"]),
+    ("all-souls", ["Commémoraison de tous les fidèles défunts", "Allerseelen", "Conmemoración de los Fieles Difuntos", "Comemoração dos Fiéis Defuntos", "Commemorazione dei fedeli defunti", "Dzień Zaduszny"]),
+    ("dedication-lateran", ["Dédicace de la basilique du Latran", "Weihe der Lateranbasilika", "Dedicación de la Basílica de Letrán", "Dedicação da Basílica de Latrão", "Dedicazione della Basilica Lateranense", "Poświęcenie Bazyliki Laterańskiej"]),
+    ("presentation-bvm", ["Présentation de la Sainte Vierge", "Mariä Opferung", "Presentación de la Santísima Virgen", "Apresentação de Nossa Senhora", "Presentazione della Beata Vergine", "Ofiarowanie NMP"]),
+    ("st-andrew", ["Saint André, apôtre", "Hl. Andreas, Apostel", "San Andrés, apóstol", "Santo André, apóstolo", "Sant'Andrea, apostolo", "Św. Andrzeja, apostoła"]),
+    ("st-martin-of-tours", ["Saint Martin de Tours, évêque", "Hl. Martin von Tours, Bischof", "San Martín de Tours, obispo", "São Martinho de Tours, bispo", "San Martino di Tours, vescovo", "Św. Marcina z Tours, biskupa"]),
+    ("st-cecilia", ["Sainte Cécile, vierge et martyre", "Hl. Cäcilia, Jungfrau und Märtyrin", "Santa Cecilia, virgen y mártir", "Santa Cecília, virgem e mártir", "Santa Cecilia, vergine e martire", "Św. Cecylii, dziewicy i męczennicy"]),
+    ("st-catherine-of-alexandria", ["Sainte Catherine d'Alexandrie, vierge et martyre", "Hl. Katharina von Alexandrien, Jungfrau und Märtyrin", "Santa Catalina de Alejandría, virgen y mártir", "Santa Catarina de Alexandria, virgem e mártir", "Santa Caterina d'Alessandria, vergine e martire", "Św. Katarzyny Aleksandryjskiej, dziewicy i męczennicy"]),
+    ("immaculate-conception", ["Immaculée Conception de la Sainte Vierge", "Mariä Empfängnis", "Inmaculada Concepción de la Santísima Virgen", "Imaculada Conceição de Nossa Senhora", "Immacolata Concezione della Beata Vergine", "Niepokalane Poczęcie NMP"]),
+    ("st-thomas-apostle", ["Saint Thomas, apôtre", "Hl. Thomas, Apostel", "Santo Tomás, apóstol", "São Tomé, apóstolo", "San Tommaso, apostolo", "Św. Tomasza, apostoła"]),
+    ("christmas", ["Nativité de Notre-Seigneur", "Geburt des Herrn", "Natividad del Señor", "Natal do Senhor", "Natale del Signore", "Narodzenie Pańskie"]),
+    ("st-stephen", ["Saint Étienne, premier martyr", "Hl. Stephanus, Erzmärtyrer", "San Esteban, protomártir", "Santo Estêvão, protomártir", "Santo Stefano, protomartire", "Św. Szczepana, pierwszego męczennika"]),
+    ("st-john-evangelist", ["Saint Jean, apôtre et évangéliste", "Hl. Johannes, Apostel und Evangelist", "San Juan, apóstol y evangelista", "São João, apóstolo e evangelista", "San Giovanni, apostolo ed evangelista", "Św. Jana, apostoła i ewangelisty"]),
+    ("holy-innocents", ["Saints Innocents", "Unschuldige Kinder", "Santos Inocentes", "Santos Inocentes", "Santi Innocenti", "Świętych Młodzianków"]),
+    ("st-sylvester", ["Saint Sylvestre Ier, pape", "Hl. Silvester I., Papst", "San Silvestre I, papa", "São Silvestre I, papa", "San Silvestro I, papa", "Św. Sylwestra I, papieża"]),
+];
+
+/// Sentences of the notes, keyed by their English: fr, de, es, pt, it, pl
+const SENTENCES: &[(&str, [&str; 6])] = &[
+    ("Blessing and imposition of ashes.", ["Bénédiction et imposition des cendres.", "Segnung und Auflegung der Asche.", "Bendición e imposición de la ceniza.", "Bênção e imposição das cinzas.", "Benedizione e imposizione delle ceneri.", "Poświęcenie i posypanie popiołem."]),
+    ("Fast and abstinence.", ["Jeûne et abstinence.", "Fasten und Abstinenz.", "Ayuno y abstinencia.", "Jejum e abstinência.", "Digiuno e astinenza.", "Post ścisły i wstrzemięźliwość."]),
+    ("Solemnity of solemnities.", ["Solennité des solennités.", "Fest der Feste.", "Solemnidad de las solemnidades.", "Solenidade das solenidades.", "Solennità delle solennità.", "Uroczystość uroczystości."]),
+    ("Sequence: Victimae Paschali Laudes.", ["Séquence : Victimae paschali laudes.", "Sequenz: Victimae paschali laudes.", "Secuencia: Victimae paschali laudes.", "Sequência: Victimae paschali laudes.", "Sequenza: Victimae paschali laudes.", "Sekwencja: Victimae paschali laudes."]),
+    ("Sequence: Veni Sancte Spiritus.", ["Séquence : Veni Sancte Spiritus.", "Sequenz: Veni Sancte Spiritus.", "Secuencia: Veni Sancte Spiritus.", "Sequência: Veni Sancte Spiritus.", "Sequenza: Veni Sancte Spiritus.", "Sekwencja: Veni Sancte Spiritus."]),
+    ("Sequence: Lauda Sion Salvatorem.", ["Séquence : Lauda Sion Salvatorem.", "Sequenz: Lauda Sion Salvatorem.", "Secuencia: Lauda Sion Salvatorem.", "Sequência: Lauda Sion Salvatorem.", "Sequenza: Lauda Sion Salvatorem.", "Sekwencja: Lauda Sion Salvatorem."]),
+    ("Procession of the Blessed Sacrament.", ["Procession du Saint-Sacrement.", "Sakramentsprozession.", "Procesión del Santísimo Sacramento.", "Procissão do Santíssimo Sacramento.", "Processione del Santissimo Sacramento.", "Procesja z Najświętszym Sakramentem."]),
+    ("Candlemas.", ["Chandeleur.", "Lichtmess.", "Candelaria.", "Candelária.", "Candelora.", "Matki Bożej Gromnicznej."]),
+    ("Blessing of candles and procession.", ["Bénédiction des cierges et procession.", "Kerzenweihe und Prozession.", "Bendición de las candelas y procesión.", "Bênção das velas e procissão.", "Benedizione delle candele e processione.", "Poświęcenie gromnic i procesja."]),
+    ("Commemoration of All the Faithful Departed.", ["Commémoraison de tous les fidèles défunts.", "Gedächtnis aller verstorbenen Gläubigen.", "Conmemoración de todos los fieles difuntos.", "Comemoração de todos os fiéis defuntos.", "Commemorazione di tutti i fedeli defunti.", "Wspomnienie wszystkich wiernych zmarłych."]),
+    ("Three Masses permitted for each priest.", ["Trois messes permises à chaque prêtre.", "Jeder Priester darf drei Messen feiern.", "Cada sacerdote puede celebrar tres misas.", "Cada sacerdote pode celebrar três missas.", "Ogni sacerdote può celebrare tre messe.", "Każdy kapłan może odprawić trzy Msze."]),
+    ("Solemnity of the Nativity.", ["Solennité de la Nativité.", "Hochfest der Geburt des Herrn.", "Solemnidad de la Natividad.", "Solenidade do Natal.", "Solennità del Natale.", "Uroczystość Narodzenia Pańskiego."]),
+    ("Three Masses: Midnight, Dawn, Day.", ["Trois messes : de la nuit, de l'aurore, du jour.", "Drei Messen: in der Nacht, am Morgen, am Tag.", "Tres misas: de medianoche, de la aurora, del día.", "Três missas: da meia-noite, da aurora, do dia.", "Tre messe: della notte, dell'aurora, del giorno.", "Trzy Msze: o północy, o świcie, w dzień."]),
+    ("Octave Day of Christmas.", ["Octave de Noël.", "Oktavtag von Weihnachten.", "Octava de Navidad.", "Oitava do Natal.", "Ottava di Natale.", "Oktawa Bożego Narodzenia."]),
+    ("Holy Day of Obligation.", ["Fête d'obligation.", "Gebotener Feiertag.", "Fiesta de precepto.", "Dia santo de guarda.", "Festa di precetto.", "Święto nakazane."]),
+    ("Holy Day of Obligation in many countries.", ["Fête d'obligation dans de nombreux pays.", "In vielen Ländern gebotener Feiertag.", "Fiesta de precepto en muchos países.", "Dia santo de guarda em muitos países.", "Festa di precetto in molti paesi.", "Święto nakazane w wielu krajach."]),
+    ("Blessing of water, chalk, and incense.", ["Bénédiction de l'eau, de la craie et de l'encens.", "Segnung von Wasser, Kreide und Weihrauch.", "Bendición del agua, la tiza y el incienso.", "Bênção da água, do giz e do incenso.", "Benedizione dell'acqua, del gesso e dell'incenso.", "Poświęcenie wody, kredy i kadzidła."]),
+    // Holy Week
+    ("Blessing of palms and procession before Mass.", ["Bénédiction des rameaux et procession avant la messe.", "Palmweihe und Prozession vor der Messe.", "Bendición de los ramos y procesión antes de la misa.", "Bênção dos ramos e procissão antes da missa.", "Benedizione delle palme e processione prima della messa.", "Poświęcenie palm i procesja przed Mszą."]),
+    ("Blessing of palms in the form of a dry Mass, and procession before Mass.", ["Bénédiction des rameaux sous forme de messe sèche, et procession avant la messe.", "Palmweihe in der Form einer Trockenmesse und Prozession vor der Messe.", "Bendición de los ramos en forma de misa seca, y procesión antes de la misa.", "Bênção dos ramos em forma de missa seca, e procissão antes da missa.", "Benedizione delle palme in forma di messa secca, e processione prima della messa.", "Poświęcenie palm w formie Mszy suchej i procesja przed Mszą."]),
+    ("Evening Mass of the Lord's Supper.", ["Messe vespérale de la Cène du Seigneur.", "Abendmesse vom Letzten Abendmahl.", "Misa vespertina de la Cena del Señor.", "Missa vespertina da Ceia do Senhor.", "Messa vespertina della Cena del Signore.", "Wieczorna Msza Wieczerzy Pańskiej."]),
+    ("Mandatum.", ["Mandatum (lavement des pieds).", "Mandatum (Fußwaschung).", "Mandatum (lavatorio de los pies).", "Mandatum (lava-pés).", "Mandatum (lavanda dei piedi).", "Mandatum (obmycie nóg)."]),
+    ("Repository.", ["Reposoir.", "Repositorium.", "Monumento.", "Repositório.", "Altare della reposizione.", "Ciemnica."]),
+    ("Stripping of the altars.", ["Dépouillement des autels.", "Entblößung der Altäre.", "Desnudación de los altares.", "Desnudamento dos altares.", "Spogliazione degli altari.", "Obnażenie ołtarzy."]),
+    ("Morning Mass.", ["Messe du matin.", "Messe am Morgen.", "Misa por la mañana.", "Missa pela manhã.", "Messa al mattino.", "Msza poranna."]),
+    ("Solemn afternoon liturgy.", ["Liturgie solennelle de l'après-midi.", "Feierliche Liturgie am Nachmittag.", "Solemne liturgia de la tarde.", "Solene liturgia da tarde.", "Solenne liturgia pomeridiana.", "Uroczysta liturgia popołudniowa."]),
+    ("Veneration of the Cross.", ["Adoration de la Croix.", "Kreuzverehrung.", "Adoración de la Cruz.", "Adoração da Cruz.", "Adorazione della Croce.", "Adoracja Krzyża."]),
+    ("Communion of the faithful.", ["Communion des fidèles.", "Kommunion der Gläubigen.", "Comunión de los fieles.", "Comunhão dos fiéis.", "Comunione dei fedeli.", "Komunia wiernych."]),
+    ("No Mass celebrated.", ["Pas de messe.", "Keine Messe.", "No se celebra la misa.", "Não se celebra missa.", "Non si celebra la messa.", "Msza nie jest odprawiana."]),
+    ("Mass of the Presanctified.", ["Messe des Présanctifiés.", "Messe der vorgeweihten Gaben.", "Misa de los Presantificados.", "Missa dos Pré-santificados.", "Messa dei Presantificati.", "Msza Uprzednio Poświęconych Darów."]),
+    ("Restored Easter Vigil at night: new fire, Paschal candle, four lessons, baptismal water, renewal of baptismal promises.", ["Vigile pascale restaurée, de nuit : feu nouveau, cierge pascal, quatre lectures, eau baptismale, rénovation des promesses du baptême.", "Erneuerte Osternacht: neues Feuer, Osterkerze, vier Lesungen, Taufwasser, Erneuerung des Taufversprechens.", "Vigilia pascual restaurada, de noche: fuego nuevo, cirio pascual, cuatro lecturas, agua bautismal, renovación de las promesas bautismales.", "Vigília pascal restaurada, à noite: lume novo, círio pascal, quatro leituras, água batismal, renovação das promessas do batismo.", "Veglia pasquale restaurata, di notte: fuoco nuovo, cero pasquale, quattro letture, acqua battesimale, rinnovazione delle promesse battesimali.", "Odnowiona Wigilia Paschalna w nocy: nowy ogień, paschał, cztery czytania, woda chrzcielna, odnowienie przyrzeczeń chrzcielnych."]),
+    ("First Mass of Easter.", ["Première messe de Pâques.", "Erste Messe von Ostern.", "Primera misa de Pascua.", "Primeira missa da Páscoa.", "Prima messa di Pasqua.", "Pierwsza Msza Wielkanocna."]),
+    ("Morning Vigil: new fire, Paschal candle, twelve prophecies, blessing of the font.", ["Vigile du matin : feu nouveau, cierge pascal, douze prophéties, bénédiction des fonts.", "Vigil am Morgen: neues Feuer, Osterkerze, zwölf Prophetien, Taufwasserweihe.", "Vigilia por la mañana: fuego nuevo, cirio pascual, doce profecías, bendición de la pila.", "Vigília pela manhã: lume novo, círio pascal, doze profecias, bênção da pia batismal.", "Veglia al mattino: fuoco nuovo, cero pasquale, dodici profezie, benedizione del fonte.", "Wigilia poranna: nowy ogień, paschał, dwanaście proroctw, poświęcenie chrzcielnicy."]),
+];
+
+/// Name of a season; English matches the API's historical names
+pub fn season_name(season: LiturgicalSeason, locale: Locale) -> &'static str {
+    use LiturgicalSeason::*;
+    let names: [&str; 8] = match season {
+        Advent => ["Adventus", "Advent", "Avent", "Advent", "Adviento", "Advento", "Avvento", "Adwent"],
+        Christmas => ["Tempus Nativitatis", "Christmastide", "Temps de Noël", "Weihnachtszeit", "Tiempo de Navidad", "Tempo do Natal", "Tempo di Natale", "Okres Bożego Narodzenia"],
+        AfterEpiphany => ["Tempus post Epiphaniam", "Time after Epiphany", "Temps après l'Épiphanie", "Zeit nach Erscheinung", "Tiempo después de Epifanía", "Tempo depois da Epifania", "Tempo dopo l'Epifania", "Okres po Objawieniu"],
+        Septuagesima => ["Tempus Septuagesimae", "Septuagesima", "Septuagésime", "Vorfastenzeit", "Septuagésima", "Septuagésima", "Settuagesima", "Przedpoście"],
+        Lent => ["Quadragesima", "Lent", "Carême", "Fastenzeit", "Cuaresma", "Quaresma", "Quaresima", "Wielki Post"],
+        Passiontide => ["Tempus Passionis", "Passiontide", "Temps de la Passion", "Passionszeit", "Tiempo de Pasión", "Tempo da Paixão", "Tempo di Passione", "Okres Męki Pańskiej"],
+        HolyWeek => ["Hebdomada Sancta", "Holy Week", "Semaine sainte", "Karwoche", "Semana Santa", "Semana Santa", "Settimana santa", "Wielki Tydzień"],
+        Easter => ["Tempus Paschale", "Eastertide", "Temps pascal", "Osterzeit", "Tiempo pascual", "Tempo pascal", "Tempo pasquale", "Okres Wielkanocny"],
+        Ascensiontide => ["Tempus Ascensionis", "Ascensiontide", "Temps de l'Ascension", "Himmelfahrtszeit", "Tiempo de la Ascensión", "Tempo da Ascensão", "Tempo dell'Ascensione", "Okres Wniebowstąpienia"],
+        AfterPentecost => ["Tempus post Pentecosten", "Time after Pentecost", "Temps après la Pentecôte", "Zeit nach Pfingsten", "Tiempo después de Pentecostés", "Tempo depois de Pentecostes", "Tempo dopo Pentecoste", "Okres po Zesłaniu Ducha Świętego"],
+    };
+    let index = match locale {
+        Locale::La => 0,
+        Locale::En => 1,
+        other => other.slot().unwrap() + 2,
+    };
+    names[index]
+}
+
+fn weekday_name(day: Weekday, locale: Locale) -> &'static str {
+    let names: [&str; 6] = match day {
+        Weekday::Mon => ["Lundi", "Montag", "Lunes", "Segunda-feira", "Lunedì", "Poniedziałek"],
+        Weekday::Tue => ["Mardi", "Dienstag", "Martes", "Terça-feira", "Martedì", "Wtorek"],
+        Weekday::Wed => ["Mercredi", "Mittwoch", "Miércoles", "Quarta-feira", "Mercoledì", "Środa"],
+        Weekday::Thu => ["Jeudi", "Donnerstag", "Jueves", "Quinta-feira", "Giovedì", "Czwartek"],
+        Weekday::Fri => ["Vendredi", "Freitag", "Viernes", "Sexta-feira", "Venerdì", "Piątek"],
+        Weekday::Sat => ["Samedi", "Samstag", "Sábado", "Sábado", "Sabato", "Sobota"],
+        Weekday::Sun => ["Dimanche", "Sonntag", "Domingo", "Domingo", "Domenica", "Niedziela"],
+    };
+    locale.slot().map_or("", |i| names[i])
+}
+
+/// Ordinal numeral; `feminine` for "week" and the Italian "domenica"
+fn ordinal(n: u8, locale: Locale, feminine: bool) -> String {
+    match locale {
+        Locale::Fr if n == 1 => if feminine { "1re".into() } else { "1er".into() },
+        Locale::Fr => format!("{n}e"),
+        Locale::Es | Locale::Pt | Locale::It if feminine => format!("{n}ª"),
+        Locale::Es | Locale::Pt | Locale::It => format!("{n}º"),
+        _ => format!("{n}."),
+    }
+}
+
+/// Title of a generated Sunday, numbered as in the Latin title
+fn sunday_title(season: LiturgicalSeason, week: u8, locale: Locale) -> Option<String> {
+    use LiturgicalSeason::*;
+    let slot = locale.slot()?;
+    let fixed = |names: [&str; 6]| Some(names[slot].to_string());
+    let (n, pattern): (u8, [&str; 6]) = match season {
+        Advent => (week, ["{o} dimanche de l'Avent", "{o} Adventssonntag", "{o} domingo de Adviento", "{o} domingo do Advento", "{o} domenica di Avvento", "{o} Niedziela Adwentu"]),
+        Christmas if week == 1 => {
+            return fixed(["Dimanche dans l'octave de Noël", "Sonntag in der Weihnachtsoktav", "Domingo infraoctava de Navidad", "Domingo na oitava do Natal", "Domenica fra l'ottava di Natale", "Niedziela w oktawie Bożego Narodzenia"])
+        }
+        Christmas => return None,
+        AfterEpiphany => (week, ["{o} dimanche après l'Épiphanie", "{o} Sonntag nach Erscheinung", "{o} domingo después de Epifanía", "{o} domingo depois da Epifania", "{o} domenica dopo l'Epifania", "{o} Niedziela po Objawieniu"]),
+        Septuagesima => {
+            return match week {
+                1 => fixed(["Dimanche de la Septuagésime", "Sonntag Septuagesima", "Domingo de Septuagésima", "Domingo da Septuagésima", "Domenica di Settuagesima", "Niedziela Siedemdziesiątnicy"]),
+                2 => fixed(["Dimanche de la Sexagésime", "Sonntag Sexagesima", "Domingo de Sexagésima", "Domingo da Sexagésima", "Domenica di Sessagesima", "Niedziela Sześćdziesiątnicy"]),
+                _ => fixed(["Dimanche de la Quinquagésime", "Sonntag Quinquagesima", "Domingo de Quincuagésima", "Domingo da Quinquagésima", "Domenica di Quinquagesima", "Niedziela Pięćdziesiątnicy"]),
+            }
+        }
+        Lent => (week, ["{o} dimanche de Carême", "{o} Fastensonntag", "{o} domingo de Cuaresma", "{o} domingo da Quaresma", "{o} domenica di Quaresima", "{o} Niedziela Wielkiego Postu"]),
+        Passiontide => return fixed(["Dimanche de la Passion", "Passionssonntag", "Domingo de Pasión", "Domingo da Paixão", "Domenica di Passione", "Niedziela Męki Pańskiej"]),
+        HolyWeek => return fixed(["Dimanche des Rameaux", "Palmsonntag", "Domingo de Ramos", "Domingo de Ramos", "Domenica delle Palme", "Niedziela Palmowa"]),
+        Easter if week <= 2 => return None,
+        Easter => (week - 1, ["{o} dimanche après Pâques", "{o} Sonntag nach Ostern", "{o} domingo después de Pascua", "{o} domingo depois da Páscoa", "{o} domenica dopo Pasqua", "{o} Niedziela po Wielkanocy"]),
+        Ascensiontide => return fixed(["Dimanche après l'Ascension", "Sonntag nach Christi Himmelfahrt", "Domingo después de la Ascensión", "Domingo depois da Ascensão", "Domenica dopo l'Ascensione", "Niedziela po Wniebowstąpieniu"]),
+        AfterPentecost => (week - 1, ["{o} dimanche après la Pentecôte", "{o} Sonntag nach Pfingsten", "{o} domingo después de Pentecostés", "{o} domingo depois de Pentecostes", "{o} domenica dopo Pentecoste", "{o} Niedziela po Zesłaniu Ducha Świętego"]),
+    };
+    let o = ordinal(n, locale, locale == Locale::It);
+    Some(capitalize(&pattern[slot].replace("{o}", &o)))
+}
+
+/// Title of a generated feria, e.g. "Mardi de la 2e semaine (Carême)"
+fn feria_title(season: LiturgicalSeason, week: u8, day: Weekday, locale: Locale) -> Option<String> {
+    let slot = locale.slot()?;
+    let weekday = weekday_name(day, locale);
+    let season = season_name(season, locale);
+    if week == 0 {
+        return Some(format!("{weekday} ({season})"));
+    }
+    let o = ordinal(week, locale, true);
+    let patterns = [
+        "{d} de la {o} semaine ({s})",
+        "{d} der {o} Woche ({s})",
+        "{d} de la {o} semana ({s})",
+        "{d} da {o} semana ({s})",
+        "{d} della {o} settimana ({s})",
+        "{d} {o} tygodnia ({s})",
+    ];
+    Some(patterns[slot].replace("{d}", weekday).replace("{o}", &o).replace("{s}", season))
+}
+
+/// Titles built from a weekday: days within octaves, Ember and Rogation days
+fn weekday_title(pattern: [&str; 6], day: Weekday, locale: Locale) -> Option<String> {
+    let slot = locale.slot()?;
+    let weekday = weekday_name(day, locale);
+    Some(pattern[slot].replace("{d}", weekday).replace("{dl}", &weekday.to_lowercase()))
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Parse the parts of a generated id: `sunday-{season}-{week}` or
+/// `feria-{season}-week-{week}-{day}`
+fn generated(id: &str) -> Option<(LiturgicalSeason, u8, Option<Weekday>)> {
+    use LiturgicalSeason::*;
+    const SEASONS: [(&str, LiturgicalSeason); 10] = [
+        ("advent", Advent), ("christmas", Christmas), ("after-epiphany", AfterEpiphany),
+        ("septuagesima", Septuagesima), ("lent", Lent), ("passiontide", Passiontide),
+        ("holy-week", HolyWeek), ("easter", Easter), ("ascensiontide", Ascensiontide),
+        ("after-pentecost", AfterPentecost),
+    ];
+    let (is_sunday, rest) = match id.split_once('-')? {
+        ("sunday", rest) => (true, rest),
+        ("feria", rest) => (false, rest),
+        _ => return None,
+    };
+    let (season_id, season) = SEASONS.iter().find(|(s, _)| rest.starts_with(&format!("{s}-")))?;
+    let rest = &rest[season_id.len() + 1..];
+    if is_sunday {
+        return Some((*season, rest.parse().ok()?, None));
+    }
+    let (week, day) = rest.strip_prefix("week-")?.split_once('-')?;
+    let day = match day {
+        "mon" => Weekday::Mon,
+        "tue" => Weekday::Tue,
+        "wed" => Weekday::Wed,
+        "thu" => Weekday::Thu,
+        "fri" => Weekday::Fri,
+        "sat" => Weekday::Sat,
+        _ => return None,
+    };
+    Some((*season, week.parse().ok()?, Some(day)))
+}
+
+/// The translated title, if this locale has one
+fn translated_title(celebration: &Celebration, weekday: Weekday, locale: Locale) -> Option<String> {
+    let slot = locale.slot()?;
+    let id = celebration.id.as_str();
+    if let Some((_, names)) = TITLES.iter().find(|(key, _)| *key == id) {
+        return Some(names[slot].to_string());
+    }
+    if let Some((season, week, day)) = generated(id) {
+        return match day {
+            None => sunday_title(season, week, locale),
+            Some(day) => feria_title(season, week, day, locale),
+        };
+    }
+    if id.starts_with("easter-octave-") {
+        return weekday_title(["{d} dans l'octave de Pâques", "{d} in der Osteroktav", "{d} de la octava de Pascua", "{d} da oitava da Páscoa", "{d} fra l'ottava di Pasqua", "{d} w oktawie Wielkanocy"], weekday, locale);
+    }
+    if id.starts_with("pentecost-octave-") {
+        return weekday_title(["{d} dans l'octave de la Pentecôte", "{d} in der Pfingstoktav", "{d} de la octava de Pentecostés", "{d} da oitava de Pentecostes", "{d} fra l'ottava di Pentecoste", "{d} w oktawie Zesłania Ducha Świętego"], weekday, locale);
+    }
+    match celebration.category {
+        CelebrationCategory::EmberDay => weekday_title(["{d} des Quatre-Temps", "Quatember{dl}", "{d} de Témporas", "{d} das Têmporas", "{d} delle Quattro Tempora", "{d} suchych dni"], weekday, locale),
+        CelebrationCategory::RogationDay => weekday_title(["{d} des Rogations", "{d} der Bittwoche", "{d} de Rogativas", "{d} das Rogações", "{d} delle Rogazioni", "{d} dni krzyżowych"], weekday, locale),
+        _ if id.starts_with("saturday-of-our-lady-") => {
+            let incipit = celebration.title_vernacular.as_deref()?.split_once('(')?.1.trim_end_matches(')');
+            let names = ["Sainte Marie au samedi", "Marien-Samstag", "Santa María en sábado", "Santa Maria no sábado", "Santa Maria in sabato", "Sobota NMP"];
+            Some(format!("{} ({})", names[slot], incipit))
+        }
+        _ => None,
+    }
+}
+
+/// Title of a celebration in the locale, falling back to English and then
+/// to Latin. `weekday` names the days within octaves and the Ember days.
+pub fn title(celebration: &Celebration, weekday: Weekday, locale: Locale) -> String {
+    if locale == Locale::La {
+        return celebration.title.clone();
+    }
+    translated_title(celebration, weekday, locale)
+        .or_else(|| celebration.title_vernacular.clone())
+        .unwrap_or_else(|| celebration.title.clone())
+}
+
+/// Notes in the locale, translated sentence by sentence; the English is
+/// kept unless every sentence has a translation
+pub fn notes(english: &str, locale: Locale) -> String {
+    let Some(slot) = locale.slot() else {
+        return english.to_string();
+    };
+    let translated: Option<Vec<&str>> = sentences(english)
+        .map(|s| SENTENCES.iter().find(|(en, _)| *en == s).map(|(_, t)| t[slot]))
+        .collect();
+    match translated {
+        Some(parts) => parts.join(" "),
+        None => english.to_string(),
+    }
+}
+
+fn sentences(text: &str) -> impl Iterator<Item = &str> {
+    text.split_inclusive(". ").map(str::trim).filter(|s| !s.is_empty())
+}
+
+/// A day with its vernacular titles and notes in the locale; English
+/// leaves the day as computed
+pub fn localize(day: &LiturgicalDay, locale: Locale) -> LiturgicalDay {
+    localize_in(day, locale, LatinStyle::default())
+}
+
+/// As [`localize`], writing the Roman date of Latin output in a style
+pub fn localize_in(day: &LiturgicalDay, locale: Locale, style: LatinStyle) -> LiturgicalDay {
+    if locale == Locale::En {
+        return day.clone();
+    }
+    let weekday = chrono::Datelike::weekday(&day.date);
+    let retitle = |c: &Celebration| Celebration {
+        title_vernacular: Some(title(c, weekday, locale)),
+        ..c.clone()
+    };
+    LiturgicalDay {
+        celebration: retitle(&day.celebration),
+        commemorations: day.commemorations.iter().map(retitle).collect(),
+        options: day.options.iter().map(retitle).collect(),
+        notes: day.notes.as_deref().map(|n| notes(n, locale)),
+        date_latin: (locale == Locale::La).then(|| latin_date::format(day.date, style)),
+        ..day.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Calendar;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_titles_and_fallback() {
+        let sunday = Celebration::sunday(LiturgicalSeason::AfterPentecost, 6);
+        assert_eq!(title(&sunday, Weekday::Sun, Locale::Fr), "5e dimanche après la Pentecôte");
+        assert_eq!(title(&sunday, Weekday::Sun, Locale::Pl), "5. Niedziela po Zesłaniu Ducha Świętego");
+        assert_eq!(title(&sunday, Weekday::Sun, Locale::La), "Domínica V post Pentecósten");
+        let feria = Celebration::feria(LiturgicalSeason::Lent, 2, Weekday::Tue);
+        assert_eq!(title(&feria, Weekday::Tue, Locale::De), "Dienstag der 2. Woche (Fastenzeit)");
+        // No translation: English, then Latin
+        let unknown = Celebration::new(
+            "st-example", "S. Exempli", "St. Example",
+            CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White,
+        );
+        assert_eq!(title(&unknown, Weekday::Mon, Locale::It), "St. Example");
+        let latin_only = Celebration { title_vernacular: None, ..unknown };
+        assert_eq!(title(&latin_only, Weekday::Mon, Locale::It), "S. Exempli");
+    }
+
+    #[test]
+    fn test_notes() {
+        assert_eq!(notes("Holy Day of Obligation.", Locale::Es), "Fiesta de precepto.");
+        assert_eq!(
+            notes("Blessing and imposition of ashes. Fast and abstinence.", Locale::Fr),
+            "Bénédiction et imposition des cendres. Jeûne et abstinence."
+        );
+        // A sentence without a translation keeps the whole note in English
+        assert_eq!(notes("Holy Day of Obligation. Something new.", Locale::Es), "Holy Day of Obligation. Something new.");
+    }
+
+    #[test]
+    fn test_localize_day() {
+        let cal = Calendar::new(2026);
+        let day = cal.get(NaiveDate::from_ymd_opt(2026, 1, 6).unwrap()).unwrap();
+        let fr = localize(day, Locale::Fr);
+        assert_eq!(fr.celebration.title_vernacular.as_deref(), Some("Épiphanie de Notre-Seigneur"));
+        assert!(fr.notes.unwrap().starts_with("Fête d'obligation."));
+        assert_eq!(fr.celebration.title, day.celebration.title);
+        assert_eq!(season_name(LiturgicalSeason::Lent, Locale::Pl), "Wielki Post");
+        assert_eq!("pt-BR".parse::<Locale>(), Ok(Locale::Pt));
+    }
+}
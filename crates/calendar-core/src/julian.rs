@@ -0,0 +1,168 @@
+//! The Julian calendar: conversion to and from the Gregorian, the Julian
+//! computus of Easter, and which reckoning the Roman Church followed in a
+//! given year.
+//!
+//! Dates elsewhere in the crate are `NaiveDate`s, proleptic Gregorian.
+//! The Roman calendar is computed by the Gregorian rules only; for years
+//! before the reform the functions here give the dates as they were kept.
+
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// First year wholly in the Gregorian calendar. The reform took effect
+/// after Thursday the 4th of October 1582 (Julian), followed by Friday the
+/// 15th (Gregorian); Easter 1582 was still kept by the Julian computus.
+pub const FIRST_GREGORIAN_YEAR: i32 = 1583;
+
+/// Days from the Julian Day Number to chrono's day count from 1 January 1
+const JDN_OFFSET: i32 = 1_721_425;
+
+/// Calendar by which a year's dates and Easter are reckoned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Reckoning {
+    Julian,
+    Gregorian,
+}
+
+/// Reckoning of the Roman Church in a year
+pub fn reckoning(year: i32) -> Reckoning {
+    if year < FIRST_GREGORIAN_YEAR {
+        Reckoning::Julian
+    } else {
+        Reckoning::Gregorian
+    }
+}
+
+/// A date in the Julian calendar, written "1582-10-04"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(into = "String")]
+pub struct JulianDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl JulianDate {
+    /// A Julian date, or `None` if the day does not exist; every fourth
+    /// year is a leap year
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        let length = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year.rem_euclid(4) == 0 => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=length).contains(&day).then_some(Self { year, month, day })
+    }
+
+    /// The same day in the proleptic Gregorian calendar
+    pub fn to_gregorian(self) -> NaiveDate {
+        let a = (14 - self.month as i32) / 12;
+        let y = self.year + 4800 - a;
+        let m = self.month as i32 + 12 * a - 3;
+        let jdn = self.day as i32 + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083;
+        NaiveDate::from_num_days_from_ce_opt(jdn - JDN_OFFSET).expect("date within chrono's range")
+    }
+
+    /// The Julian date of a Gregorian day
+    pub fn from_gregorian(date: NaiveDate) -> Self {
+        let c = date.num_days_from_ce() + JDN_OFFSET + 32082;
+        let d = (4 * c + 3).div_euclid(1461);
+        let e = c - (1461 * d).div_euclid(4);
+        let m = (5 * e + 2) / 153;
+        Self {
+            year: d - 4800 + m / 10,
+            month: (m + 3 - 12 * (m / 10)) as u32,
+            day: (e - (153 * m + 2) / 5 + 1) as u32,
+        }
+    }
+}
+
+impl fmt::Display for JulianDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl From<JulianDate> for String {
+    fn from(date: JulianDate) -> Self {
+        date.to_string()
+    }
+}
+
+/// Easter by the Julian computus (Meeus), as a Julian date. The Eastern
+/// Churches keep it still; Rome kept it until 1582.
+pub fn easter(year: i32) -> JulianDate {
+    let a = year.rem_euclid(4);
+    let b = year.rem_euclid(7);
+    let c = year.rem_euclid(19);
+    let d = (19 * c + 15) % 30;
+    let e = (2 * a + 4 * b - d + 34) % 7;
+    let month = (d + e + 114) / 31;
+    let day = (d + e + 114) % 31 + 1;
+    JulianDate::new(year, month as u32, day as u32).expect("Easter computation produced invalid date")
+}
+
+/// Orthodox Easter on the Gregorian calendar, for comparison with the Roman
+pub fn orthodox_easter(year: i32) -> NaiveDate {
+    easter(year).to_gregorian()
+}
+
+/// The day Rome kept Easter: by the Julian computus before the reform, by
+/// the Gregorian after
+pub fn historical_easter(year: i32) -> NaiveDate {
+    match reckoning(year) {
+        Reckoning::Julian => orthodox_easter(year),
+        Reckoning::Gregorian => crate::computus::easter(year),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn g(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_conversion() {
+        // The day before the reform and the first Gregorian day
+        let eve = JulianDate::new(1582, 10, 4).unwrap();
+        assert_eq!(eve.to_gregorian().succ_opt(), Some(g(1582, 10, 15)));
+        assert_eq!(JulianDate::from_gregorian(g(1582, 10, 15)).to_string(), "1582-10-05");
+        // Christmas of the Old Calendar
+        assert_eq!(JulianDate::new(2026, 12, 25).unwrap().to_gregorian(), g(2027, 1, 7));
+        // A Julian leap day that the Gregorian calendar does not have
+        let leap = JulianDate::new(1900, 2, 29).unwrap();
+        assert_eq!(leap.to_gregorian(), g(1900, 3, 13));
+        assert!(JulianDate::new(1901, 2, 29).is_none());
+        for date in [g(1, 1, 1), g(325, 6, 19), g(1200, 4, 9), g(2100, 3, 1)] {
+            assert_eq!(JulianDate::from_gregorian(date).to_gregorian(), date);
+        }
+    }
+
+    #[test]
+    fn test_julian_and_orthodox_easter() {
+        assert_eq!(easter(1582), JulianDate::new(1582, 4, 15).unwrap());
+        assert_eq!(orthodox_easter(2024), g(2024, 5, 5));
+        assert_eq!(orthodox_easter(2025), g(2025, 4, 20));
+        assert_eq!(orthodox_easter(2026), g(2026, 4, 12));
+        for year in 1900..=2100 {
+            assert_eq!(orthodox_easter(year).weekday(), chrono::Weekday::Sun);
+        }
+    }
+
+    #[test]
+    fn test_reform_policy() {
+        assert_eq!(reckoning(1582), Reckoning::Julian);
+        assert_eq!(reckoning(FIRST_GREGORIAN_YEAR), Reckoning::Gregorian);
+        // Easter 1200 fell on the 9th of April (Julian)
+        assert_eq!(JulianDate::from_gregorian(historical_easter(1200)), JulianDate::new(1200, 4, 9).unwrap());
+        assert_eq!(historical_easter(2026), g(2026, 4, 5));
+    }
+}
@@ -0,0 +1,176 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::*;
+
+/// How Latin titles and texts are written out.
+///
+/// Latin is stored once in a canonical form: the digraphs `ae` and `oe`
+/// spelled out, the tonic accent of words of three or more syllables
+/// marked with an acute ("Ecclésiae", "quáesumus"), and a diaeresis where
+/// two vowels that could be a digraph are said apart ("Michaël"). Each
+/// style is derived from that form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LatinStyle {
+    /// "In medio Ecclesiae", as in the Missal's rubrics and most web use
+    #[default]
+    Classical,
+    /// "In medio Ecclesiæ", as printed in the Missal
+    Ligature,
+    /// "In médio Ecclésiæ", as printed in the chant books for reading aloud
+    Accented,
+}
+
+impl FromStr for LatinStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "classical" => Ok(LatinStyle::Classical),
+            "ligature" => Ok(LatinStyle::Ligature),
+            "accented" => Ok(LatinStyle::Accented),
+            _ => Err(format!("Unknown Latin style: {}", s)),
+        }
+    }
+}
+
+/// Strip an acute accent or diaeresis from a vowel
+fn plain(c: char) -> char {
+    match c {
+        'á' => 'a',
+        'é' | 'ë' => 'e',
+        'í' | 'ï' => 'i',
+        'ó' => 'o',
+        'ú' | 'ü' => 'u',
+        'ý' => 'y',
+        'Á' => 'A',
+        'É' | 'Ë' => 'E',
+        'Í' | 'Ï' => 'I',
+        'Ó' => 'O',
+        'Ú' | 'Ü' => 'U',
+        _ => c,
+    }
+}
+
+/// The ligature for a digraph whose first letter is `first`, accented when
+/// the canonical form marks the digraph as stressed
+fn ligature(first: char, accented: bool) -> Option<&'static str> {
+    let lig = match (first, accented) {
+        ('a', false) | ('á', false) => "æ",
+        ('A', false) | ('Á', false) => "Æ",
+        ('o', false) | ('ó', false) => "œ",
+        ('O', false) | ('Ó', false) => "Œ",
+        ('a', true) => "æ",
+        ('A', true) => "Æ",
+        ('o', true) => "œ",
+        ('O', true) => "Œ",
+        ('á', true) => "ǽ",
+        ('Á', true) => "Ǽ",
+        // No precomposed accented œ; use a combining acute
+        ('ó', true) => "œ\u{301}",
+        ('Ó', true) => "Œ\u{301}",
+        _ => return None,
+    };
+    Some(lig)
+}
+
+/// Write canonical Latin in a style
+pub fn render(text: &str, style: LatinStyle) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if style != LatinStyle::Classical && matches!(chars.peek(), Some('e') | Some('E')) {
+            if let Some(lig) = ligature(c, style == LatinStyle::Accented) {
+                chars.next();
+                out.push_str(lig);
+                continue;
+            }
+        }
+        match style {
+            LatinStyle::Accented => out.push(c),
+            _ => out.push(plain(c)),
+        }
+    }
+    out
+}
+
+/// Write the Latin of a day in a style: the titles of its celebrations and
+/// of the precedence trace, and the incipits of its chants
+pub(crate) fn render_day(day: &mut LiturgicalDay, style: LatinStyle) {
+    let celebrations = std::iter::once(&mut day.celebration)
+        .chain(day.commemorations.iter_mut())
+        .chain(day.options.iter_mut());
+    for c in celebrations {
+        c.title = render(&c.title, style);
+    }
+    for chant in &mut day.chants {
+        if let Some(name) = &chant.name {
+            chant.name = Some(render(name, style));
+        }
+    }
+    if let Some(trace) = &mut day.trace {
+        for candidate in &mut trace.candidates {
+            candidate.title = render(&candidate.title, style);
+        }
+    }
+}
+
+/// Write the Latin of the Mass texts in a style, commemorations included
+pub(crate) fn render_mass_texts(texts: &mut MassTexts, style: LatinStyle) {
+    let p = &mut texts.propers;
+    let propers = [
+        &mut p.introit,
+        &mut p.collect,
+        &mut p.epistle,
+        &mut p.gradual,
+        &mut p.sequence,
+        &mut p.gospel,
+        &mut p.offertory,
+        &mut p.secret,
+        &mut p.communion,
+        &mut p.postcommunion,
+        &mut p.last_gospel,
+    ];
+    let orations = texts
+        .commemorations
+        .iter_mut()
+        .flat_map(|c| [&mut c.collect, &mut c.secret, &mut c.postcommunion]);
+    for text in propers.into_iter().chain(orations).flatten() {
+        text.latin = render(&text.latin, style);
+    }
+    for c in &mut texts.commemorations {
+        c.title = render(&c.title, style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTROIT: &str = "In médio Ecclésiae apéruit os ejus";
+
+    #[test]
+    fn test_styles() {
+        assert_eq!(render(INTROIT, LatinStyle::Classical), "In medio Ecclesiae aperuit os ejus");
+        assert_eq!(render(INTROIT, LatinStyle::Ligature), "In medio Ecclesiæ aperuit os ejus");
+        assert_eq!(render(INTROIT, LatinStyle::Accented), "In médio Ecclésiæ apéruit os ejus");
+    }
+
+    #[test]
+    fn test_accented_digraphs_and_hiatus() {
+        assert_eq!(render("quáesumus, Dómine", LatinStyle::Accented), "quǽsumus, Dómine");
+        assert_eq!(render("quáesumus", LatinStyle::Ligature), "quæsumus");
+        assert_eq!(render("Aetérne Deus, cóelum", LatinStyle::Accented), "Ætérne Deus, cœ\u{301}lum");
+        // A diaeresis or an accent on the second vowel keeps the vowels apart
+        assert_eq!(render("S. Michaélis, Israël", LatinStyle::Ligature), "S. Michaelis, Israel");
+        assert_eq!(render("S. Michaélis, Israël", LatinStyle::Accented), "S. Michaélis, Israël");
+    }
+
+    #[test]
+    fn test_parse_style() {
+        assert_eq!("Ligature".parse::<LatinStyle>(), Ok(LatinStyle::Ligature));
+        assert!("gothic".parse::<LatinStyle>().is_err());
+    }
+}
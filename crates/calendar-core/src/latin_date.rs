@@ -0,0 +1,191 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::latin::{self, LatinStyle};
+use crate::types::roman;
+
+/// Months in the genitive, as the Martyrology names them after Kalends,
+/// Nones and Ides
+const MONTHS: [&str; 12] = [
+    "Januárii", "Februárii", "Mártii", "Aprílis", "Maii", "Júnii",
+    "Júlii", "Augústi", "Septémbris", "Octóbris", "Novémbris", "Decémbris",
+];
+
+/// Day of the Nones; the Ides fall eight days later. March, May, July and
+/// October keep them two days later than the other months.
+fn nones(month: u32) -> u32 {
+    match month {
+        3 | 5 | 7 | 10 => 7,
+        _ => 5,
+    }
+}
+
+/// Number of days in the month of `date`
+fn month_length(date: NaiveDate) -> u32 {
+    let next = match date.month() {
+        12 => NaiveDate::from_ymd_opt(date.year() + 1, 1, 1),
+        m => NaiveDate::from_ymd_opt(date.year(), m + 1, 1),
+    };
+    next.and_then(|n| n.pred_opt()).map(|d| d.day()).unwrap_or(31)
+}
+
+/// The day of the month reckoned the Roman way, e.g. "Pridie Kalendas
+/// Februarii", "III Nonas Martii", "Idibus Maii"
+pub fn roman_day(date: NaiveDate, style: LatinStyle) -> String {
+    let (day, month) = (date.day(), date.month());
+    let (nones, ides) = (nones(month), nones(month) + 8);
+    let this = MONTHS[month as usize - 1];
+    let canonical = if day == 1 {
+        format!("Kaléndis {this}")
+    } else if day < nones {
+        counted(nones - day + 1, "Nonas", this)
+    } else if day == nones {
+        format!("Nonis {this}")
+    } else if day < ides {
+        counted(ides - day + 1, "Idus", this)
+    } else if day == ides {
+        format!("Ídibus {this}")
+    } else {
+        // In a leap year the Church keeps the 24th of February as the
+        // doubled sixth day before the Kalends of March, so the 24th and
+        // 25th are both "VI Kalendas Martii" and the days after count as
+        // in a common year.
+        let leap_day = month == 2 && month_length(date) == 29;
+        let day = if leap_day && day > 24 { day - 1 } else { day };
+        let length = if leap_day { 28 } else { month_length(date) };
+        counted(length + 2 - day, "Kaléndas", MONTHS[month as usize % 12])
+    };
+    latin::render(&canonical, style)
+}
+
+/// "Pridie Nonas Martii", or "III Nonas Martii" further back
+fn counted(n: u32, marker: &str, month: &str) -> String {
+    match n {
+        2 => format!("Prídie {marker} {month}"),
+        n => format!("{} {marker} {month}", roman(n)),
+    }
+}
+
+/// Ferial name of a weekday: "Dominica", "Feria II" to "Feria VI", "Sabbato"
+pub fn feria(weekday: Weekday, style: LatinStyle) -> String {
+    let canonical = match weekday {
+        Weekday::Sun => "Domínica".to_string(),
+        Weekday::Sat => "Sábbato".to_string(),
+        day => format!("Féria {}", roman(day.number_from_sunday())),
+    };
+    latin::render(&canonical, style)
+}
+
+/// The year in numerals, e.g. "Anno Domini MMXXVI"; years before the
+/// first are counted "ante Christum natum"
+pub fn anno_domini(year: i32, style: LatinStyle) -> String {
+    let canonical = if year > 0 {
+        format!("Anno Dómini {}", roman(year as u32))
+    } else {
+        format!("Anno {} ante Christum natum", roman((1 - year) as u32))
+    };
+    latin::render(&canonical, style)
+}
+
+/// The year in words as in the dating of documents, e.g. "Anno Domini bis
+/// millesimo vicesimo sexto". Years beyond 9999 keep their numerals.
+pub fn anno_domini_in_words(year: i32, style: LatinStyle) -> String {
+    let n = if year > 0 { year as u32 } else { (1 - year) as u32 };
+    let Some(words) = ordinal(n) else {
+        return anno_domini(year, style);
+    };
+    let canonical = if year > 0 {
+        format!("Anno Dómini {words}")
+    } else {
+        format!("Anno {words} ante Christum natum")
+    };
+    latin::render(&canonical, style)
+}
+
+/// Ordinal number in the ablative, e.g. "bis millesimo vicesimo sexto"
+fn ordinal(n: u32) -> Option<String> {
+    const UNITS: [&str; 10] = [
+        "", "primo", "secúndo", "tértio", "quarto", "quinto", "sexto", "séptimo", "octávo", "nono",
+    ];
+    const TEENS: [&str; 10] = [
+        "décimo", "undécimo", "duodécimo", "tértio décimo", "quarto décimo",
+        "quinto décimo", "sexto décimo", "séptimo décimo", "duodevicésimo", "undevicésimo",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "vicésimo", "tricésimo", "quadragésimo", "quinquagésimo",
+        "sexagésimo", "septuagésimo", "octogésimo", "nonagésimo",
+    ];
+    const HUNDREDS: [&str; 10] = [
+        "", "centésimo", "ducentésimo", "trecentésimo", "quadringentésimo",
+        "quingentésimo", "sescentésimo", "septingentésimo", "octingentésimo", "nongentésimo",
+    ];
+    const THOUSANDS: [&str; 10] = [
+        "", "millésimo", "bis millésimo", "ter millésimo", "quáter millésimo",
+        "quínquies millésimo", "séxies millésimo", "sépties millésimo", "ócties millésimo",
+        "nóvies millésimo",
+    ];
+    if n == 0 || n > 9999 {
+        return None;
+    }
+    let (thousands, hundreds, rest) = (n / 1000, (n / 100) % 10, n % 100);
+    let mut parts = vec![THOUSANDS[thousands as usize], HUNDREDS[hundreds as usize]];
+    match rest {
+        10..=19 => parts.push(TEENS[rest as usize - 10]),
+        _ => parts.extend([TENS[rest as usize / 10], UNITS[rest as usize % 10]]),
+    }
+    Some(parts.into_iter().filter(|p| !p.is_empty()).collect::<Vec<_>>().join(" "))
+}
+
+/// A date as an Ordo or the Martyrology heads it, e.g. "Feria IV, III Nonas
+/// Martii, Anno Domini MMXXVI"
+pub fn format(date: NaiveDate, style: LatinStyle) -> String {
+    format!(
+        "{}, {}, {}",
+        feria(date.weekday(), style),
+        roman_day(date, style),
+        anno_domini(date.year(), style)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn test_kalends_nones_ides() {
+        let c = LatinStyle::Classical;
+        assert_eq!(roman_day(d(2026, 1, 1), c), "Kalendis Januarii");
+        assert_eq!(roman_day(d(2026, 1, 14), c), "XIX Kalendas Februarii");
+        assert_eq!(roman_day(d(2026, 1, 31), c), "Pridie Kalendas Februarii");
+        assert_eq!(roman_day(d(2026, 3, 5), c), "III Nonas Martii");
+        assert_eq!(roman_day(d(2026, 3, 7), c), "Nonis Martii");
+        assert_eq!(roman_day(d(2026, 4, 12), c), "Pridie Idus Aprilis");
+        assert_eq!(roman_day(d(2026, 5, 15), c), "Idibus Maii");
+        assert_eq!(roman_day(d(2026, 12, 31), c), "Pridie Kalendas Januarii");
+        assert_eq!(roman_day(d(2026, 3, 5), LatinStyle::Accented), "III Nonas Mártii");
+    }
+
+    #[test]
+    fn test_leap_february() {
+        let c = LatinStyle::Classical;
+        assert_eq!(roman_day(d(2026, 2, 24), c), "VI Kalendas Martii");
+        assert_eq!(roman_day(d(2026, 2, 28), c), "Pridie Kalendas Martii");
+        assert_eq!(roman_day(d(2028, 2, 24), c), "VI Kalendas Martii");
+        assert_eq!(roman_day(d(2028, 2, 25), c), "VI Kalendas Martii");
+        assert_eq!(roman_day(d(2028, 2, 29), c), "Pridie Kalendas Martii");
+    }
+
+    #[test]
+    fn test_years_and_ferias() {
+        let c = LatinStyle::Classical;
+        assert_eq!(anno_domini(2026, c), "Anno Domini MMXXVI");
+        assert_eq!(anno_domini(0, c), "Anno I ante Christum natum");
+        assert_eq!(anno_domini_in_words(2026, c), "Anno Domini bis millesimo vicesimo sexto");
+        assert_eq!(anno_domini_in_words(1918, c), "Anno Domini millesimo nongentesimo duodevicesimo");
+        assert_eq!(feria(Weekday::Wed, c), "Feria IV");
+        assert_eq!(format(d(2026, 3, 4), c), "Feria IV, IV Nonas Martii, Anno Domini MMXXVI");
+    }
+}
@@ -1,11 +1,31 @@
 pub mod types;
 pub mod computus;
+pub mod julian;
 pub mod temporal;
 pub mod sanctoral;
 pub mod precedence;
+pub mod rubrics;
 pub mod readings;
+pub mod scripture;
+pub mod chants;
+pub mod ordinary;
+pub mod stations;
+pub mod holy_week;
+pub mod orations;
+pub mod propers;
+pub mod commons;
+pub mod i18n;
+pub mod latin;
+pub mod latin_date;
+pub mod noveritis;
+pub mod designation;
+pub mod occurrences;
 pub mod calendar;
 
 pub use types::*;
 pub use computus::easter;
-pub use calendar::Calendar;
+pub use calendar::{Calendar, CalendarOptions};
+pub use orations::OrationRegistry;
+pub use scripture::ScriptureRef;
+pub use i18n::Locale;
+pub use latin::LatinStyle;
@@ -0,0 +1,103 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::computus::moveable_feasts;
+use crate::latin::{self, LatinStyle};
+use crate::latin_date;
+
+/// The proclamation of the moveable feasts sung after the Gospel on the
+/// Epiphany, in Latin and English
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Noveritis {
+    pub year: i32,
+    pub latin: String,
+    pub english: String,
+}
+
+/// Each announced feast: its Latin and English sentence after the date
+const FEASTS: [(&str, &str); 7] = [
+    ("erit Domínica in Septuagésima.", "will be Septuagesima Sunday."),
+    (
+        "dies Cínerum, et inítium jejúnii sacratíssimae Quadragésimae.",
+        "Ash Wednesday, and the beginning of the fast of most holy Lent.",
+    ),
+    (
+        "sanctum Pascha Dómini nostri Jesu Christi cum gáudio celebrábimus.",
+        "we shall celebrate with joy the holy Pasch of our Lord Jesus Christ.",
+    ),
+    ("erit Ascénsio Dómini nostri Jesu Christi.", "will be the Ascension of our Lord Jesus Christ."),
+    ("Festum Pentecóstes.", "the Feast of Pentecost."),
+    ("Festum sanctíssimi Córporis Christi.", "the Feast of the most holy Body of Christ."),
+    (
+        "Domínica prima Advéntus Dómini nostri Jesu Christi, cui est honor et glória, in sáecula saeculórum. Amen.",
+        "the First Sunday of the Advent of our Lord Jesus Christ, to whom be honour and glory, for ever and ever. Amen.",
+    ),
+];
+
+const LATIN_OPENING: &str = "Noverítis, fratres caríssimi, quod annuénte Dei misericórdia, sicut de \
+Nativitáte Dómini nostri Jesu Christi gavísi sumus, ita et de Resurrectióne ejúsdem Salvatóris nostri \
+gáudium vobis annuntiámus.";
+
+const ENGLISH_OPENING: &str = "Know, dearly beloved brethren, that, by the mercy of God, as we have \
+rejoiced at the Nativity of our Lord Jesus Christ, so also we announce to you the joy of the \
+Resurrection of the same our Saviour.";
+
+/// "the 1st of February"
+fn english_date(date: NaiveDate) -> String {
+    let day = date.day();
+    let suffix = match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("the {day}{suffix} of {}", date.format("%B"))
+}
+
+/// The proclamation for a year, from Septuagesima to the First Sunday of
+/// Advent, with the dates in the Roman form
+pub fn noveritis(year: i32, style: LatinStyle) -> Noveritis {
+    let mf = moveable_feasts(year);
+    let dates = [
+        mf.septuagesima,
+        mf.ash_wednesday,
+        mf.easter,
+        mf.ascension,
+        mf.pentecost,
+        mf.corpus_christi,
+        mf.advent_1,
+    ];
+    let mut latin = vec![latin::render(LATIN_OPENING, style)];
+    let mut english = vec![ENGLISH_OPENING.to_string()];
+    for (date, (la, en)) in dates.into_iter().zip(FEASTS) {
+        latin.push(format!("{} {}", latin_date::roman_day(date, style), latin::render(la, style)));
+        english.push(format!("On {} {en}", english_date(date)));
+    }
+    Noveritis { year, latin: latin.join(" "), english: english.join(" ") }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_noveritis_2026() {
+        let n = noveritis(2026, LatinStyle::Classical);
+        assert!(n.latin.starts_with("Noveritis, fratres carissimi"));
+        assert!(n.latin.contains("Kalendis Februarii erit Dominica in Septuagesima."));
+        assert!(n.latin.contains("XII Kalendas Martii dies Cinerum"));
+        assert!(n.latin.contains("Nonis Aprilis sanctum Pascha"));
+        assert!(n.latin.ends_with("III Kalendas Decembris Dominica prima Adventus Domini nostri Jesu Christi, \
+cui est honor et gloria, in saecula saeculorum. Amen."));
+        assert!(n.english.contains("On the 5th of April we shall celebrate with joy the holy Pasch"));
+        assert!(n.english.contains("On the 29th of November the First Sunday of the Advent"));
+    }
+
+    #[test]
+    fn test_styles() {
+        let n = noveritis(2026, LatinStyle::Ligature);
+        assert!(n.latin.contains("in sæcula sæculorum"));
+        assert_eq!(english_date(NaiveDate::from_ymd_opt(2026, 5, 22).unwrap()), "the 22nd of May");
+    }
+}
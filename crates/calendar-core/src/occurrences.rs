@@ -0,0 +1,87 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::{Calendar, CalendarOptions};
+use crate::types::*;
+
+/// What became of a celebration in one year
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Occurrence {
+    pub year: i32,
+    /// The day the celebration falls on by the calendar
+    pub date: NaiveDate,
+    pub season: LiturgicalSeason,
+    pub outcome: Outcome,
+    /// The day it is kept: `date` unless transferred; `None` when omitted
+    pub kept: Option<NaiveDate>,
+    /// The celebration of `date` when this one is not celebrated there
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impeded_by: Option<String>,
+}
+
+/// What became of a celebration in a calendar's year, or `None` if it does
+/// not occur
+pub fn occurrence(calendar: &Calendar, id: &str) -> Option<Occurrence> {
+    let (day, candidate) = calendar.days().values().find_map(|day| {
+        let candidate = day.trace.as_ref()?.candidates.iter().find(|c| c.id == id)?;
+        Some((day, candidate))
+    })?;
+    let kept = match candidate.outcome {
+        Outcome::Celebrated | Outcome::Commemorated => Some(day.date),
+        Outcome::Omitted => None,
+        Outcome::Transferred => calendar
+            .days()
+            .range(day.date..)
+            .find(|(_, d)| d.celebration.id == id)
+            .map(|(date, _)| *date),
+    };
+    let impeded_by = (candidate.outcome != Outcome::Celebrated).then(|| day.celebration.id.clone());
+    Some(Occurrence {
+        year: calendar.year(),
+        date: day.date,
+        season: day.season,
+        outcome: candidate.outcome,
+        kept,
+        impeded_by,
+    })
+}
+
+/// What became of a celebration in each year of a range, e.g. every year
+/// St. Joseph falls in Holy Week; years in which it does not occur are left
+/// out
+pub fn occurrences(id: &str, from: i32, to: i32, options: &CalendarOptions) -> Vec<Occurrence> {
+    (from..=to)
+        .filter_map(|year| occurrence(&Calendar::with_options(year, options.clone()), id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn test_celebrated_and_transferred() {
+        let joseph = occurrence(&Calendar::new(2026), "st-joseph").unwrap();
+        assert_eq!(joseph.outcome, Outcome::Celebrated);
+        assert_eq!(joseph.kept, Some(d(2026, 3, 19)));
+        assert_eq!(joseph.impeded_by, None);
+        // Monday of Holy Week in 2035
+        let joseph = occurrence(&Calendar::new(2035), "st-joseph").unwrap();
+        assert_eq!((joseph.season, joseph.outcome), (LiturgicalSeason::HolyWeek, Outcome::Transferred));
+        // after the Annunciation, which takes the first free day
+        assert_eq!(joseph.kept, Some(d(2035, 4, 3)));
+        assert_eq!(joseph.impeded_by.as_deref(), Some("feria-holy-week-week-1-mon"));
+    }
+
+    #[test]
+    fn test_range() {
+        let found = occurrences("st-matthias", 2024, 2030, &CalendarOptions::default());
+        assert_eq!(found.len(), 7);
+        assert!(found.iter().all(|o| o.kept.is_some() || o.outcome == Outcome::Omitted));
+        assert!(occurrences("st-nobody", 2026, 2027, &CalendarOptions::default()).is_empty());
+    }
+}
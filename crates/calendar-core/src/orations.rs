@@ -0,0 +1,251 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::types::*;
+
+/// Which anniversary a registered date commemorates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnniversaryOf {
+    PopeElection,
+    PopeCoronation,
+    BishopElection,
+    BishopConsecration,
+}
+
+/// A yearly anniversary on which the collect for the Pope or the bishop is added
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Anniversary {
+    pub of: AnniversaryOf,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Anniversary {
+    /// Whether the anniversary is kept on a date; one of the 29th of
+    /// February is kept on the 28th in common years
+    pub fn falls_on(&self, date: NaiveDate) -> bool {
+        let day = if (self.month, self.day) == (2, 29) && !date.leap_year() { 28 } else { self.day };
+        (self.month, day) == (date.month(), date.day())
+    }
+}
+
+/// A collect commanded by the bishop (oratio imperata) for a period of time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Imperata {
+    pub title: String,
+    pub title_vernacular: Option<String>,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Commanded for a grave cause; such a collect is still said on Class II days
+    #[serde(default)]
+    pub pro_re_gravi: bool,
+    /// Texts of the commanded orations, as the bishop's decree gives them
+    #[serde(default)]
+    pub collect: Option<TextPair>,
+    #[serde(default)]
+    pub secret: Option<TextPair>,
+    #[serde(default)]
+    pub postcommunion: Option<TextPair>,
+}
+
+/// Local configuration of anniversaries and commanded collects
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrationRegistry {
+    #[serde(default)]
+    pub anniversaries: Vec<Anniversary>,
+    #[serde(default)]
+    pub imperata: Vec<Imperata>,
+}
+
+/// Highest number of orations said under the rubrics before a simple
+/// imperata is left out (collect of the day included)
+const MAX_ORATIONS: usize = 3;
+
+impl OrationRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_anniversary(mut self, of: AnniversaryOf, month: u32, day: u32) -> Self {
+        self.anniversaries.push(Anniversary { of, month, day });
+        self
+    }
+
+    pub fn with_imperata(mut self, imperata: Imperata) -> Self {
+        self.imperata.push(imperata);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.anniversaries.is_empty() && self.imperata.is_empty()
+    }
+
+    /// Orations to add after the collect of the day and its commemorations.
+    ///
+    /// None are said on Class I days. On Class II days only the anniversary
+    /// collects and an imperata commanded pro re gravi are said. A simple
+    /// imperata is added only while the total stays within three orations.
+    pub fn orations_for(
+        &self,
+        date: NaiveDate,
+        winner: &Celebration,
+        commemorations: &[Celebration],
+    ) -> Vec<AddedOration> {
+        if winner.rank == CelebrationRank::ClassI {
+            return Vec::new();
+        }
+        let restricted = winner.rank == CelebrationRank::ClassII;
+
+        let mut orations = Vec::new();
+
+        // Pope before bishop, and each only once even if both the election
+        // and coronation (or consecration) fall on the same day
+        for kind in [OrationKind::PopeAnniversary, OrationKind::BishopAnniversary] {
+            let falls = self.anniversaries.iter().any(|a| a.falls_on(date) && anniversary_kind(a.of) == kind);
+            if falls {
+                orations.push(AddedOration::anniversary(kind));
+            }
+        }
+
+        for imp in &self.imperata {
+            if date < imp.from || date > imp.to {
+                continue;
+            }
+            if !imp.pro_re_gravi {
+                if restricted {
+                    continue;
+                }
+                if 1 + commemorations.len() + orations.len() >= MAX_ORATIONS {
+                    continue;
+                }
+            }
+            orations.push(AddedOration {
+                kind: OrationKind::Imperata,
+                title: imp.title.clone(),
+                title_vernacular: imp.title_vernacular.clone(),
+                collect: imp.collect.clone(),
+                secret: imp.secret.clone(),
+                postcommunion: imp.postcommunion.clone(),
+            });
+        }
+
+        orations
+    }
+}
+
+fn anniversary_kind(of: AnniversaryOf) -> OrationKind {
+    match of {
+        AnniversaryOf::PopeElection | AnniversaryOf::PopeCoronation => OrationKind::PopeAnniversary,
+        AnniversaryOf::BishopElection | AnniversaryOf::BishopConsecration => {
+            OrationKind::BishopAnniversary
+        }
+    }
+}
+
+impl AddedOration {
+    fn anniversary(kind: OrationKind) -> Self {
+        let (title, title_en) = match kind {
+            OrationKind::PopeAnniversary => ("Pro Papa", "For the Pope"),
+            OrationKind::BishopAnniversary => ("Pro Episcopo", "For the Bishop"),
+            OrationKind::Imperata => unreachable!("imperata carry their own title"),
+        };
+        Self {
+            kind,
+            title: title.into(),
+            title_vernacular: Some(title_en.into()),
+            collect: None,
+            secret: None,
+            postcommunion: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, day).unwrap()
+    }
+
+    fn rain() -> Imperata {
+        Imperata {
+            title: "Ad petendam pluviam".into(),
+            title_vernacular: Some("For rain".into()),
+            from: d(6, 1),
+            to: d(6, 30),
+            pro_re_gravi: false,
+            collect: None,
+            secret: None,
+            postcommunion: None,
+        }
+    }
+
+    fn saint(rank: CelebrationRank) -> Celebration {
+        Celebration::new(
+            "some-saint", "S. Alicujus", "Some Saint",
+            rank, CelebrationCategory::Feast,
+            LiturgicalColor::White,
+        )
+    }
+
+    #[test]
+    fn test_none_on_class_i() {
+        let reg = OrationRegistry::new()
+            .with_anniversary(AnniversaryOf::PopeCoronation, 6, 10)
+            .with_imperata(rain());
+        assert!(reg.orations_for(d(6, 10), &saint(CelebrationRank::ClassI), &[]).is_empty());
+    }
+
+    #[test]
+    fn test_class_ii_keeps_only_restricted_set() {
+        let grave = Imperata { pro_re_gravi: true, title: "Pro pace".into(), ..rain() };
+        let reg = OrationRegistry::new()
+            .with_anniversary(AnniversaryOf::BishopConsecration, 6, 10)
+            .with_imperata(rain())
+            .with_imperata(grave);
+        let orations = reg.orations_for(d(6, 10), &saint(CelebrationRank::ClassII), &[]);
+        let titles: Vec<&str> = orations.iter().map(|o| o.title.as_str()).collect();
+        assert_eq!(titles, vec!["Pro Episcopo", "Pro pace"]);
+    }
+
+    #[test]
+    fn test_pope_before_bishop() {
+        let reg = OrationRegistry::new()
+            .with_imperata(rain())
+            .with_anniversary(AnniversaryOf::BishopElection, 6, 10)
+            .with_anniversary(AnniversaryOf::PopeElection, 6, 10)
+            .with_anniversary(AnniversaryOf::PopeCoronation, 6, 10);
+        let orations = reg.orations_for(d(6, 10), &saint(CelebrationRank::ClassIII), &[]);
+        let kinds: Vec<OrationKind> = orations.iter().map(|o| o.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![OrationKind::PopeAnniversary, OrationKind::BishopAnniversary]
+        );
+    }
+
+    #[test]
+    fn test_simple_imperata_limited_to_three_orations() {
+        let reg = OrationRegistry::new().with_imperata(rain());
+        let winner = saint(CelebrationRank::ClassIII);
+        assert_eq!(reg.orations_for(d(6, 10), &winner, &[]).len(), 1);
+        let comm = saint(CelebrationRank::ClassIV);
+        assert!(reg.orations_for(d(6, 10), &winner, &[comm.clone(), comm]).is_empty());
+    }
+
+    #[test]
+    fn test_leap_day_anniversary() {
+        let reg = OrationRegistry::new().with_anniversary(AnniversaryOf::BishopConsecration, 2, 29);
+        let winner = saint(CelebrationRank::ClassIII);
+        let on = |y, m, day| reg.orations_for(NaiveDate::from_ymd_opt(y, m, day).unwrap(), &winner, &[]).len();
+        assert_eq!((on(2028, 2, 28), on(2028, 2, 29)), (0, 1));
+        assert_eq!((on(2026, 2, 28), on(2026, 3, 1)), (1, 0));
+    }
+
+    #[test]
+    fn test_imperata_outside_period() {
+        let reg = OrationRegistry::new().with_imperata(rain());
+        assert!(reg.orations_for(d(7, 1), &saint(CelebrationRank::ClassIII), &[]).is_empty());
+    }
+}
@@ -0,0 +1,222 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::propers;
+use crate::types::*;
+
+/// Gloria, Credo and Preface for the Mass of the day.
+///
+/// Returns `None` on Good Friday, when no Mass is said. Under the 1962
+/// rubrics the Credo is no longer said on the feasts of Doctors; the
+/// earlier systems keep it.
+pub fn ordinary(
+    date: NaiveDate,
+    celebration: &Celebration,
+    commemorations: &[Celebration],
+    season: LiturgicalSeason,
+    system: RubricalSystem,
+) -> Option<Ordinary> {
+    if celebration.id == "good-friday" {
+        return None;
+    }
+    if celebration.id == "all-souls" {
+        return Some(Ordinary { gloria: false, credo: false, preface: Preface::Dead });
+    }
+    Some(Ordinary {
+        gloria: gloria(celebration, season),
+        credo: credo(date, celebration, commemorations, system),
+        preface: proper_preface(date, celebration).unwrap_or_else(|| seasonal_preface(date, celebration, season)),
+    })
+}
+
+fn gloria(celebration: &Celebration, season: LiturgicalSeason) -> bool {
+    use CelebrationCategory::*;
+
+    let paschaltide = matches!(season, LiturgicalSeason::Easter | LiturgicalSeason::Ascensiontide);
+    match celebration.category {
+        Sunday => !matches!(
+            season,
+            LiturgicalSeason::Advent
+                | LiturgicalSeason::Septuagesima
+                | LiturgicalSeason::Lent
+                | LiturgicalSeason::Passiontide
+                | LiturgicalSeason::HolyWeek
+        ),
+        WithinOctave | OctaveDay => true,
+        // The Ember days of Whitsun week fall within the Octave of Pentecost
+        EmberDay => celebration.rank == CelebrationRank::ClassI,
+        RogationDay | Vigil => false,
+        Feria => paschaltide || matches!(celebration.id.as_str(), "holy-thursday" | "holy-saturday"),
+        _ => true,
+    }
+}
+
+fn credo(
+    date: NaiveDate,
+    celebration: &Celebration,
+    commemorations: &[Celebration],
+    system: RubricalSystem,
+) -> bool {
+    use CelebrationCategory::*;
+
+    let id = celebration.id.as_str();
+    // A commemorated Sunday brings its Credo with it
+    if commemorations.iter().any(|c| c.category == Sunday) {
+        return true;
+    }
+    // Every Mass within the Octave of Christmas has it by reason of the Octave
+    if within_christmas_octave(date) {
+        return true;
+    }
+    match celebration.category {
+        Sunday | WithinOctave | OctaveDay => return true,
+        EmberDay => return celebration.rank == CelebrationRank::ClassI,
+        RogationDay | Vigil => return false,
+        Feria => return id == "holy-thursday",
+        _ => {}
+    }
+    if id == "holy-saturday" {
+        return false;
+    }
+    match celebration.rank {
+        CelebrationRank::ClassI => true,
+        CelebrationRank::ClassII => {
+            celebration.category == FeastOfLord
+                || is_marian(id)
+                || is_apostle(id)
+                || EVANGELISTS.contains(&id)
+                || (system != RubricalSystem::Rubrics1962 && is_doctor(celebration))
+        }
+        _ => system != RubricalSystem::Rubrics1962 && is_doctor(celebration),
+    }
+}
+
+/// The Preface proper to the celebration itself, if any
+fn proper_preface(date: NaiveDate, celebration: &Celebration) -> Option<Preface> {
+    let id = celebration.id.as_str();
+    let preface = match id {
+        "christmas" | "circumcision" | "purification-bvm" | "transfiguration" | "corpus-christi" => Preface::Christmas,
+        "epiphany" | "holy-family" => Preface::Epiphany,
+        "ash-wednesday" => Preface::Lent,
+        "holy-thursday" | "palm-sunday" | "finding-holy-cross" | "exaltation-holy-cross" => Preface::Cross,
+        "holy-saturday" | "easter-sunday" | "low-sunday" => Preface::Easter,
+        "ascension" => Preface::Ascension,
+        "pentecost" => Preface::HolySpirit,
+        "trinity-sunday" => Preface::Trinity,
+        "sacred-heart" => Preface::SacredHeart,
+        "christ-the-king" => Preface::ChristTheKing,
+        "st-joseph" | "st-joseph-worker" => Preface::StJoseph,
+        _ if id.starts_with("easter-octave-") => Preface::Easter,
+        _ if id.starts_with("pentecost-octave-") => Preface::HolySpirit,
+        // The days after the Epiphany repeat its Mass until the 13th
+        _ if date.month() == 1 && (7..=13).contains(&date.day())
+            && celebration.category == CelebrationCategory::Feria => Preface::Epiphany,
+        _ if is_marian(id) => Preface::BlessedVirgin,
+        // Within the Octave of Christmas even St. John takes the Nativity's
+        _ if within_christmas_octave(date) => return None,
+        _ if is_apostle(id) => Preface::Apostles,
+        _ => return None,
+    };
+    Some(preface)
+}
+
+/// The Preface of the season, used when the celebration has none of its own
+fn seasonal_preface(date: NaiveDate, celebration: &Celebration, season: LiturgicalSeason) -> Preface {
+    match season {
+        LiturgicalSeason::Christmas if date.month() == 12 || date.day() < 6 => Preface::Christmas,
+        LiturgicalSeason::Lent => Preface::Lent,
+        LiturgicalSeason::Passiontide | LiturgicalSeason::HolyWeek => Preface::Cross,
+        LiturgicalSeason::Easter => Preface::Easter,
+        LiturgicalSeason::Ascensiontide => Preface::Ascension,
+        // Sundays of the year take the Preface of the Trinity
+        _ if celebration.category == CelebrationCategory::Sunday => Preface::Trinity,
+        _ => Preface::Common,
+    }
+}
+
+fn is_marian(id: &str) -> bool {
+    id.contains("bvm")
+        || id.contains("mary")
+        || id.starts_with("saturday-of-our-lady")
+        || matches!(id, "annunciation" | "holy-rosary" | "immaculate-conception")
+}
+
+/// Feasts of the Apostles, which take their Preface and (from Class II) the Credo
+const APOSTLES: &[&str] = &[
+    "conversion-of-st-paul",
+    "chair-of-st-peter",
+    "st-matthias",
+    "ss-philip-james",
+    "ss-peter-paul",
+    "st-james-greater",
+    "st-bartholomew",
+    "st-matthew",
+    "ss-simon-jude",
+    "st-andrew",
+    "st-thomas-apostle",
+    "st-john-evangelist",
+];
+
+/// Evangelists who were not Apostles: the Credo, but the Common Preface
+const EVANGELISTS: &[&str] = &["st-mark", "st-luke"];
+
+fn is_apostle(id: &str) -> bool {
+    APOSTLES.contains(&id)
+}
+
+/// Christmas Day to its Octave Day, the 1st of January
+fn within_christmas_octave(date: NaiveDate) -> bool {
+    (date.month() == 12 && date.day() >= 25) || (date.month() == 1 && date.day() == 1)
+}
+
+/// Doctors whose Mass is not taken from the Common of Doctors
+const OTHER_DOCTORS: &[&str] = &["st-gregory-great"];
+
+fn is_doctor(celebration: &Celebration) -> bool {
+    propers::common_of(&celebration.id) == Some(Common::Doctors)
+        || OTHER_DOCTORS.contains(&celebration.id.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_sundays() {
+        let advent = Celebration::sunday(LiturgicalSeason::Advent, 2);
+        let o = ordinary(date(12, 6), &advent, &[], LiturgicalSeason::Advent, RubricalSystem::Rubrics1962).unwrap();
+        assert_eq!((o.gloria, o.credo, o.preface), (false, true, Preface::Trinity));
+        let lent = Celebration::sunday(LiturgicalSeason::Lent, 2);
+        let o = ordinary(date(3, 1), &lent, &[], LiturgicalSeason::Lent, RubricalSystem::Rubrics1962).unwrap();
+        assert_eq!((o.gloria, o.credo, o.preface), (false, true, Preface::Lent));
+        let easter = Celebration::sunday(LiturgicalSeason::Easter, 3);
+        let o = ordinary(date(4, 19), &easter, &[], LiturgicalSeason::Easter, RubricalSystem::Rubrics1962).unwrap();
+        assert_eq!((o.gloria, o.credo, o.preface), (true, true, Preface::Easter));
+    }
+
+    #[test]
+    fn test_ferias() {
+        let feria = Celebration::feria(LiturgicalSeason::AfterPentecost, 7, chrono::Weekday::Tue);
+        let o = ordinary(date(7, 14), &feria, &[], LiturgicalSeason::AfterPentecost, RubricalSystem::Rubrics1962).unwrap();
+        assert_eq!((o.gloria, o.credo, o.preface), (false, false, Preface::Common));
+        let feria = Celebration::feria(LiturgicalSeason::Easter, 3, chrono::Weekday::Tue);
+        let o = ordinary(date(4, 21), &feria, &[], LiturgicalSeason::Easter, RubricalSystem::Rubrics1962).unwrap();
+        assert!(o.gloria && !o.credo);
+    }
+
+    #[test]
+    fn test_credo_for_doctors_before_1962() {
+        let gregory = Celebration::new(
+            "st-gregory-great", "S. Gregorii I Papae", "St. Gregory the Great, Pope and Doctor",
+            CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White,
+        );
+        let o = ordinary(date(3, 12), &gregory, &[], LiturgicalSeason::Lent, RubricalSystem::Rubrics1962).unwrap();
+        assert!(o.gloria && !o.credo);
+        assert_eq!(o.preface, Preface::Lent);
+        let o = ordinary(date(3, 12), &gregory, &[], LiturgicalSeason::Lent, RubricalSystem::PrePius).unwrap();
+        assert!(o.credo);
+    }
+}
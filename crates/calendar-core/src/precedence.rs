@@ -1,65 +1,120 @@
+use chrono::NaiveDate;
+
+use crate::rubrics::{self, RubricTables};
 use crate::types::*;
 
-/// Resolve precedence between temporal and sanctoral celebrations for a given day.
-/// Returns (winner, commemorations).
-///
-/// The 1962 precedence table (simplified):
-/// 1. Easter Triduum, Easter, Christmas, Pentecost (precedence 1)
-/// 2. Sundays of Class I (precedence 2)
-/// 3. Octave days/within octave of Class I (precedence 3)
-/// 4. Solemnities of Class I (precedence 4)
-/// 5. Feasts of the Lord, Class I (precedence 5) -- actually shares with Class II Lord feasts
-/// 6. Sundays of Class II (precedence 6)
-/// 7. Feasts of Class II (precedence 7)
-/// 8. Privileged ferias (precedence 8)
-/// 9. Feasts of Class III (precedence 9)
-/// 10. Ferias of Advent Dec 17-23 (precedence 10)
-/// 11. Ordinary ferias, Class IV (precedence 11)
+/// Resolve precedence between temporal and sanctoral celebrations for a given day
+/// under the 1962 rubrics. Returns (winner, commemorations).
 pub fn resolve_precedence(
     temporal_celebration: &Celebration,
     sanctoral_celebrations: &[Celebration],
 ) -> (Celebration, Vec<Celebration>) {
-    let mut all: Vec<&Celebration> = Vec::new();
-    all.push(temporal_celebration);
+    let tables = rubrics::tables(RubricalSystem::Rubrics1962);
+    let r = resolve_day(tables, temporal_celebration, sanctoral_celebrations, None);
+    (r.winner, r.commemorations)
+}
+
+/// An impeded feast waiting for a free day
+#[derive(Debug, Clone)]
+pub struct Transfer {
+    pub from: NaiveDate,
+    pub celebration: Celebration,
+}
+
+/// Outcome of precedence for one day
+#[derive(Debug, Clone)]
+pub struct Resolution {
+    pub winner: Celebration,
+    pub commemorations: Vec<Celebration>,
+    /// Impeded feasts to move to the next free day
+    pub transferred: Vec<Celebration>,
+    pub trace: PrecedenceTrace,
+}
+
+/// Resolve precedence for a day against the tables of a rubrical system,
+/// recording why each candidate won, was commemorated, omitted or
+/// transferred. A feast transferred from an earlier day competes with the
+/// day's own celebrations.
+///
+/// Candidates are ordered by their row in the table of precedence, then by
+/// rank, save where the tables prefer one by exception; the occurrence
+/// table decides what becomes of each loser.
+pub fn resolve_day(
+    tables: &RubricTables,
+    temporal_celebration: &Celebration,
+    sanctoral_celebrations: &[Celebration],
+    transfer: Option<&Transfer>,
+) -> Resolution {
+    let mut all: Vec<(&Celebration, Option<NaiveDate>)> = Vec::new();
+    all.push((temporal_celebration, None));
     for c in sanctoral_celebrations {
-        all.push(c);
+        all.push((c, None));
+    }
+    if let Some(t) = transfer {
+        all.push((&t.celebration, Some(t.from)));
     }
 
-    // Sort by precedence number (lower wins), then by rank
-    all.sort_by(|a, b| {
-        a.precedence.cmp(&b.precedence)
-            .then(a.rank.precedence_value().cmp(&b.rank.precedence_value()))
-    });
+    // Sort by row of the table (lower wins), then by rank
+    all.sort_by(|(a, _), (b, _)| tables.order(a, b));
 
-    let winner = all[0].clone();
+    let (winner, transferred_from) = all[0];
     let mut commemorations = Vec::new();
+    let mut transferred = Vec::new();
+    let mut trace = PrecedenceTrace::default();
 
-    // Commemorations: lower-ranked celebrations that are at least Class III
-    // or privileged ferias can be commemorated.
-    // In 1962 rubrics, Class IV feasts are commemorated; Class III feasts
-    // impeded by a higher celebration are commemorated.
-    for c in &all[1..] {
-        match c.rank {
-            CelebrationRank::ClassI => {
-                // Class I feasts impeded are transferred, not commemorated.
-                // For now we skip transfer logic and just commemorate.
-                commemorations.push((*c).clone());
-            }
-            CelebrationRank::ClassII
-            | CelebrationRank::ClassIII
-            | CelebrationRank::FeriaPrivileged => {
-                commemorations.push((*c).clone());
-            }
-            CelebrationRank::ClassIV => {
-                commemorations.push((*c).clone());
-            }
-            CelebrationRank::Feria => {
-                // Ordinary ferias are not commemorated
-            }
+    let preferred = all[1..].iter().find_map(|(c, _)| Some((c, tables.preference(winner, c)?)));
+    let (reason, citation) = match (transferred_from, preferred) {
+        (Some(from), _) => (
+            format!("Transferred from {}; first day not occupied by a Class I or II celebration", from),
+            "RG 95",
+        ),
+        (None, Some((c, p))) => (format!("Preferred to {} by exception to the table of precedence", c.id), p.citation),
+        (None, None) if all.len() == 1 => ("Only celebration of the day".to_string(), "RG 91"),
+        (None, None) => (
+            format!("Highest in the table of precedence (row {})", tables.row(winner).row),
+            "RG 91",
+        ),
+    };
+    trace.candidates.push(candidate(tables, winner, Outcome::Celebrated, reason, citation));
+
+    for (c, _) in &all[1..] {
+        let rule = tables.occurrence(winner, c);
+        match rule.outcome {
+            Outcome::Commemorated => commemorations.push((*c).clone()),
+            Outcome::Transferred => transferred.push((*c).clone()),
+            Outcome::Omitted | Outcome::Celebrated => {}
         }
+        let reason = format!("Impeded by {}; {}", winner.id, rule.reason);
+        trace.candidates.push(candidate(tables, c, rule.outcome, reason, rule.citation));
+    }
+
+    Resolution {
+        winner: winner.clone(),
+        commemorations,
+        transferred,
+        trace,
     }
+}
 
-    (winner, commemorations)
+/// Trace entry for a celebration under the given tables.
+pub fn candidate(
+    tables: &RubricTables,
+    c: &Celebration,
+    outcome: Outcome,
+    reason: String,
+    citation: &str,
+) -> TraceCandidate {
+    let row = tables.row(c);
+    TraceCandidate {
+        id: c.id.clone(),
+        title: c.title.clone(),
+        rank: c.rank,
+        precedence: row.row,
+        row: row.description.to_string(),
+        outcome,
+        reason,
+        citation: citation.to_string(),
+    }
 }
 
 #[cfg(test)]
@@ -71,14 +126,14 @@ mod tests {
         let temporal = Celebration::new(
             "easter-sunday", "Dominica Resurrectionis", "Easter Sunday",
             CelebrationRank::ClassI, CelebrationCategory::Solemnity,
-            LiturgicalColor::White, 1,
+            LiturgicalColor::White,
         );
         let sanctoral = Celebration::new(
             "some-saint", "S. Alicujus", "Some Saint",
             CelebrationRank::ClassIII, CelebrationCategory::Feast,
-            LiturgicalColor::White, 9,
+            LiturgicalColor::White,
         );
-        let (winner, comms) = resolve_precedence(&temporal, &[sanctoral.clone()]);
+        let (winner, comms) = resolve_precedence(&temporal, &[sanctoral]);
         assert_eq!(winner.id, "easter-sunday");
         assert_eq!(comms.len(), 1);
         assert_eq!(comms[0].id, "some-saint");
@@ -90,7 +145,7 @@ mod tests {
         let sanctoral = Celebration::new(
             "all-saints", "Omnium Sanctorum", "All Saints",
             CelebrationRank::ClassI, CelebrationCategory::Solemnity,
-            LiturgicalColor::White, 4,
+            LiturgicalColor::White,
         );
         let (winner, comms) = resolve_precedence(&temporal, &[sanctoral]);
         assert_eq!(winner.id, "all-saints");
@@ -104,9 +159,9 @@ mod tests {
         let sanctoral = Celebration::new(
             "st-ambrose", "S. Ambrosii", "St. Ambrose",
             CelebrationRank::ClassIII, CelebrationCategory::Feast,
-            LiturgicalColor::White, 9,
+            LiturgicalColor::White,
         );
-        let (winner, comms) = resolve_precedence(&temporal, &[sanctoral.clone()]);
+        let (winner, comms) = resolve_precedence(&temporal, &[sanctoral]);
         // Advent Sunday Class I (prec 6 for week 2) vs Class III (prec 9)
         assert_eq!(winner.category, CelebrationCategory::Sunday);
         assert_eq!(comms.len(), 1);
@@ -117,12 +172,12 @@ mod tests {
         let temporal = Celebration::new(
             "lent-feria", "Feria", "Lenten Feria",
             CelebrationRank::FeriaPrivileged, CelebrationCategory::Feria,
-            LiturgicalColor::Violet, 8,
+            LiturgicalColor::Violet,
         );
         let sanctoral = Celebration::new(
             "minor-saint", "S. Minoris", "Minor Saint",
             CelebrationRank::ClassIV, CelebrationCategory::Memorial,
-            LiturgicalColor::White, 11,
+            LiturgicalColor::White,
         );
         let (winner, comms) = resolve_precedence(&temporal, &[sanctoral]);
         assert_eq!(winner.id, "lent-feria");
@@ -134,7 +189,7 @@ mod tests {
         let sanctoral = Celebration::new(
             "st-someone", "S. Alicujus", "St. Someone",
             CelebrationRank::ClassIII, CelebrationCategory::Feast,
-            LiturgicalColor::White, 9,
+            LiturgicalColor::White,
         );
         let temporal = Celebration::feria(LiturgicalSeason::AfterPentecost, 5, chrono::Weekday::Tue);
         let (winner, comms) = resolve_precedence(&temporal, &[sanctoral]);
@@ -142,4 +197,45 @@ mod tests {
         // Ordinary feria is NOT commemorated
         assert!(comms.is_empty());
     }
+
+    #[test]
+    fn test_impeded_class_i_feast_is_transferred() {
+        let holy_week = Celebration::feria(LiturgicalSeason::HolyWeek, 1, chrono::Weekday::Mon);
+        let annunciation = Celebration::new(
+            "annunciation", "In Annuntiatione B.M.V.", "The Annunciation of the BVM",
+            CelebrationRank::ClassI, CelebrationCategory::FeastOfLord,
+            LiturgicalColor::White,
+        );
+        let tables = rubrics::tables(RubricalSystem::Rubrics1962);
+        let r = resolve_day(tables, &holy_week, &[annunciation], None);
+        assert_eq!(r.winner.category, CelebrationCategory::Feria);
+        assert!(r.commemorations.is_empty());
+        assert_eq!(r.transferred[0].id, "annunciation");
+        assert_eq!(r.trace.candidates[1].outcome, Outcome::Transferred);
+        assert_eq!(r.trace.candidates[1].citation, "RG 95");
+    }
+
+    #[test]
+    fn test_trace_lists_every_candidate() {
+        let temporal = Celebration::feria(LiturgicalSeason::AfterPentecost, 5, chrono::Weekday::Tue);
+        let saint = Celebration::new(
+            "st-someone", "S. Alicujus", "St. Someone",
+            CelebrationRank::ClassIII, CelebrationCategory::Feast,
+            LiturgicalColor::White,
+        );
+        let transfer = Transfer {
+            from: NaiveDate::from_ymd_opt(2026, 3, 25).unwrap(),
+            celebration: Celebration::new(
+                "annunciation", "In Annuntiatione B.M.V.", "The Annunciation of the BVM",
+                CelebrationRank::ClassI, CelebrationCategory::FeastOfLord,
+                LiturgicalColor::White,
+            ),
+        };
+        let tables = rubrics::tables(RubricalSystem::Rubrics1962);
+        let r = resolve_day(tables, &temporal, &[saint], Some(&transfer));
+        let outcomes: Vec<Outcome> = r.trace.candidates.iter().map(|c| c.outcome).collect();
+        assert_eq!(outcomes, vec![Outcome::Celebrated, Outcome::Commemorated, Outcome::Omitted]);
+        assert_eq!(r.trace.candidates[0].id, "annunciation");
+        assert!(r.trace.candidates[0].reason.contains("2026-03-25"));
+    }
 }
@@ -0,0 +1,458 @@
+use crate::commons::{get_common, with_name};
+use crate::latin::{self, LatinStyle};
+use crate::sanctoral;
+use crate::types::*;
+
+/// Get the proper texts of a Mass by celebration ID, with the parts a
+/// feast lacks taken from its Common and the saint's name said in them.
+/// Texts follow the 1962 Missale Romanum. Only Christmas, Easter Sunday,
+/// Pentecost and St. Thomas Aquinas are seeded, besides the feasts with a
+/// Common; any other ID gives `None`. The Epistle and Gospel are not yet
+/// carried here (see `readings::get_readings` for their references).
+/// The Latin is in its canonical accented form (see `latin::render`).
+pub fn get_propers(celebration_id: &str) -> Option<ProperTexts> {
+    let parts = proper_parts(celebration_id);
+    match sanctoral::common_of(celebration_id) {
+        Some((common, name)) => Some(merge(parts.unwrap_or_default(), with_name(get_common(*common), name))),
+        None => parts,
+    }
+}
+
+/// The Common a feast takes its Mass from, as the sanctoral gives it
+pub fn common_of(celebration_id: &str) -> Option<Common> {
+    sanctoral::common_of(celebration_id).map(|(common, _)| *common)
+}
+
+/// Parts proper to a celebration; for a feast with a Common, only the
+/// parts that override it
+fn proper_parts(celebration_id: &str) -> Option<ProperTexts> {
+    let p = match celebration_id {
+        // Midnight Mass, whose readings are those in `readings`
+        "christmas" => ProperTexts {
+            introit: Some(tp(
+                Some("Ps 2:7, 1"),
+                "Dóminus dixit ad me: Fílius meus es tu, ego hódie génui te. Ps. Quare fremuérunt gentes: et pópuli meditáti sunt inánia?",
+                "The Lord hath said to me: Thou art My Son, this day have I begotten Thee. Ps. Why have the Gentiles raged, and the people devised vain things?",
+            )),
+            collect: Some(tp(
+                None,
+                "Deus, qui hanc sacratíssimam noctem veri lúminis fecit illustratióne claréscere: da, quáesumus; ut, cujus lucis mystéria in terra cognóvimus, ejus quoque gáudiis in caelo perfruámur.",
+                "O God, who hast made this most sacred night to shine with the brightness of the true Light: grant, we beseech Thee, that we who have known the mysteries of His light on earth may also enjoy His happiness in heaven.",
+            )),
+            gradual: Some(tp(
+                Some("Ps 109:3, 1; Ps 2:7"),
+                "Tecum princípium in die virtútis tuae: in splendóribus sanctórum, ex útero ante lucíferum génui te. V. Dixit Dóminus Dómino meo: Sede a dextris meis: donec ponam inimícos tuos scabéllum pedum tuórum. Allelúja, allelúja. V. Dóminus dixit ad me: Fílius meus es tu, ego hódie génui te. Allelúja.",
+                "With Thee is the principality in the day of Thy strength: in the brightness of the saints, from the womb before the day-star I begot Thee. V. The Lord said to my Lord: Sit Thou at My right hand, until I make Thy enemies Thy footstool. Alleluia, alleluia. V. The Lord hath said to me: Thou art My Son, this day have I begotten Thee. Alleluia.",
+            )),
+            offertory: Some(tp(
+                Some("Ps 95:11, 13"),
+                "Laeténtur caeli, et exsúltet terra ante fáciem Dómini: quóniam venit.",
+                "Let the heavens rejoice, and let the earth be glad before the face of the Lord, because He cometh.",
+            )),
+            secret: Some(tp(
+                None,
+                "Accépta tibi sit, Dómine, quáesumus, hodiérnae festivitátis oblátio: ut, tua grátia largiénte, per haec sacrosáncta commércia, in illíus inveniámur forma, in quo tecum est nostra substántia.",
+                "May the oblation of this day's festival be pleasing to Thee, O Lord, we beseech Thee: that by the bounty of Thy grace we may, through this holy intercourse, be found like unto Him in whom our substance is united to Thee.",
+            )),
+            communion: Some(tp(
+                Some("Ps 109:3"),
+                "In splendóribus sanctórum, ex útero ante lucíferum génui te.",
+                "In the brightness of the saints, from the womb before the day-star I begot Thee.",
+            )),
+            postcommunion: Some(tp(
+                None,
+                "Da nobis, quáesumus, Dómine Deus noster: ut, qui Nativitátem Dómini nostri Jesu Christi mystériis nos frequentáre gaudémus; dignis conversatiónibus ad ejus mereámur perveníre consórtium.",
+                "Grant, we beseech Thee, O Lord our God, that we who rejoice to celebrate in these mysteries the Nativity of our Lord Jesus Christ may by worthy conduct deserve to attain to fellowship with Him.",
+            )),
+            last_gospel: Some(last_gospel()),
+            ..Default::default()
+        },
+
+        "easter-sunday" => ProperTexts {
+            introit: Some(tp(
+                Some("Ps 138:18, 5-6, 1-2"),
+                "Resurréxi, et adhuc tecum sum, allelúja: posuísti super me manum tuam, allelúja: mirábilis facta est sciéntia tua, allelúja, allelúja. Ps. Dómine, probásti me, et cognovísti me: tu cognovísti sessiónem meam, et resurrectiónem meam.",
+                "I arose, and am still with Thee, alleluia: Thou hast laid Thy hand upon me, alleluia: Thy knowledge is become wonderful, alleluia, alleluia. Ps. Lord, Thou hast proved me and known me: Thou hast known my sitting down and my rising up.",
+            )),
+            collect: Some(tp(
+                None,
+                "Deus, qui hodiérna die per Unigénitum tuum aeternitátis nobis áditum, devícta morte, reserásti: vota nostra, quae praeveniéndo aspíras, étiam adjuvándo proséquere.",
+                "O God, who on this day, through Thine only-begotten Son, didst overcome death and open unto us the gate of everlasting life: as by Thy prevenient grace Thou dost breathe into us good desires, so by Thy help do Thou bring them to good effect.",
+            )),
+            gradual: Some(tp(
+                Some("Ps 117:24, 1; 1 Cor 5:7"),
+                "Haec dies, quam fecit Dóminus: exsultémus et laetémur in ea. V. Confitémini Dómino, quóniam bonus: quóniam in sáeculum misericórdia ejus. Allelúja, allelúja. V. Pascha nostrum immolátus est Christus.",
+                "This is the day which the Lord hath made: let us be glad and rejoice therein. V. Give praise to the Lord, for He is good: for His mercy endureth for ever. Alleluia, alleluia. V. Christ our Pasch is sacrificed.",
+            )),
+            sequence: Some(tp(
+                None,
+                "Víctimae pascháli laudes ímmolent Christiáni. Agnus redémit oves: Christus ínnocens Patri reconciliávit peccatóres. Mors et vita duéllo conflixére mirándo: dux vitae mórtuus, regnat vivus. Dic nobis María, quid vidísti in via? Sepúlcrum Christi vivéntis, et glóriam vidi resurgéntis: Angélicos testes, sudárium, et vestes. Surréxit Christus spes mea: praecédet suos in Galiláeam. Scimus Christum surrexísse a mórtuis vere: tu nobis, victor Rex, miserére. Amen. Allelúja.",
+                "To the Paschal Victim let Christians offer songs of praise. The Lamb redeemed the sheep: Christ, the innocent one, hath reconciled sinners to the Father. Death and life contended in a wondrous combat: the Prince of life, who died, reigns alive. Tell us, Mary, what didst thou see on the way? I saw the sepulchre of the living Christ, and the glory of Him that rose: the angelic witnesses, the napkin and the linen cloths. Christ my hope is risen: He will go before His own into Galilee. We know that Christ is truly risen from the dead: do Thou, victorious King, have mercy on us. Amen. Alleluia.",
+            )),
+            offertory: Some(tp(
+                Some("Ps 75:9-10"),
+                "Terra trémuit, et quiévit, dum resúrgeret in judício Deus, allelúja.",
+                "The earth trembled and was still, when God arose in judgment, alleluia.",
+            )),
+            secret: Some(tp(
+                None,
+                "Súscipe, quáesumus, Dómine, preces pópuli tui cum oblatiónibus hostiárum: ut, paschálibus initiáta mystériis, ad aeternitátis nobis medélam, te operánte, profíciant.",
+                "Receive, we beseech Thee, O Lord, the prayers of Thy people together with the offering of these sacrifices: that what has been begun in the Paschal mysteries may by Thy working avail us unto everlasting healing.",
+            )),
+            communion: Some(tp(
+                Some("1 Cor 5:7-8"),
+                "Pascha nostrum immolátus est Christus, allelúja: ítaque epulémur in ázymis sinceritátis et veritátis, allelúja, allelúja, allelúja.",
+                "Christ our Pasch is immolated, alleluia: therefore let us feast with the unleavened bread of sincerity and truth, alleluia, alleluia, alleluia.",
+            )),
+            postcommunion: Some(tp(
+                None,
+                "Spíritum nobis, Dómine, tuae caritátis infúnde: ut, quos sacraméntis paschálibus satiásti, tua fácias pietáte concórdes.",
+                "Pour forth upon us, O Lord, the spirit of Thy love: that those whom Thou hast filled with the Paschal sacraments may by Thy goodness be of one mind.",
+            )),
+            last_gospel: Some(last_gospel()),
+            ..Default::default()
+        },
+
+        "pentecost" => ProperTexts {
+            introit: Some(tp(
+                Some("Wis 1:7; Ps 67:2"),
+                "Spíritus Dómini replévit orbem terrárum, allelúja: et hoc quod cóntinet ómnia, sciéntiam habet vocis, allelúja, allelúja, allelúja. Ps. Exsúrgat Deus, et dissipéntur inimíci ejus: et fúgiant, qui odérunt eum, a fácie ejus.",
+                "The Spirit of the Lord hath filled the whole world, alleluia: and that which containeth all things hath knowledge of the voice, alleluia, alleluia, alleluia. Ps. Let God arise, and let His enemies be scattered: and let them that hate Him flee from before His face.",
+            )),
+            collect: Some(tp(
+                None,
+                "Deus, qui hodiérna die corda fidélium Sancti Spíritus illustratióne docuísti: da nobis in eódem Spíritu recta sápere; et de ejus semper consolatióne gaudére.",
+                "O God, who on this day didst instruct the hearts of the faithful by the light of the Holy Spirit: grant us by the same Spirit to relish what is right, and evermore to rejoice in His consolation.",
+            )),
+            gradual: Some(tp(
+                Some("Ps 103:30"),
+                "Allelúja, allelúja. V. Emítte Spíritum tuum, et creabúntur, et renovábis fáciem terrae. Allelúja. V. Veni, Sancte Spíritus, reple tuórum corda fidélium: et tui amóris in eis ignem accénde.",
+                "Alleluia, alleluia. V. Send forth Thy Spirit, and they shall be created, and Thou shalt renew the face of the earth. Alleluia. V. Come, Holy Spirit, fill the hearts of Thy faithful: and kindle in them the fire of Thy love.",
+            )),
+            sequence: Some(tp(
+                None,
+                "Veni, Sancte Spíritus, et emítte cáelitus lucis tuae rádium. Veni, pater páuperum, veni, dator múnerum, veni, lumen córdium. Consolátor óptime, dulcis hospes ánimae, dulce refrigérium. In labóre réquies, in aestu tempéries, in fletu solátium. O lux beatíssima, reple cordis íntima tuórum fidélium. Sine tuo númine, nihil est in hómine, nihil est innóxium. Lava quod est sórdidum, riga quod est áridum, sana quod est sáucium. Flecte quod est rígidum, fove quod est frígidum, rege quod est dévium. Da tuis fidélibus, in te confidéntibus, sacrum septenárium. Da virtútis méritum, da salútis éxitum, da perénne gáudium. Amen. Allelúja.",
+                "Come, Holy Spirit, and send forth from heaven the ray of Thy light. Come, Father of the poor; come, Giver of gifts; come, Light of hearts. Thou best of comforters, sweet guest of the soul, sweet refreshment. In labour rest, in heat coolness, in weeping solace. O most blessed Light, fill the inmost hearts of Thy faithful. Without Thy grace there is nothing in man, nothing that is harmless. Cleanse what is defiled, water what is dry, heal what is wounded. Bend what is rigid, warm what is cold, guide what goes astray. Give to Thy faithful who trust in Thee the sacred sevenfold gifts. Give the reward of virtue, give the end of salvation, give everlasting joy. Amen. Alleluia.",
+            )),
+            offertory: Some(tp(
+                Some("Ps 67:29-30"),
+                "Confírma hoc, Deus, quod operátus es in nobis: a templo tuo, quod est in Jerúsalem, tibi ófferent reges múnera, allelúja.",
+                "Confirm, O God, what Thou hast wrought in us: from Thy temple which is in Jerusalem, kings shall offer presents to Thee, alleluia.",
+            )),
+            secret: Some(tp(
+                None,
+                "Múnera, quáesumus, Dómine, obláta sanctífica: et corda nostra Sancti Spíritus illustratióne emúnda.",
+                "Sanctify, we beseech Thee, O Lord, the gifts offered, and cleanse our hearts by the light of the Holy Spirit.",
+            )),
+            communion: Some(tp(
+                Some("Acts 2:2, 4"),
+                "Factus est repénte de caelo sonus, tamquam adveniéntis spíritus veheméntis, ubi erant sedéntes, allelúja: et repléti sunt omnes Spíritu Sancto, loquéntes magnália Dei, allelúja, allelúja.",
+                "Suddenly there came a sound from heaven, as of a mighty wind coming, where they were sitting, alleluia: and they were all filled with the Holy Spirit, speaking the wonderful works of God, alleluia, alleluia.",
+            )),
+            postcommunion: Some(tp(
+                None,
+                "Sancti Spíritus, Dómine, corda nostra mundet infúsio: et sui roris íntima aspersióne fecúndet.",
+                "May the outpouring of the Holy Spirit cleanse our hearts, O Lord, and make them fruitful by the inward sprinkling of His dew.",
+            )),
+            last_gospel: Some(last_gospel()),
+            ..Default::default()
+        },
+
+        // Commune Doctorum with proper orations
+        "st-thomas-aquinas" => ProperTexts {
+            collect: Some(tp(
+                None,
+                "Deus, qui Ecclésiam tuam beáti Thomae Confessóris tui mira eruditióne claríficas, et sancta operatióne fecúndas: da nobis, quáesumus; et quae dócuit, intelléctu conspícere, et quae egit, imitatióne complére.",
+                "O God, who dost enlighten Thy Church by the wondrous learning of blessed Thomas Thy Confessor, and make it fruitful by his holy works: grant us, we beseech Thee, both to understand what he taught and to fulfil by imitation what he did.",
+            )),
+            secret: Some(tp(
+                None,
+                "Sancti Thomae Confessóris tui atque Doctóris nobis, Dómine, pia non desit orátio: quae et múnera nostra concíliet, et tuam nobis indulgéntiam semper obtíneat.",
+                "May the loving prayer of blessed Thomas, Thy Confessor and Doctor, never fail us, O Lord: may it make our offerings acceptable, and ever obtain for us Thy forgiveness.",
+            )),
+            postcommunion: Some(tp(
+                None,
+                "Ut nobis, Dómine, tua sacrifícia dent salútem: beátus Thomas Conféssor tuus et Doctor egrégius, quáesumus, precátor accédat.",
+                "That Thy sacrifices, O Lord, may bring us salvation, may blessed Thomas, Thy Confessor and illustrious Doctor, we beseech Thee, intercede for us.",
+            )),
+            ..Default::default()
+        },
+
+        // Commune Doctorum with proper collect
+        "st-augustine" => ProperTexts {
+            collect: Some(tp(
+                None,
+                "Adésto supplicatiónibus nostris, omnípotens Deus: et, quibus fidúciam sperándae pietátis indúlges, intercedénte beáto Augustíno Confessóre tuo atque Pontífice, consuétae misericórdiae tríbue benígnus efféctum.",
+                "Give ear to our prayers, almighty God: and to those to whom Thou grantest confidence in hoping for Thy mercy, do Thou graciously grant, through the intercession of blessed Augustine, Thy Confessor and Bishop, the effect of Thy wonted mercy.",
+            )),
+            ..Default::default()
+        },
+
+        // Commune Confessoris Pontificis with proper collect
+        "st-patrick" => ProperTexts {
+            collect: Some(tp(
+                None,
+                "Deus, qui ad praedicándam géntibus glóriam tuam beátum Patrícium Confessórem atque Pontíficem míttere dignátus es: ejus méritis et intercessióne concéde; ut, quae nobis agénda praecípis, te miseránte adimplére possímus.",
+                "O God, who didst vouchsafe to send blessed Patrick, Thy Confessor and Bishop, to preach Thy glory to the nations: grant by his merits and intercession that what Thou commandest us to do we may by Thy mercy be able to fulfil.",
+            )),
+            ..Default::default()
+        },
+
+        "st-martin-of-tours" => ProperTexts {
+            collect: Some(tp(
+                None,
+                "Deus, qui cónspicis, quia ex nulla nostra virtúte subsístimus: concéde propítius; ut, intercessióne beáti Martíni Confessóris tui atque Pontíficis, contra ómnia advérsa muniámur.",
+                "O God, who seest that we subsist by no strength of our own: mercifully grant that, by the intercession of blessed Martin, Thy Confessor and Bishop, we may be defended against all adversities.",
+            )),
+            ..Default::default()
+        },
+
+        // Commune plurimorum Martyrum with proper collect
+        "ss-fabian-sebastian" => ProperTexts {
+            collect: Some(tp(
+                None,
+                "Infirmitátem nostram réspice, omnípotens Deus: et, quia pondus própriae actiónis gravat, beatórum Mártyrum tuórum Fabiáni et Sebastiáni intercéssio gloriósa nos prótegat.",
+                "Look upon our weakness, almighty God: and since the burden of our own deeds weighs us down, may the glorious intercession of Thy blessed Martyrs Fabian and Sebastian protect us.",
+            )),
+            ..Default::default()
+        },
+
+        // Commune Confessoris non Pontificis with proper collect
+        "st-francis-of-paola" => ProperTexts {
+            collect: Some(tp(
+                None,
+                "Deus, humílium celsitúdo, qui beátum Francíscum Confessórem tuum Sanctórum tuórum glória sublimásti: tríbue, quáesumus; ut ejus méritis et imitatióne, promíssa humílibus praemia felíciter consequámur.",
+                "O God, the exaltation of the humble, who didst raise blessed Francis, Thy Confessor, to the glory of Thy Saints: grant, we beseech Thee, that by his merits and example we may happily attain the rewards promised to the humble.",
+            )),
+            ..Default::default()
+        },
+
+        // Commune non Virginum with proper collect
+        "st-monica" => ProperTexts {
+            collect: Some(tp(
+                None,
+                "Deus, maeréntium consolátor et in te sperántium salus, qui beátae Mónicae pias in conversióne fílii sui Augustíni lácrimas misericórditer suscepísti: da nobis utriúsque intercessióne; peccáta nostra deploráre, et grátiae tuae véniam inveníre.",
+                "O God, the consoler of the sorrowful and the salvation of them that hope in Thee, who didst mercifully accept the loving tears of blessed Monica for the conversion of her son Augustine: grant us by the intercession of them both to bewail our sins and to find the pardon of Thy grace.",
+            )),
+            ..Default::default()
+        },
+
+        _ => return None,
+    };
+    Some(p)
+}
+
+/// Full Mass texts for a day: the propers of the celebration, followed by
+/// the orations of each commemoration and then of each added oration
+/// (anniversaries, imperata), in the order they are said after the
+/// collect, secret and postcommunion of the day.
+///
+/// Returns `None` when no propers are known for the day's celebration.
+/// Commemorations without known texts are listed with their title only.
+/// The Latin is written in the classical style.
+pub fn mass_texts(day: &LiturgicalDay) -> Option<MassTexts> {
+    mass_texts_in(day, LatinStyle::default())
+}
+
+/// Full Mass texts for a day with the Latin written in a style
+pub fn mass_texts_in(day: &LiturgicalDay, style: LatinStyle) -> Option<MassTexts> {
+    let propers = get_propers(&day.celebration.id)?;
+
+    let mut commemorations: Vec<CommemorationOrations> = day
+        .commemorations
+        .iter()
+        .map(|c| {
+            let p = get_propers(&c.id).unwrap_or_default();
+            CommemorationOrations {
+                id: c.id.clone(),
+                title: c.title.clone(),
+                collect: p.collect,
+                secret: p.secret,
+                postcommunion: p.postcommunion,
+            }
+        })
+        .collect();
+
+    for o in &day.orations {
+        let (id, (collect, secret, postcommunion)) = match o.kind {
+            OrationKind::PopeAnniversary => ("pro-papa", some(pro_papa())),
+            OrationKind::BishopAnniversary => ("pro-episcopo", some(pro_episcopo())),
+            OrationKind::Imperata => ("imperata", (o.collect.clone(), o.secret.clone(), o.postcommunion.clone())),
+        };
+        commemorations.push(CommemorationOrations {
+            id: id.into(),
+            title: o.title.clone(),
+            collect,
+            secret,
+            postcommunion,
+        });
+    }
+
+    let mut texts = MassTexts {
+        celebration_id: day.celebration.id.clone(),
+        common: common_of(&day.celebration.id),
+        propers,
+        commemorations,
+    };
+    latin::render_mass_texts(&mut texts, style);
+    Some(texts)
+}
+
+/// Overlay the proper parts of a feast on its Common
+fn merge(proper: ProperTexts, common: ProperTexts) -> ProperTexts {
+    ProperTexts {
+        introit: proper.introit.or(common.introit),
+        collect: proper.collect.or(common.collect),
+        epistle: proper.epistle.or(common.epistle),
+        gradual: proper.gradual.or(common.gradual),
+        sequence: proper.sequence.or(common.sequence),
+        gospel: proper.gospel.or(common.gospel),
+        offertory: proper.offertory.or(common.offertory),
+        secret: proper.secret.or(common.secret),
+        communion: proper.communion.or(common.communion),
+        postcommunion: proper.postcommunion.or(common.postcommunion),
+        last_gospel: proper.last_gospel.or(common.last_gospel),
+    }
+}
+
+pub(crate) fn tp(reference: Option<&str>, latin: &str, english: &str) -> TextPair {
+    TextPair {
+        reference: reference.map(String::from),
+        latin: latin.into(),
+        vernacular: Some(english.into()),
+    }
+}
+
+/// The Last Gospel, John 1:1-14
+pub(crate) fn last_gospel() -> TextPair {
+    tp(
+        Some("John 1:1-14"),
+        "In princípio erat Verbum, et Verbum erat apud Deum, et Deus erat Verbum. Hoc erat in princípio apud Deum. Ómnia per ipsum facta sunt: et sine ipso factum est nihil, quod factum est: in ipso vita erat, et vita erat lux hóminum: et lux in ténebris lucet, et ténebrae eam non comprehendérunt. Fuit homo missus a Deo, cui nomen erat Joánnes. Hic venit in testimónium, ut testimónium perhibéret de lúmine, ut omnes créderent per illum. Non erat ille lux, sed ut testimónium perhibéret de lúmine. Erat lux vera, quae illúminat omnem hóminem veniéntem in hunc mundum. In mundo erat, et mundus per ipsum factus est, et mundus eum non cognóvit. In própria venit, et sui eum non recepérunt. Quotquot autem recepérunt eum, dedit eis potestátem fílios Dei fíeri, his, qui credunt in nómine ejus: qui non ex sanguínibus, neque ex voluntáte carnis, neque ex voluntáte viri, sed ex Deo nati sunt. Et Verbum caro factum est, et habitávit in nobis: et vídimus glóriam ejus, glóriam quasi Unigéniti a Patre, plenum grátiae et veritátis.",
+        "In the beginning was the Word, and the Word was with God, and the Word was God. The same was in the beginning with God. All things were made by him: and without him was made nothing that was made. In him was life, and the life was the light of men. And the light shineth in darkness, and the darkness did not comprehend it. There was a man sent from God, whose name was John. This man came for a witness, to give testimony of the light, that all men might believe through him. He was not the light, but was to give testimony of the light. That was the true light, which enlighteneth every man that cometh into this world. He was in the world, and the world was made by him, and the world knew him not. He came unto his own, and his own received him not. But as many as received him, he gave them power to be made the sons of God, to them that believe in his name. Who are born, not of blood, nor of the will of the flesh, nor of the will of man, but of God. And the Word was made flesh, and dwelt among us, (and we saw his glory, the glory as it were of the only begotten of the Father,) full of grace and truth.",
+    )
+}
+
+fn some((c, s, p): (TextPair, TextPair, TextPair)) -> (Option<TextPair>, Option<TextPair>, Option<TextPair>) {
+    (Some(c), Some(s), Some(p))
+}
+
+/// Collect, secret and postcommunion Pro Papa, said on the anniversary of
+/// the Pope's election and coronation
+fn pro_papa() -> (TextPair, TextPair, TextPair) {
+    for_shepherd("Ecclésiae tuae", "Thy Church")
+}
+
+/// Collect, secret and postcommunion Pro Episcopo, said in a diocese on the
+/// anniversary of its bishop's election and consecration
+fn pro_episcopo() -> (TextPair, TextPair, TextPair) {
+    for_shepherd("Ecclésiae N.", "the Church of N.")
+}
+
+/// The orations for a shepherd of the Church, set over `church`
+fn for_shepherd(church: &str, church_en: &str) -> (TextPair, TextPair, TextPair) {
+    (
+        tp(
+            None,
+            &format!("Deus, ómnium fidélium pastor et rector, fámulum tuum N., quem pastórem {church} praeésse voluísti, propítius réspice: da ei, quáesumus, verbo et exémplo, quibus praeest, profícere; ut ad vitam, una cum grege sibi crédito, pervéniat sempitérnam."),
+            &format!("O God, the Shepherd and Ruler of all the faithful, look favourably upon Thy servant N., whom Thou hast been pleased to set as shepherd over {church_en}: grant him, we beseech Thee, by word and example so to edify those over whom he is set, that he may attain to everlasting life together with the flock committed to him."),
+        ),
+        tp(
+            None,
+            &format!("Oblátis, quáesumus, Dómine, placáre munéribus: et fámulum tuum N., quem pastórem {church} praeésse voluísti, assídua protectióne gubérna."),
+            &format!("Be appeased, O Lord, we beseech Thee, by the gifts we have offered: and govern with continual protection Thy servant N., whom Thou hast been pleased to set as shepherd over {church_en}."),
+        ),
+        tp(
+            None,
+            &format!("Haec nos, quáesumus, Dómine, divíni sacraménti percéptio prótegat: et fámulum tuum N., quem pastórem {church} praeésse voluísti, una cum commísso sibi grege salvet semper et múniat."),
+            &format!("May the reception of this divine sacrament protect us, O Lord, we beseech Thee: and may it ever save and defend Thy servant N., whom Thou hast been pleased to set as shepherd over {church_en}, together with the flock committed to him."),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Calendar;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_propers_complete_for_easter() {
+        let p = get_propers("easter-sunday").unwrap();
+        assert!(p.introit.unwrap().latin.starts_with("Resurréxi"));
+        assert!(p.sequence.unwrap().latin.starts_with("Víctimae pascháli"));
+        assert_eq!(p.last_gospel.unwrap().reference.as_deref(), Some("John 1:1-14"));
+        assert!(get_propers("no-such-feast").is_none());
+    }
+
+    #[test]
+    fn test_mass_texts_for_calendar_day() {
+        let cal = Calendar::new(2026);
+        let day = cal.get(NaiveDate::from_ymd_opt(2026, 1, 28).unwrap()).unwrap();
+        let m = mass_texts(day).unwrap();
+        assert_eq!(m.celebration_id, "st-thomas-aquinas");
+        assert!(m.propers.introit.unwrap().latin.starts_with("In medio Ecclesiae"));
+    }
+
+    #[test]
+    fn test_proper_collect_overrides_common() {
+        let p = get_propers("st-augustine").unwrap();
+        assert_eq!(common_of("st-augustine"), Some(Common::Doctors));
+        assert!(p.collect.unwrap().latin.contains("Augustíno"));
+        // The rest comes from the Common, with the saint's name said
+        assert!(p.introit.unwrap().latin.starts_with("In médio Ecclésiae"));
+        assert!(p.secret.unwrap().latin.starts_with("Sancti Augustíni Confessóris"));
+        assert!(p.postcommunion.unwrap().latin.contains("beátus Augustínus Conféssor"));
+    }
+
+    #[test]
+    fn test_martyrs_and_holy_women() {
+        let p = get_propers("ss-fabian-sebastian").unwrap();
+        assert_eq!(common_of("ss-fabian-sebastian"), Some(Common::Martyrs));
+        assert!(p.introit.unwrap().latin.starts_with("Intret"));
+        let post = p.postcommunion.unwrap();
+        assert!(post.latin.contains("Martýribus tuis Fabiáno et Sebastiáno;"));
+        assert!(post.vernacular.unwrap().contains("Martyrs Fabian and Sebastian,"));
+        let p = get_propers("st-monica").unwrap();
+        assert_eq!(common_of("st-monica"), Some(Common::HolyWoman));
+        assert!(p.collect.unwrap().latin.contains("beátae Mónicae"));
+        assert!(p.gradual.unwrap().latin.starts_with("Diffúsa est grátia"));
+    }
+
+    #[test]
+    fn test_commemorations_follow_in_order() {
+        let cal = Calendar::new(2026);
+        let mut day = cal.get(NaiveDate::from_ymd_opt(2026, 4, 5).unwrap()).unwrap().clone();
+        assert_eq!(day.celebration.id, "easter-sunday");
+        day.commemorations.push(Celebration::new(
+            "st-thomas-aquinas", "S. Thomae de Aquino", "St. Thomas Aquinas",
+            CelebrationRank::ClassIII, CelebrationCategory::Feast,
+            LiturgicalColor::White,
+        ));
+        let added = |kind, title: &str| AddedOration {
+            kind,
+            title: title.into(),
+            title_vernacular: None,
+            collect: None,
+            secret: None,
+            postcommunion: None,
+        };
+        day.orations.push(added(OrationKind::PopeAnniversary, "Pro Papa"));
+        day.orations.push(added(OrationKind::BishopAnniversary, "Pro Episcopo"));
+        day.orations.push(AddedOration {
+            collect: Some(tp(None, "Da nobis, quáesumus, Dómine, plúviam salutárem.", "Grant us, O Lord, seasonable rain.")),
+            ..added(OrationKind::Imperata, "Ad petendam pluviam")
+        });
+        let m = mass_texts(&day).unwrap();
+        let ids: Vec<&str> = m.commemorations.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["st-thomas-aquinas", "pro-papa", "pro-episcopo", "imperata"]);
+        assert!(m.commemorations[0].secret.as_ref().unwrap().latin.starts_with("Sancti Thomae"));
+        assert!(m.commemorations[1].postcommunion.as_ref().unwrap().latin.starts_with("Haec nos"));
+        // The bishop's orations are complete and name his Church
+        let bishop = &m.commemorations[2];
+        assert!(bishop.collect.as_ref().unwrap().latin.contains("Ecclesiae N."));
+        assert!(bishop.secret.is_some() && bishop.postcommunion.is_some());
+        // An imperata says the texts the registry gives
+        assert!(m.commemorations[3].collect.as_ref().unwrap().latin.starts_with("Da nobis"));
+        assert!(m.commemorations[3].secret.is_none());
+    }
+}
@@ -1,230 +1,439 @@
-use crate::types::Readings;
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::types::*;
 
 /// Get the scripture readings for a celebration by its ID.
 /// These are references from the 1962 Missale Romanum.
 pub fn get_readings(celebration_id: &str) -> Option<Readings> {
     let r = match celebration_id {
         // Christmas & Epiphany cycle
-        "christmas" => Readings {
-            epistle: Some("Titus 2:11-15".into()),
-            gospel: Some("Luke 2:1-14".into()),
-            old_testament: None,
-            gradual: Some("Ps 97:3-4, 2".into()),
-        },
-        "circumcision" => Readings {
-            epistle: Some("Titus 2:11-15".into()),
-            gospel: Some("Luke 2:21".into()),
-            old_testament: None,
-            gradual: Some("Ps 97:3-4".into()),
-        },
-        "epiphany" => Readings {
-            epistle: Some("Isaias 60:1-6".into()),
-            gospel: Some("Matt 2:1-12".into()),
-            old_testament: None,
-            gradual: Some("Ps 71:10-11".into()),
-        },
-        "holy-family" => Readings {
-            epistle: Some("Col 3:12-17".into()),
-            gospel: Some("Luke 2:42-52".into()),
-            old_testament: None,
-            gradual: Some("Ps 26:4".into()),
-        },
-        "holy-name-of-jesus" => Readings {
-            epistle: Some("Acts 4:8-12".into()),
-            gospel: Some("Luke 2:21".into()),
-            old_testament: None,
-            gradual: Some("Ps 105:47".into()),
-        },
+        "christmas" => Readings::new()
+            .epistle("Titus 2:11-15")
+            .gradual("Ps 97:3-4, 2")
+            .gospel("Luke 2:1-14"),
+        "circumcision" => Readings::new()
+            .epistle("Titus 2:11-15")
+            .gradual("Ps 97:3-4")
+            .gospel("Luke 2:21"),
+        "epiphany" => Readings::new()
+            .lesson("Isaias 60:1-6")
+            .gradual("Ps 71:10-11")
+            .gospel("Matt 2:1-12"),
+        "holy-family" => Readings::new()
+            .epistle("Col 3:12-17")
+            .gradual("Ps 26:4")
+            .gospel("Luke 2:42-52"),
+        "holy-name-of-jesus" => Readings::new()
+            .lesson("Acts 4:8-12")
+            .gradual("Ps 105:47")
+            .gospel("Luke 2:21"),
 
         // Lent & Passiontide
-        "ash-wednesday" => Readings {
-            epistle: Some("Joel 2:12-19".into()),
-            gospel: Some("Matt 6:16-21".into()),
-            old_testament: None,
-            gradual: Some("Ps 56:2".into()),
-        },
-        "palm-sunday" => Readings {
-            epistle: Some("Phil 2:5-11".into()),
-            gospel: Some("Matt 26:36–27:60 (Passion)".into()),
-            old_testament: None,
-            gradual: Some("Ps 21:2-3, 22".into()),
-        },
+        "ash-wednesday" => Readings::new()
+            .lesson("Joel 2:12-19")
+            .gradual("Ps 56:2")
+            .gospel("Matt 6:16-21"),
+        "palm-sunday" => Readings::new()
+            .epistle("Phil 2:5-11")
+            .gradual("Ps 21:2-3, 22")
+            .gospel("Matt 26:36–27:60 (Passion)"),
 
         // Sacred Triduum
-        "holy-thursday" => Readings {
-            epistle: Some("1 Cor 11:20-32".into()),
-            gospel: Some("John 13:1-15".into()),
-            old_testament: None,
-            gradual: Some("Phil 2:8-9".into()),
-        },
-        "good-friday" => Readings {
-            epistle: Some("Osee 6:1-6; Exod 12:1-11".into()),
-            gospel: Some("John 18:1–19:42 (Passion)".into()),
-            old_testament: Some("Isaias 52:13–53:12".into()),
-            gradual: Some("Hab 3:2-3".into()),
-        },
-        "holy-saturday" => Readings {
-            epistle: Some("Col 3:1-4".into()),
-            gospel: Some("Matt 28:1-7".into()),
-            old_testament: Some("Gen 1:1–2:2 (and Prophecies)".into()),
-            gradual: None,
-        },
+        "holy-thursday" => Readings::new()
+            .epistle("1 Cor 11:20-32")
+            .gradual("Phil 2:8-9")
+            .gospel("John 13:1-15"),
+        "good-friday" => Readings::new()
+            .lesson("Osee 6:1-6")
+            .tract("Hab 3:2-3")
+            .lesson("Exod 12:1-11")
+            .tract("Ps 139:2-10, 14")
+            .gospel("John 18:1–19:42 (Passion)"),
+        // Restored Vigil; the twelve prophecies of the older rite are in `prophecies`
+        "holy-saturday" => Readings::new()
+            .lesson("Gen 1:1–2:2")
+            .lesson("Exod 14:24–15:1")
+            .tract("Exod 15:1-2")
+            .lesson("Isaias 4:1-6")
+            .tract("Isaias 5:1-2")
+            .lesson("Deut 31:22-30")
+            .tract("Deut 32:1-4")
+            .epistle("Col 3:1-4")
+            .alleluia("Ps 117:1")
+            .tract("Ps 116:1-2")
+            .gospel("Matt 28:1-7"),
 
         // Easter cycle
-        "easter-sunday" => Readings {
-            epistle: Some("1 Cor 5:7-8".into()),
-            gospel: Some("Mark 16:1-7".into()),
-            old_testament: None,
-            gradual: Some("Ps 117:24, 1 (Sequence: Victimae Paschali Laudes)".into()),
-        },
-        "low-sunday" => Readings {
-            epistle: Some("1 John 5:4-10".into()),
-            gospel: Some("John 20:19-31".into()),
-            old_testament: None,
-            gradual: Some("Matt 28:7; John 20:26".into()),
-        },
-        "ascension" => Readings {
-            epistle: Some("Acts 1:1-11".into()),
-            gospel: Some("Mark 16:14-20".into()),
-            old_testament: None,
-            gradual: Some("Ps 46:6; Ps 67:18-19".into()),
-        },
-        "pentecost" => Readings {
-            epistle: Some("Acts 2:1-11".into()),
-            gospel: Some("John 14:23-31".into()),
-            old_testament: None,
-            gradual: Some("Ps 103:30 (Sequence: Veni Sancte Spiritus)".into()),
-        },
+        "easter-sunday" => Readings::new()
+            .epistle("1 Cor 5:7-8")
+            .gradual("Ps 117:24, 1")
+            .alleluia("1 Cor 5:7")
+            .sequence("Victimae paschali laudes")
+            .gospel("Mark 16:1-7"),
+        "low-sunday" => Readings::new()
+            .epistle("1 John 5:4-10")
+            .alleluia("Matt 28:7; John 20:26")
+            .gospel("John 20:19-31"),
+        "ascension" => Readings::new()
+            .lesson("Acts 1:1-11")
+            .alleluia("Ps 46:6; Ps 67:18-19")
+            .gospel("Mark 16:14-20"),
+        "pentecost" => Readings::new()
+            .lesson("Acts 2:1-11")
+            .alleluia("Ps 103:30")
+            .sequence("Veni, Sancte Spiritus")
+            .gospel("John 14:23-31"),
 
         // Corpus Christi & Sacred Heart
-        "corpus-christi" => Readings {
-            epistle: Some("1 Cor 11:23-29".into()),
-            gospel: Some("John 6:56-59".into()),
-            old_testament: None,
-            gradual: Some("Ps 144:15-16 (Sequence: Lauda Sion)".into()),
-        },
-        "sacred-heart" => Readings {
-            epistle: Some("Eph 3:8-19".into()),
-            gospel: Some("John 19:31-37".into()),
-            old_testament: None,
-            gradual: Some("Ps 24:8-9".into()),
-        },
+        "corpus-christi" => Readings::new()
+            .epistle("1 Cor 11:23-29")
+            .gradual("Ps 144:15-16")
+            .sequence("Lauda Sion")
+            .gospel("John 6:56-59"),
+        "sacred-heart" => Readings::new()
+            .epistle("Eph 3:8-19")
+            .gradual("Ps 24:8-9")
+            .gospel("John 19:31-37"),
 
         // Christ the King
-        "christ-the-king" => Readings {
-            epistle: Some("Col 1:12-20".into()),
-            gospel: Some("John 18:33-37".into()),
-            old_testament: None,
-            gradual: Some("Ps 71:8, 11".into()),
-        },
+        "christ-the-king" => Readings::new()
+            .epistle("Col 1:12-20")
+            .gradual("Ps 71:8, 11")
+            .gospel("John 18:33-37"),
 
         // Major sanctoral feasts
-        "purification-bvm" => Readings {
-            epistle: Some("Mal 3:1-4".into()),
-            gospel: Some("Luke 2:22-32".into()),
-            old_testament: None,
-            gradual: Some("Ps 47:10-11, 9".into()),
-        },
-        "st-joseph" => Readings {
-            epistle: Some("Ecclus 45:1-6".into()),
-            gospel: Some("Matt 1:18-21".into()),
-            old_testament: None,
-            gradual: Some("Ps 20:4-5".into()),
-        },
-        "annunciation" => Readings {
-            epistle: Some("Isaias 7:10-15".into()),
-            gospel: Some("Luke 1:26-38".into()),
-            old_testament: None,
-            gradual: Some("Ps 44:3, 5".into()),
-        },
-        "nativity-of-st-john-baptist" => Readings {
-            epistle: Some("Isaias 49:1-3, 5-7".into()),
-            gospel: Some("Luke 1:57-68".into()),
-            old_testament: None,
-            gradual: Some("Jer 1:5, 9".into()),
-        },
-        "ss-peter-paul" => Readings {
-            epistle: Some("Acts 12:1-11".into()),
-            gospel: Some("Matt 16:13-19".into()),
-            old_testament: None,
-            gradual: Some("Ps 44:17-18".into()),
-        },
-        "transfiguration" => Readings {
-            epistle: Some("2 Peter 1:16-19".into()),
-            gospel: Some("Matt 17:1-9".into()),
-            old_testament: None,
-            gradual: Some("Ps 44:3".into()),
-        },
-        "assumption-bvm" => Readings {
-            epistle: Some("Judith 13:22-25; 15:10".into()),
-            gospel: Some("Luke 1:41-50".into()),
-            old_testament: None,
-            gradual: Some("Ps 44:10, 12, 16".into()),
-        },
-        "exaltation-holy-cross" => Readings {
-            epistle: Some("Phil 2:5-11".into()),
-            gospel: Some("John 12:31-36".into()),
-            old_testament: None,
-            gradual: Some("Phil 2:8-9".into()),
-        },
-        "st-michael" => Readings {
-            epistle: Some("Apoc 1:1-5".into()),
-            gospel: Some("Matt 18:1-10".into()),
-            old_testament: None,
-            gradual: Some("Ps 102:20".into()),
-        },
-        "all-saints" => Readings {
-            epistle: Some("Apoc 7:2-12".into()),
-            gospel: Some("Matt 5:1-12".into()),
-            old_testament: None,
-            gradual: Some("Ps 33:10-11".into()),
-        },
-        "all-souls" => Readings {
-            epistle: Some("1 Cor 15:51-57".into()),
-            gospel: Some("John 5:25-29".into()),
-            old_testament: None,
-            gradual: None,
-        },
-        "immaculate-conception" => Readings {
-            epistle: Some("Prov 8:22-35".into()),
-            gospel: Some("Luke 1:26-28".into()),
-            old_testament: None,
-            gradual: Some("Judith 15:10; 13:23".into()),
-        },
+        "purification-bvm" => Readings::new()
+            .lesson("Mal 3:1-4")
+            .gradual("Ps 47:10-11, 9")
+            .gospel("Luke 2:22-32"),
+        "st-joseph" => Readings::new()
+            .lesson("Ecclus 45:1-6")
+            .gradual("Ps 20:4-5")
+            .gospel("Matt 1:18-21"),
+        "annunciation" => Readings::new()
+            .lesson("Isaias 7:10-15")
+            .gradual("Ps 44:3, 5")
+            .gospel("Luke 1:26-38"),
+        "nativity-of-st-john-baptist" => Readings::new()
+            .lesson("Isaias 49:1-3, 5-7")
+            .gradual("Jer 1:5, 9")
+            .gospel("Luke 1:57-68"),
+        "ss-peter-paul" => Readings::new()
+            .lesson("Acts 12:1-11")
+            .gradual("Ps 44:17-18")
+            .gospel("Matt 16:13-19"),
+        "transfiguration" => Readings::new()
+            .epistle("2 Pet 1:16-19")
+            .gradual("Ps 44:3")
+            .gospel("Matt 17:1-9"),
+        "assumption-bvm" => Readings::new()
+            .lesson("Judith 13:22-25; 15:10")
+            .gradual("Ps 44:10, 12, 16")
+            .gospel("Luke 1:41-50"),
+        "exaltation-holy-cross" => Readings::new()
+            .epistle("Phil 2:5-11")
+            .gradual("Phil 2:8-9")
+            .gospel("John 12:31-36"),
+        "st-michael" => Readings::new()
+            .epistle("Apoc 1:1-5")
+            .gradual("Ps 102:20")
+            .gospel("Matt 18:1-10"),
+        "all-saints" => Readings::new()
+            .epistle("Apoc 7:2-12")
+            .gradual("Ps 33:10-11")
+            .gospel("Matt 5:1-12"),
+        "all-souls" => Readings::new()
+            .epistle("1 Cor 15:51-57")
+            .gospel("John 5:25-29"),
+        "immaculate-conception" => Readings::new()
+            .lesson("Prov 8:22-35")
+            .gradual("Judith 15:10; 13:23")
+            .gospel("Luke 1:26-28"),
+
+        // Sancta Maria in Sabbato, by season
+        "saturday-of-our-lady-rorate" => Readings::new()
+            .lesson("Isaias 7:10-15")
+            .gradual("Ps 23:7, 3-4")
+            .gospel("Luke 1:26-38"),
+        "saturday-of-our-lady-vultum-tuum" => Readings::new()
+            .lesson("Ecclus 24:14-16")
+            .gradual("Ps 44:3, 2")
+            .gospel("Luke 2:15-21"),
+        "saturday-of-our-lady-salve-sancta-parens" => Readings::new()
+            .lesson("Ecclus 24:14-16")
+            .gospel("Luke 11:27-28"),
+        "saturday-of-our-lady-salve-sancta-parens-paschal" => Readings::new()
+            .lesson("Ecclus 24:14-16")
+            .gospel("John 19:25-27"),
 
         // Dec 25 octave
-        "st-stephen" => Readings {
-            epistle: Some("Acts 6:8-10; 7:54-59".into()),
-            gospel: Some("Matt 23:34-39".into()),
-            old_testament: None,
-            gradual: Some("Ps 118:23, 86, 23".into()),
-        },
-        "st-john-evangelist" => Readings {
-            epistle: Some("Ecclus 15:1-6".into()),
-            gospel: Some("John 21:19-24".into()),
-            old_testament: None,
-            gradual: Some("Ps 91:13-14".into()),
-        },
-        "holy-innocents" => Readings {
-            epistle: Some("Apoc 14:1-5".into()),
-            gospel: Some("Matt 2:13-18".into()),
-            old_testament: None,
-            gradual: Some("Ps 123:7-8".into()),
-        },
+        "st-stephen" => Readings::new()
+            .lesson("Acts 6:8-10; 7:54-59")
+            .gradual("Ps 118:23, 86, 23")
+            .gospel("Matt 23:34-39"),
+        "st-john-evangelist" => Readings::new()
+            .lesson("Ecclus 15:1-6")
+            .gradual("Ps 91:13-14")
+            .gospel("John 21:19-24"),
+        "holy-innocents" => Readings::new()
+            .epistle("Apoc 14:1-5")
+            .gradual("Ps 123:7-8")
+            .gospel("Matt 2:13-18"),
+
+        // Sunday within the Octave of Christmas
+        "sunday-christmas-1" => Readings::new().epistle("Gal 4:1-7").gospel("Luke 2:33-40"),
+
+        // Sundays of Advent
+        "sunday-advent-1" => Readings::new().epistle("Rom 13:11-14").gospel("Luke 21:25-33"),
+        "sunday-advent-2" => Readings::new().epistle("Rom 15:4-13").gospel("Matt 11:2-10"),
+        "sunday-advent-3" => Readings::new().epistle("Phil 4:4-7").gospel("John 1:19-28"),
+        "sunday-advent-4" => Readings::new().epistle("1 Cor 4:1-5").gospel("Luke 3:1-6"),
+
+        // Sundays after Epiphany (also resumed after the 23rd Sunday after Pentecost)
+        "sunday-after-epiphany-1" => Readings::new().epistle("Rom 12:1-5").gospel("Luke 2:42-52"),
+        "sunday-after-epiphany-2" => Readings::new().epistle("Rom 12:6-16").gospel("John 2:1-11"),
+        "sunday-after-epiphany-3" => Readings::new().epistle("Rom 12:16-21").gospel("Matt 8:1-13"),
+        "sunday-after-epiphany-4" => Readings::new().epistle("Rom 13:8-10").gospel("Matt 8:23-27"),
+        "sunday-after-epiphany-5" => Readings::new().epistle("Col 3:12-17").gospel("Matt 13:24-30"),
+        "sunday-after-epiphany-6" => Readings::new().epistle("1 Thess 1:2-10").gospel("Matt 13:31-35"),
+
+        // Septuagesima, Sexagesima, Quinquagesima
+        "sunday-septuagesima-1" => Readings::new().epistle("1 Cor 9:24-27; 10:1-5").gospel("Matt 20:1-16"),
+        "sunday-septuagesima-2" => Readings::new().epistle("2 Cor 11:19-33; 12:1-9").gospel("Luke 8:4-15"),
+        "sunday-septuagesima-3" => Readings::new().epistle("1 Cor 13:1-13").gospel("Luke 18:31-43"),
+
+        // Lent: every feria has its own Mass (Ember days below, by season)
+        "feria-lent-week-0-thu" => Readings::new().lesson("Isaias 38:1-6").gospel("Matt 8:5-13"),
+        "feria-lent-week-0-fri" => Readings::new().lesson("Isaias 58:1-9").gospel("Matt 5:43-48; 6:1-4"),
+        "feria-lent-week-0-sat" => Readings::new().lesson("Isaias 58:9-14").gospel("Mark 6:47-56"),
+        "sunday-lent-1" => Readings::new().epistle("2 Cor 6:1-10").gospel("Matt 4:1-11"),
+        "feria-lent-week-1-mon" => Readings::new().lesson("Ezech 34:11-16").gospel("Matt 25:31-46"),
+        "feria-lent-week-1-tue" => Readings::new().lesson("Isaias 55:6-11").gospel("Matt 21:10-17"),
+        "feria-lent-week-1-thu" => Readings::new().lesson("Ezech 18:1-9").gospel("Matt 15:21-28"),
+        "sunday-lent-2" => Readings::new().epistle("1 Thess 4:1-7").gospel("Matt 17:1-9"),
+        "feria-lent-week-2-mon" => Readings::new().lesson("Dan 9:15-19").gospel("John 8:21-29"),
+        "feria-lent-week-2-tue" => Readings::new().lesson("3 Kings 17:8-16").gospel("Matt 23:1-12"),
+        "feria-lent-week-2-wed" => Readings::new().lesson("Esther 13:8-11, 15-17").gospel("Matt 20:17-28"),
+        "feria-lent-week-2-thu" => Readings::new().lesson("Jer 17:5-10").gospel("Luke 16:19-31"),
+        "feria-lent-week-2-fri" => Readings::new().lesson("Gen 37:6-22").gospel("Matt 21:33-46"),
+        "feria-lent-week-2-sat" => Readings::new().lesson("Gen 27:6-40").gospel("Luke 15:11-32"),
+        "sunday-lent-3" => Readings::new().epistle("Eph 5:1-9").gospel("Luke 11:14-28"),
+        "feria-lent-week-3-mon" => Readings::new().lesson("4 Kings 5:1-15").gospel("Luke 4:23-30"),
+        "feria-lent-week-3-tue" => Readings::new().lesson("4 Kings 4:1-7").gospel("Matt 18:15-22"),
+        "feria-lent-week-3-wed" => Readings::new().lesson("Exod 20:12-24").gospel("Matt 15:1-20"),
+        "feria-lent-week-3-thu" => Readings::new().lesson("Jer 7:1-7").gospel("Luke 4:38-44"),
+        "feria-lent-week-3-fri" => Readings::new().lesson("Num 20:1-3, 6-13").gospel("John 4:5-42"),
+        "feria-lent-week-3-sat" => Readings::new().lesson("Dan 13:1-62").gospel("John 8:1-11"),
+        "sunday-lent-4" => Readings::new().epistle("Gal 4:22-31").gospel("John 6:1-15"),
+        "feria-lent-week-4-mon" => Readings::new().lesson("3 Kings 3:16-28").gospel("John 2:13-25"),
+        "feria-lent-week-4-tue" => Readings::new().lesson("Exod 32:7-14").gospel("John 7:14-31"),
+        "feria-lent-week-4-wed" => Readings::new()
+            .lesson("Ezech 36:23-28")
+            .lesson("Isaias 1:16-19")
+            .gospel("John 9:1-38"),
+        "feria-lent-week-4-thu" => Readings::new().lesson("4 Kings 4:25-38").gospel("John 5:17-29"),
+        "feria-lent-week-4-fri" => Readings::new().lesson("3 Kings 17:17-24").gospel("John 11:1-45"),
+        "feria-lent-week-4-sat" => Readings::new().lesson("Isaias 49:8-15").gospel("John 8:12-20"),
+        "sunday-passiontide-1" => Readings::new().epistle("Heb 9:11-15").gospel("John 8:46-59"),
+        "feria-passiontide-week-1-mon" => Readings::new().lesson("Jonas 3:1-10").gospel("John 7:32-39"),
+        "feria-passiontide-week-1-tue" => Readings::new().lesson("Dan 14:27-42").gospel("John 7:1-13"),
+        "feria-passiontide-week-1-wed" => Readings::new().lesson("Lev 19:1-2, 11-19, 25").gospel("John 10:22-38"),
+        "feria-passiontide-week-1-thu" => Readings::new().lesson("Dan 3:25, 34-45").gospel("Luke 7:36-50"),
+        "feria-passiontide-week-1-fri" => Readings::new().lesson("Jer 17:13-18").gospel("John 11:47-54"),
+        "feria-passiontide-week-1-sat" => Readings::new().lesson("Jer 18:18-23").gospel("John 12:10-36"),
+        "feria-holy-week-week-1-mon" => Readings::new().lesson("Isaias 50:5-10").gospel("John 12:1-9"),
+        "feria-holy-week-week-1-tue" => Readings::new().lesson("Jer 11:18-20").gospel("Mark 14:32–15:46 (Passion)"),
+        "feria-holy-week-week-1-wed" => Readings::new()
+            .lesson("Isaias 62:11; 63:1-7")
+            .lesson("Isaias 53:1-12")
+            .gospel("Luke 22:39–23:53 (Passion)"),
+
+        // Easter Octave
+        "easter-octave-1" => Readings::new().lesson("Acts 10:37-43").gospel("Luke 24:13-35"),
+        "easter-octave-2" => Readings::new().lesson("Acts 13:16, 26-33").gospel("Luke 24:36-47"),
+        "easter-octave-3" => Readings::new().lesson("Acts 3:13-15, 17-19").gospel("John 21:1-14"),
+        "easter-octave-4" => Readings::new().lesson("Acts 8:26-40").gospel("John 20:11-18"),
+        "easter-octave-5" => Readings::new().epistle("1 Pet 3:18-22").gospel("Matt 28:16-20"),
+        "easter-octave-6" => Readings::new().epistle("1 Pet 2:1-10").gospel("John 20:1-9"),
+
+        // Sundays after Easter (the 2nd Sunday after Easter is the 3rd of the season)
+        "sunday-easter-3" => Readings::new().epistle("1 Pet 2:21-25").gospel("John 10:11-16"),
+        "sunday-easter-4" => Readings::new().epistle("1 Pet 2:11-19").gospel("John 16:16-22"),
+        "sunday-easter-5" => Readings::new().epistle("James 1:17-21").gospel("John 16:5-14"),
+        "sunday-easter-6" => Readings::new().epistle("James 1:22-27").gospel("John 16:23-30"),
+        "sunday-ascensiontide-1" => Readings::new().epistle("1 Pet 4:7-11").gospel("John 15:26-27; 16:1-4"),
+
+        // Pentecost Octave, including the Ember days of Whitsun week
+        "pentecost-octave-1" => Readings::new().lesson("Acts 10:42-48").gospel("John 3:16-21"),
+        "pentecost-octave-2" => Readings::new().lesson("Acts 8:14-17").gospel("John 10:1-10"),
+        "pentecost-octave-3" => Readings::new()
+            .lesson("Acts 2:14-21")
+            .lesson("Acts 5:12-16")
+            .gospel("John 6:44-52"),
+        "pentecost-octave-4" => Readings::new().lesson("Acts 8:5-8").gospel("Luke 9:1-6"),
+        "pentecost-octave-5" => Readings::new().lesson("Joel 2:23-24, 26-27").gospel("Luke 5:17-26"),
+        "pentecost-octave-6" => Readings::new()
+            .lesson("Joel 2:28-32")
+            .lesson("Lev 23:9-11, 15-17, 21")
+            .lesson("Deut 26:1-3, 7-11")
+            .lesson("Lev 26:3-12")
+            .lesson("Dan 3:47-51")
+            .epistle("Rom 5:1-5")
+            .gospel("Luke 4:38-44"),
+        "trinity-sunday" => Readings::new().epistle("Rom 11:33-36").gospel("Matt 28:18-20"),
+
+        // Sundays after Pentecost, keyed as generated: the week of the Octave
+        // counts as the 1st, so the Missal's 1st Sunday, whose Mass the
+        // ferias after Trinity Sunday take, is `sunday-after-pentecost-2`
+        "sunday-after-pentecost-2" => Readings::new().epistle("1 John 4:8-21").gospel("Luke 6:36-42"),
+        "sunday-after-pentecost-3" => Readings::new().epistle("1 John 3:13-18").gospel("Luke 14:16-24"),
+        "sunday-after-pentecost-4" => Readings::new().epistle("1 Pet 5:6-11").gospel("Luke 15:1-10"),
+        "sunday-after-pentecost-5" => Readings::new().epistle("Rom 8:18-23").gospel("Luke 5:1-11"),
+        "sunday-after-pentecost-6" => Readings::new().epistle("1 Pet 3:8-15").gospel("Matt 5:20-24"),
+        "sunday-after-pentecost-7" => Readings::new().epistle("Rom 6:3-11").gospel("Mark 8:1-9"),
+        "sunday-after-pentecost-8" => Readings::new().epistle("Rom 6:19-23").gospel("Matt 7:15-21"),
+        "sunday-after-pentecost-9" => Readings::new().epistle("Rom 8:12-17").gospel("Luke 16:1-9"),
+        "sunday-after-pentecost-10" => Readings::new().epistle("1 Cor 10:6-13").gospel("Luke 19:41-47"),
+        "sunday-after-pentecost-11" => Readings::new().epistle("1 Cor 12:2-11").gospel("Luke 18:9-14"),
+        "sunday-after-pentecost-12" => Readings::new().epistle("1 Cor 15:1-10").gospel("Mark 7:31-37"),
+        "sunday-after-pentecost-13" => Readings::new().epistle("2 Cor 3:4-9").gospel("Luke 10:23-37"),
+        "sunday-after-pentecost-14" => Readings::new().epistle("Gal 3:16-22").gospel("Luke 17:11-19"),
+        "sunday-after-pentecost-15" => Readings::new().epistle("Gal 5:16-24").gospel("Matt 6:24-33"),
+        "sunday-after-pentecost-16" => Readings::new().epistle("Gal 5:25-26; 6:1-10").gospel("Luke 7:11-16"),
+        "sunday-after-pentecost-17" => Readings::new().epistle("Eph 3:13-21").gospel("Luke 14:1-11"),
+        "sunday-after-pentecost-18" => Readings::new().epistle("Eph 4:1-6").gospel("Matt 22:34-46"),
+        "sunday-after-pentecost-19" => Readings::new().epistle("1 Cor 1:4-8").gospel("Matt 9:1-8"),
+        "sunday-after-pentecost-20" => Readings::new().epistle("Eph 4:23-28").gospel("Matt 22:1-14"),
+        "sunday-after-pentecost-21" => Readings::new().epistle("Eph 5:15-21").gospel("John 4:46-53"),
+        "sunday-after-pentecost-22" => Readings::new().epistle("Eph 6:10-17").gospel("Matt 18:23-35"),
+        "sunday-after-pentecost-23" => Readings::new().epistle("Phil 1:6-11").gospel("Matt 22:15-21"),
+        "sunday-after-pentecost-24" => Readings::new().epistle("Phil 3:17-21; 4:1-3").gospel("Matt 9:18-26"),
+        "last-sunday-after-pentecost" => Readings::new().epistle("Col 1:9-14").gospel("Matt 24:15-35"),
 
         _ => return None,
     };
     Some(r)
 }
 
-/// Get special notes for a celebration.
+/// Readings for a celebration on a given date. Besides the fixed table
+/// above, the Ember and Rogation days take the Mass of their season, the
+/// Sundays after Pentecost beyond the 23rd (from `sunday-after-pentecost-25`)
+/// take the Sundays after Epiphany left over that year (the last is always
+/// the 24th), and Holy Saturday before 1955 has the twelve prophecies.
+pub fn readings_for(
+    celebration: &Celebration,
+    date: NaiveDate,
+    season: LiturgicalSeason,
+    mf: &MoveableFeasts,
+    system: RubricalSystem,
+) -> Option<Readings> {
+    if celebration.id == "holy-saturday" && system == RubricalSystem::PrePius {
+        return Some(prophecies());
+    }
+    if let Some(n) = celebration.id.strip_prefix("sunday-after-pentecost-") {
+        let n: i64 = n.parse().ok()?;
+        // Weeks after Pentecost this year, numbered as the ids are
+        let total = (mf.advent_1 - mf.pentecost).num_days() / 7;
+        if n > 24 && n < total {
+            return get_readings(&format!("sunday-after-epiphany-{}", 6 - (total - 1 - n)));
+        }
+    }
+    if let Some(r) = get_readings(&celebration.id) {
+        return Some(r);
+    }
+    match celebration.category {
+        CelebrationCategory::EmberDay => ember_readings(season, date.weekday()),
+        CelebrationCategory::RogationDay => {
+            Some(Readings::new().epistle("James 5:16-20").gospel("Luke 11:5-13"))
+        }
+        _ => None,
+    }
+}
+
+/// The Vigil of Easter before the restored Ordo of 1955: twelve prophecies,
+/// three of them followed by a tract, then the Mass.
+fn prophecies() -> Readings {
+    Readings::new()
+        .lesson("Gen 1:1–2:2")
+        .lesson("Gen 5:31–8:21")
+        .lesson("Gen 22:1-19")
+        .lesson("Exod 14:24–15:1")
+        .tract("Exod 15:1-2")
+        .lesson("Isaias 54:17–55:11")
+        .lesson("Baruch 3:9-38")
+        .lesson("Ezech 37:1-14")
+        .lesson("Isaias 4:1-6")
+        .tract("Isaias 5:1-2")
+        .lesson("Exod 12:1-11")
+        .lesson("Jonas 3:1-10")
+        .lesson("Deut 31:22-30")
+        .tract("Deut 32:1-4")
+        .lesson("Dan 3:1-24")
+        .epistle("Col 3:1-4")
+        .alleluia("Ps 117:1")
+        .tract("Ps 116:1-2")
+        .gospel("Matt 28:1-7")
+}
+
+/// Ember Wednesday, Friday and Saturday of Lent, September and Advent
+/// (those of Whitsun week are days of the Pentecost Octave).
+fn ember_readings(season: LiturgicalSeason, weekday: Weekday) -> Option<Readings> {
+    let r = match (season, weekday) {
+        (LiturgicalSeason::Lent, Weekday::Wed) => Readings::new()
+            .lesson("Exod 24:12-18")
+            .lesson("3 Kings 19:3-8")
+            .gospel("Matt 12:38-50"),
+        (LiturgicalSeason::Lent, Weekday::Fri) => Readings::new()
+            .lesson("Ezech 18:20-28")
+            .gospel("John 5:1-15"),
+        (LiturgicalSeason::Lent, Weekday::Sat) => Readings::new()
+            .lesson("Deut 26:12-19")
+            .lesson("Deut 11:22-25")
+            .lesson("2 Mach 1:23-27")
+            .lesson("Ecclus 36:1-10")
+            .lesson("Dan 3:47-51")
+            .epistle("1 Thess 5:14-23")
+            .gospel("Matt 17:1-9"),
+        (LiturgicalSeason::AfterPentecost, Weekday::Wed) => Readings::new()
+            .lesson("Amos 9:13-15")
+            .lesson("2 Esdras 8:1-10")
+            .gospel("Mark 9:16-28"),
+        (LiturgicalSeason::AfterPentecost, Weekday::Fri) => Readings::new()
+            .lesson("Osee 14:2-10")
+            .gospel("Luke 7:36-50"),
+        (LiturgicalSeason::AfterPentecost, Weekday::Sat) => Readings::new()
+            .lesson("Lev 23:26-32")
+            .lesson("Lev 23:39-43")
+            .lesson("Mich 7:14, 16, 18-20")
+            .lesson("Zach 8:14-19")
+            .lesson("Dan 3:47-51")
+            .epistle("Heb 9:2-12")
+            .gospel("Luke 13:6-17"),
+        (LiturgicalSeason::Advent, Weekday::Wed) => Readings::new()
+            .lesson("Isaias 2:2-5")
+            .lesson("Isaias 7:10-15")
+            .gospel("Luke 1:26-38"),
+        (LiturgicalSeason::Advent, Weekday::Fri) => Readings::new()
+            .lesson("Isaias 11:1-5")
+            .gospel("Luke 1:39-47"),
+        (LiturgicalSeason::Advent, Weekday::Sat) => Readings::new()
+            .lesson("Isaias 19:20-22")
+            .lesson("Isaias 35:1-7")
+            .lesson("Isaias 40:9-11")
+            .lesson("Isaias 45:1-8")
+            .lesson("Dan 3:47-51")
+            .epistle("2 Thess 2:1-8")
+            .gospel("Luke 3:1-6"),
+        _ => return None,
+    };
+    Some(r)
+}
+
+/// Get special notes for a celebration. Holy Week notes depend on the
+/// rubrical system and come from `holy_week::notes`.
 pub fn get_notes(celebration_id: &str) -> Option<String> {
     match celebration_id {
         "ash-wednesday" => Some("Blessing and imposition of ashes. Fast and abstinence.".into()),
-        "palm-sunday" => Some("Blessing of palms and procession before Mass.".into()),
-        "holy-thursday" => Some("Mass of the Lord's Supper. Mandatum. Stripping of the altars. Repository.".into()),
-        "good-friday" => Some("Solemn liturgical action. Veneration of the Cross. Fast and abstinence. No Mass celebrated.".into()),
-        "holy-saturday" => Some("Easter Vigil: Blessing of the new fire, Paschal candle, baptismal water. First Mass of Easter.".into()),
         "easter-sunday" => Some("Solemnity of solemnities. Sequence: Victimae Paschali Laudes.".into()),
         "pentecost" => Some("Sequence: Veni Sancte Spiritus.".into()),
         "corpus-christi" => Some("Sequence: Lauda Sion Salvatorem. Procession of the Blessed Sacrament.".into()),
@@ -242,3 +451,62 @@ pub fn get_notes(celebration_id: &str) -> Option<String> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_good_friday_lessons_and_tracts_in_order() {
+        let r = get_readings("good-friday").unwrap();
+        let kinds: Vec<ReadingKind> = r.items.iter().map(|i| i.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ReadingKind::Lesson,
+                ReadingKind::Tract,
+                ReadingKind::Lesson,
+                ReadingKind::Tract,
+                ReadingKind::Gospel,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lessons_are_old_testament_or_acts() {
+        use crate::scripture::Book;
+        let cal = crate::calendar::Calendar::new(2026);
+        for (date, day) in cal.days() {
+            let Some(r) = &day.readings else { continue };
+            for item in r.items.iter().filter(|i| i.kind == ReadingKind::Lesson) {
+                for p in &item.parsed {
+                    let old_testament = (p.book as u8) < Book::Matthew as u8;
+                    assert!(old_testament || p.book == Book::Acts, "{} on {} is an Epistle", item.reference, date);
+                }
+            }
+        }
+        let first = |id| get_readings(id).unwrap().items[0].kind;
+        assert_eq!(first("transfiguration"), ReadingKind::Epistle);
+        assert_eq!(first("all-saints"), ReadingKind::Epistle);
+    }
+
+    #[test]
+    fn test_json_keeps_single_reading_fields() {
+        let r = get_readings("pentecost-octave-3").unwrap();
+        let json = serde_json::to_value(&r).unwrap();
+        assert_eq!(json["old_testament"], "Acts 2:14-21");
+        assert_eq!(json["epistle"], "Acts 5:12-16");
+        assert_eq!(json["gospel"], "John 6:44-52");
+        assert_eq!(json["items"].as_array().unwrap().len(), 3);
+        assert_eq!(json["items"][0]["kind"], "lesson");
+        let back: Readings = serde_json::from_value(json).unwrap();
+        assert_eq!(back, r);
+    }
+
+    #[test]
+    fn test_json_without_items_is_read() {
+        let json = serde_json::json!({"epistle": "Rom 13:11-14", "gospel": "Luke 21:25-33"});
+        let r: Readings = serde_json::from_value(json).unwrap();
+        assert_eq!(r, get_readings("sunday-advent-1").unwrap());
+    }
+}
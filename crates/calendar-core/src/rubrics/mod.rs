@@ -0,0 +1,281 @@
+//! Declarative precedence, occurrence and concurrence tables for each
+//! rubrical system. The resolver consults these instead of a number
+//! carried on the celebration.
+
+use crate::types::*;
+
+mod pre1955;
+mod r1955;
+mod r1962;
+
+/// Criteria a celebration must meet to fall in a row of the table.
+/// Empty lists match anything; ids and id prefixes are alternatives.
+#[derive(Debug)]
+pub struct RowMatch {
+    pub ids: &'static [&'static str],
+    pub id_prefixes: &'static [&'static str],
+    pub ranks: &'static [CelebrationRank],
+    pub categories: &'static [CelebrationCategory],
+}
+
+impl RowMatch {
+    pub const ANY: RowMatch = RowMatch { ids: &[], id_prefixes: &[], ranks: &[], categories: &[] };
+
+    pub const fn ids(ids: &'static [&'static str]) -> Self {
+        Self { ids, ..Self::ANY }
+    }
+
+    pub const fn kind(ranks: &'static [CelebrationRank], categories: &'static [CelebrationCategory]) -> Self {
+        Self { ids: &[], id_prefixes: &[], ranks, categories }
+    }
+
+    fn matches(&self, c: &Celebration) -> bool {
+        let by_id = self.ids.is_empty() && self.id_prefixes.is_empty()
+            || self.ids.contains(&c.id.as_str())
+            || self.id_prefixes.iter().any(|p| c.id.starts_with(p));
+        by_id
+            && (self.ranks.is_empty() || self.ranks.contains(&c.rank))
+            && (self.categories.is_empty() || self.categories.contains(&c.category))
+    }
+}
+
+/// One row of a table of precedence (lower rows win)
+#[derive(Debug)]
+pub struct PrecedenceRow {
+    pub row: u8,
+    pub description: &'static str,
+    pub matches: RowMatch,
+}
+
+/// A celebration preferred in occurrence to those of a higher row, as an
+/// exception to the order of the table
+#[derive(Debug)]
+pub struct Preference {
+    pub id: &'static str,
+    pub over: RowMatch,
+    pub citation: &'static str,
+}
+
+/// What happens to the lesser of two occurring celebrations.
+/// Empty lists match any row or rank.
+#[derive(Debug)]
+pub struct OccurrenceRule {
+    pub winner_rows: &'static [u8],
+    pub loser_rows: &'static [u8],
+    pub loser_ranks: &'static [CelebrationRank],
+    pub outcome: Outcome,
+    pub reason: &'static str,
+    pub citation: &'static str,
+}
+
+/// How the rows of two concurring offices compare
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    Any,
+    FollowingHigher,
+    Equal,
+    PrecedingHigher,
+}
+
+/// Whose Vespers are said when the office of one day meets the next.
+/// Empty lists match any row.
+#[derive(Debug)]
+pub struct ConcurrenceRule {
+    pub preceding_rows: &'static [u8],
+    pub following_rows: &'static [u8],
+    pub relation: Relation,
+    pub vespers: VespersOf,
+    /// Commemorate the other office, unless it is an ordinary feria
+    pub commemorate: bool,
+    pub citation: &'static str,
+}
+
+/// The tables a rubrical system loads
+#[derive(Debug)]
+pub struct RubricTables {
+    pub system: RubricalSystem,
+    pub precedence: &'static [PrecedenceRow],
+    pub preferences: &'static [Preference],
+    pub occurrence: &'static [OccurrenceRule],
+    pub concurrence: &'static [ConcurrenceRule],
+}
+
+/// Tables for a rubrical system.
+pub fn tables(system: RubricalSystem) -> &'static RubricTables {
+    match system {
+        RubricalSystem::Rubrics1962 => &r1962::TABLES,
+        RubricalSystem::Rubrics1955 => &r1955::TABLES,
+        RubricalSystem::PrePius => &pre1955::TABLES,
+    }
+}
+
+impl RubricTables {
+    /// The row of the table of precedence a celebration falls in.
+    /// Every table ends with a catch-all row.
+    pub fn row(&self, c: &Celebration) -> &PrecedenceRow {
+        self.precedence
+            .iter()
+            .find(|r| r.matches.matches(c))
+            .unwrap_or_else(|| self.precedence.last().expect("empty precedence table"))
+    }
+
+    /// The exception by which `a` is preferred to `b` in occurrence, if any.
+    pub fn preference(&self, a: &Celebration, b: &Celebration) -> Option<&Preference> {
+        self.preferences.iter().find(|p| p.id == a.id && p.over.matches(b))
+    }
+
+    /// Order of two occurring celebrations: by their rows in the table of
+    /// precedence, then by rank, then feasts of the Lord before those of
+    /// the saints, unless an exception prefers one.
+    pub fn order(&self, a: &Celebration, b: &Celebration) -> std::cmp::Ordering {
+        if self.preference(a, b).is_some() {
+            return std::cmp::Ordering::Less;
+        }
+        if self.preference(b, a).is_some() {
+            return std::cmp::Ordering::Greater;
+        }
+        self.row(a).row.cmp(&self.row(b).row)
+            .then(a.rank.precedence_value().cmp(&b.rank.precedence_value()))
+            .then_with(|| {
+                let of_saints = |c: &Celebration| c.category != CelebrationCategory::FeastOfLord;
+                of_saints(a).cmp(&of_saints(b))
+            })
+    }
+
+    /// The occurrence rule for a celebration impeded by the winner.
+    pub fn occurrence(&self, winner: &Celebration, loser: &Celebration) -> &OccurrenceRule {
+        let w = self.row(winner).row;
+        let l = self.row(loser).row;
+        self.occurrence
+            .iter()
+            .find(|r| {
+                (r.winner_rows.is_empty() || r.winner_rows.contains(&w))
+                    && (r.loser_rows.is_empty() || r.loser_rows.contains(&l))
+                    && (r.loser_ranks.is_empty() || r.loser_ranks.contains(&loser.rank))
+            })
+            .expect("occurrence table has no catch-all rule")
+    }
+
+    /// Vespers when the office of `preceding` meets that of `following`.
+    pub fn concurrence(&self, preceding: &Celebration, following: &Celebration) -> Vespers {
+        let p = self.row(preceding).row;
+        let f = self.row(following).row;
+        let relation = match f.cmp(&p) {
+            std::cmp::Ordering::Less => Relation::FollowingHigher,
+            std::cmp::Ordering::Equal => Relation::Equal,
+            std::cmp::Ordering::Greater => Relation::PrecedingHigher,
+        };
+        let rule = self
+            .concurrence
+            .iter()
+            .find(|r| {
+                (r.preceding_rows.is_empty() || r.preceding_rows.contains(&p))
+                    && (r.following_rows.is_empty() || r.following_rows.contains(&f))
+                    && (r.relation == Relation::Any || r.relation == relation)
+            })
+            .expect("concurrence table has no catch-all rule");
+
+        let (of, other) = match rule.vespers {
+            VespersOf::Preceding => (preceding, following),
+            VespersOf::Following | VespersOf::SplitAtChapter => (following, preceding),
+        };
+        let commemoration = if rule.commemorate && other.rank != CelebrationRank::Feria {
+            Some(other.id.clone())
+        } else {
+            None
+        };
+        Vespers {
+            of: rule.vespers,
+            celebration: of.id.clone(),
+            commemoration,
+            citation: rule.citation.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feast(id: &str, rank: CelebrationRank, category: CelebrationCategory) -> Celebration {
+        Celebration::new(id, id, id, rank, category, LiturgicalColor::White)
+    }
+
+    #[test]
+    fn test_every_table_classifies_every_rank() {
+        let ranks = [
+            CelebrationRank::ClassI,
+            CelebrationRank::ClassII,
+            CelebrationRank::ClassIII,
+            CelebrationRank::ClassIV,
+            CelebrationRank::Feria,
+            CelebrationRank::FeriaPrivileged,
+        ];
+        for system in [RubricalSystem::Rubrics1962, RubricalSystem::Rubrics1955, RubricalSystem::PrePius] {
+            let t = tables(system);
+            for rank in ranks {
+                let c = feast("x", rank, CelebrationCategory::Feast);
+                let row = t.row(&c);
+                assert!(row.row >= 1, "{:?} {:?}", system, rank);
+            }
+        }
+    }
+
+    #[test]
+    fn test_1962_rows() {
+        let t = tables(RubricalSystem::Rubrics1962);
+        assert_eq!(t.row(&feast("easter-sunday", CelebrationRank::ClassI, CelebrationCategory::Solemnity)).row, 1);
+        assert_eq!(t.row(&feast("low-sunday", CelebrationRank::ClassI, CelebrationCategory::OctaveDay)).row, 2);
+        assert_eq!(t.row(&feast("ascension", CelebrationRank::ClassI, CelebrationCategory::FeastOfLord)).row, 4);
+        assert_eq!(t.row(&Celebration::sunday(LiturgicalSeason::Advent, 1)).row, 2);
+        assert_eq!(t.row(&Celebration::sunday(LiturgicalSeason::Advent, 2)).row, 2);
+        assert_eq!(t.row(&Celebration::sunday(LiturgicalSeason::Lent, 3)).row, 2);
+        assert_eq!(t.row(&Celebration::sunday(LiturgicalSeason::AfterPentecost, 3)).row, 6);
+        assert_eq!(t.row(&feast("all-saints", CelebrationRank::ClassI, CelebrationCategory::Solemnity)).row, 4);
+        assert_eq!(t.row(&feast("st-luke", CelebrationRank::ClassII, CelebrationCategory::Feast)).row, 7);
+        assert_eq!(t.row(&Celebration::feria(LiturgicalSeason::AfterPentecost, 3, chrono::Weekday::Mon)).row, 11);
+    }
+
+    #[test]
+    fn test_immaculate_conception_preferred_to_advent_sunday() {
+        let t = tables(RubricalSystem::Rubrics1962);
+        let ic = feast("immaculate-conception", CelebrationRank::ClassI, CelebrationCategory::Solemnity);
+        let advent = Celebration::sunday(LiturgicalSeason::Advent, 2);
+        assert_eq!(t.order(&ic, &advent), std::cmp::Ordering::Less);
+        assert_eq!(t.preference(&ic, &advent).unwrap().citation, "RG 15");
+        let joseph = feast("st-joseph", CelebrationRank::ClassI, CelebrationCategory::Solemnity);
+        assert_eq!(t.order(&joseph, &Celebration::sunday(LiturgicalSeason::Lent, 3)), std::cmp::Ordering::Greater);
+    }
+
+    #[test]
+    fn test_class_ii_impeded_by_class_i_differs_between_systems() {
+        let winner = feast("annunciation", CelebrationRank::ClassI, CelebrationCategory::FeastOfLord);
+        let loser = feast("st-matthias", CelebrationRank::ClassII, CelebrationCategory::Feast);
+        let o1962 = tables(RubricalSystem::Rubrics1962).occurrence(&winner, &loser);
+        let opre = tables(RubricalSystem::PrePius).occurrence(&winner, &loser);
+        assert_eq!(o1962.outcome, Outcome::Commemorated);
+        assert_eq!(opre.outcome, Outcome::Transferred);
+    }
+
+    #[test]
+    fn test_concurrence_first_vespers_of_class_i() {
+        let t = tables(RubricalSystem::Rubrics1962);
+        let before = feast("st-someone", CelebrationRank::ClassIII, CelebrationCategory::Feast);
+        let after = feast("all-saints", CelebrationRank::ClassI, CelebrationCategory::Solemnity);
+        let v = t.concurrence(&before, &after);
+        assert_eq!(v.of, VespersOf::Following);
+        assert_eq!(v.celebration, "all-saints");
+        assert_eq!(v.commemoration.as_deref(), Some("st-someone"));
+    }
+
+    #[test]
+    fn test_concurrence_equal_doubles_split_pre1955() {
+        let t = tables(RubricalSystem::PrePius);
+        let a = feast("st-a", CelebrationRank::ClassIII, CelebrationCategory::Feast);
+        let b = feast("st-b", CelebrationRank::ClassIII, CelebrationCategory::Feast);
+        assert_eq!(t.concurrence(&a, &b).of, VespersOf::SplitAtChapter);
+        // Under 1962, Class III feasts have no first Vespers
+        let t = tables(RubricalSystem::Rubrics1962);
+        assert_eq!(t.concurrence(&a, &b).of, VespersOf::Preceding);
+    }
+}
@@ -0,0 +1,186 @@
+//! Tables of the rubrics of Divino Afflatu (1911) as in force until 1955.
+//! The old ranks are read from the 1962 classes: Class I as doubles of the
+//! first class, Class II as doubles of the second class, Class III as
+//! greater and lesser doubles, Class IV as simples.
+//!
+//! This is an approximation. The sanctoral carries only the 1962 classes,
+//! so semidoubles cannot be told from doubles, feasts raised or lowered in
+//! 1955 and 1960 keep their 1962 class, and the octaves suppressed in 1955
+//! (Epiphany, Ascension, Corpus Christi, the Sacred Heart and the saints'
+//! octaves) are not kept.
+
+use super::*;
+use CelebrationCategory as Cat;
+use CelebrationRank as Rank;
+
+pub(super) const PRECEDENCE: &[PrecedenceRow] = &[
+    PrecedenceRow {
+        row: 1,
+        description: "Primary doubles of the first class: Easter, Pentecost, Christmas, Epiphany, Ascension, Corpus Christi, Triduum",
+        matches: RowMatch {
+            ids: &[
+                "christmas", "easter-sunday", "holy-thursday", "good-friday", "holy-saturday",
+                "ascension", "pentecost", "corpus-christi", "epiphany",
+            ],
+            id_prefixes: &["easter-octave-", "pentecost-octave-"],
+            ranks: &[],
+            categories: &[],
+        },
+    },
+    PrecedenceRow {
+        row: 2,
+        description: "Greater Sundays of the first class",
+        matches: RowMatch {
+            ids: &["sunday-advent-1", "low-sunday", "sunday-passiontide-1", "palm-sunday"],
+            id_prefixes: &["sunday-lent-"],
+            ranks: &[],
+            categories: &[],
+        },
+    },
+    PrecedenceRow {
+        row: 3,
+        description: "Privileged ferias: Ash Wednesday, ferias of Holy Week",
+        matches: RowMatch::kind(&[Rank::ClassI], &[Cat::Feria]),
+    },
+    PrecedenceRow {
+        row: 4,
+        description: "Doubles of the first class",
+        matches: RowMatch::kind(&[Rank::ClassI], &[Cat::Solemnity, Cat::FeastOfLord]),
+    },
+    PrecedenceRow {
+        row: 5,
+        description: "Greater Sundays of the second class",
+        matches: RowMatch {
+            ids: &[],
+            id_prefixes: &["sunday-advent-", "sunday-septuagesima-"],
+            ranks: &[],
+            categories: &[Cat::Sunday],
+        },
+    },
+    PrecedenceRow {
+        row: 6,
+        description: "Doubles of the second class",
+        matches: RowMatch::kind(&[Rank::ClassII], &[Cat::Feast, Cat::FeastOfLord, Cat::Solemnity]),
+    },
+    PrecedenceRow {
+        row: 7,
+        description: "Sundays per annum",
+        matches: RowMatch::kind(&[], &[Cat::Sunday]),
+    },
+    PrecedenceRow {
+        row: 8,
+        description: "Greater ferias and Ember days",
+        matches: RowMatch::kind(&[Rank::FeriaPrivileged], &[]),
+    },
+    PrecedenceRow {
+        row: 9,
+        description: "Greater and lesser doubles",
+        matches: RowMatch::kind(&[Rank::ClassII, Rank::ClassIII], &[]),
+    },
+    PrecedenceRow {
+        row: 10,
+        description: "Simples",
+        matches: RowMatch::kind(&[Rank::ClassIV], &[]),
+    },
+    PrecedenceRow {
+        row: 11,
+        description: "Ferias",
+        matches: RowMatch::ANY,
+    },
+];
+
+/// Occurrence: rows of the winner against rows of the impeded office
+pub(super) const OCCURRENCE: &[OccurrenceRule] = &[
+    OccurrenceRule {
+        winner_rows: &[],
+        loser_rows: &[1, 2, 3, 5, 7, 8],
+        loser_ranks: &[],
+        outcome: Outcome::Commemorated,
+        reason: "Sundays and privileged ferias are always commemorated",
+        citation: "Add. et Var., de occurrentia",
+    },
+    OccurrenceRule {
+        winner_rows: &[],
+        loser_rows: &[4, 6],
+        loser_ranks: &[],
+        outcome: Outcome::Transferred,
+        reason: "Doubles of the first and second class are transferred",
+        citation: "Add. et Var., de translatione",
+    },
+    OccurrenceRule {
+        winner_rows: &[1],
+        loser_rows: &[9, 10],
+        loser_ranks: &[],
+        outcome: Outcome::Omitted,
+        reason: "No commemoration of a double or simple on a primary double of the first class",
+        citation: "Add. et Var., de occurrentia",
+    },
+    OccurrenceRule {
+        winner_rows: &[],
+        loser_rows: &[11],
+        loser_ranks: &[],
+        outcome: Outcome::Omitted,
+        reason: "Ordinary ferias are not commemorated",
+        citation: "Add. et Var., de occurrentia",
+    },
+    OccurrenceRule {
+        winner_rows: &[],
+        loser_rows: &[],
+        loser_ranks: &[],
+        outcome: Outcome::Commemorated,
+        reason: "Commemorated",
+        citation: "Add. et Var., de occurrentia",
+    },
+];
+
+/// Concurrence: doubles and simples have first Vespers; ferias do not
+pub(super) const CONCURRENCE: &[ConcurrenceRule] = &[
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[3, 8, 11],
+        relation: Relation::Any,
+        vespers: VespersOf::Preceding,
+        commemorate: false,
+        citation: "Add. et Var., de concurrentia",
+    },
+    ConcurrenceRule {
+        preceding_rows: &[10, 11],
+        following_rows: &[],
+        relation: Relation::Any,
+        vespers: VespersOf::Following,
+        commemorate: false,
+        citation: "Add. et Var., de concurrentia",
+    },
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[],
+        relation: Relation::FollowingHigher,
+        vespers: VespersOf::Following,
+        commemorate: true,
+        citation: "Add. et Var., de concurrentia",
+    },
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[],
+        relation: Relation::Equal,
+        vespers: VespersOf::SplitAtChapter,
+        commemorate: true,
+        citation: "Add. et Var., de concurrentia",
+    },
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[],
+        relation: Relation::Any,
+        vespers: VespersOf::Preceding,
+        commemorate: true,
+        citation: "Add. et Var., de concurrentia",
+    },
+];
+
+pub(super) static TABLES: RubricTables = RubricTables {
+    system: RubricalSystem::PrePius,
+    precedence: PRECEDENCE,
+    preferences: &[],
+    occurrence: OCCURRENCE,
+    concurrence: CONCURRENCE,
+};
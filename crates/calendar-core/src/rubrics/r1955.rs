@@ -0,0 +1,48 @@
+//! Tables under the simplification of 1955 (Cum nostra hac aetate).
+//! Precedence and occurrence follow Divino Afflatu; first Vespers are kept
+//! only for Sundays and doubles of the first and second class.
+
+use super::*;
+
+const CONCURRENCE: &[ConcurrenceRule] = &[
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[3, 8, 9, 10, 11],
+        relation: Relation::Any,
+        vespers: VespersOf::Preceding,
+        commemorate: false,
+        citation: "Cum nostra, de concurrentia",
+    },
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[],
+        relation: Relation::FollowingHigher,
+        vespers: VespersOf::Following,
+        commemorate: true,
+        citation: "Cum nostra, de concurrentia",
+    },
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[],
+        relation: Relation::Equal,
+        vespers: VespersOf::SplitAtChapter,
+        commemorate: true,
+        citation: "Cum nostra, de concurrentia",
+    },
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[],
+        relation: Relation::Any,
+        vespers: VespersOf::Preceding,
+        commemorate: true,
+        citation: "Cum nostra, de concurrentia",
+    },
+];
+
+pub(super) static TABLES: RubricTables = RubricTables {
+    system: RubricalSystem::Rubrics1955,
+    precedence: super::pre1955::PRECEDENCE,
+    preferences: &[],
+    occurrence: super::pre1955::OCCURRENCE,
+    concurrence: CONCURRENCE,
+};
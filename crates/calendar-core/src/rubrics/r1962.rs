@@ -0,0 +1,164 @@
+//! Tables of the 1960 Rubricae Generales (1962 Missal).
+//!
+//! The table of precedence is abridged to the days this calendar models,
+//! and its rows are matched by id, rank and category rather than by date:
+//!
+//! - The Advent ferias of the 17th to the 23rd of December have no row of
+//!   their own (row 10 of the Rubrics); they rank with the other privileged
+//!   ferias in row 8.
+//! - The Vigils of Christmas and Pentecost are not generated, so they have
+//!   no row; the Vigil of Pentecost comes through as a feria.
+//! - The days within the Octaves of Easter and Pentecost share row 1 with
+//!   the Triduum and the three great feasts.
+
+use super::*;
+use CelebrationCategory as Cat;
+use CelebrationRank as Rank;
+
+const PRECEDENCE: &[PrecedenceRow] = &[
+    PrecedenceRow {
+        row: 1,
+        description: "Easter Triduum; Christmas, Easter and Pentecost; days within the Octaves of Easter and Pentecost",
+        matches: RowMatch {
+            ids: &["christmas", "easter-sunday", "holy-thursday", "good-friday", "holy-saturday", "pentecost"],
+            id_prefixes: &["easter-octave-", "pentecost-octave-"],
+            ranks: &[],
+            categories: &[],
+        },
+    },
+    PrecedenceRow {
+        row: 2,
+        description: "Sundays of Class I",
+        matches: RowMatch::ids(&["low-sunday"]),
+    },
+    PrecedenceRow {
+        row: 2,
+        description: "Sundays of Class I",
+        matches: RowMatch::kind(&[Rank::ClassI], &[Cat::Sunday]),
+    },
+    PrecedenceRow {
+        row: 3,
+        description: "Ash Wednesday; ferias of Holy Week",
+        matches: RowMatch::kind(&[Rank::ClassI], &[Cat::Feria]),
+    },
+    PrecedenceRow {
+        row: 4,
+        description: "Feasts of Class I",
+        matches: RowMatch::kind(&[Rank::ClassI], &[Cat::Solemnity, Cat::FeastOfLord]),
+    },
+    PrecedenceRow {
+        row: 5,
+        description: "Feasts of the Lord of Class II; feasts within the octave of Christmas",
+        matches: RowMatch::ids(&["st-stephen", "st-john-evangelist", "holy-innocents"]),
+    },
+    PrecedenceRow {
+        row: 5,
+        description: "Feasts of the Lord of Class II; feasts within the octave of Christmas",
+        matches: RowMatch::kind(&[Rank::ClassII], &[Cat::FeastOfLord]),
+    },
+    PrecedenceRow {
+        row: 6,
+        description: "Sundays of Class II",
+        matches: RowMatch::kind(&[], &[Cat::Sunday]),
+    },
+    PrecedenceRow {
+        row: 7,
+        description: "Feasts of Class II",
+        matches: RowMatch::kind(&[Rank::ClassII], &[]),
+    },
+    PrecedenceRow {
+        row: 8,
+        description: "Privileged ferias",
+        matches: RowMatch::kind(&[Rank::FeriaPrivileged], &[]),
+    },
+    PrecedenceRow {
+        row: 9,
+        description: "Feasts of Class III",
+        matches: RowMatch::kind(&[Rank::ClassIII], &[]),
+    },
+    PrecedenceRow {
+        row: 11,
+        description: "Ordinary ferias and Class IV commemorations",
+        matches: RowMatch::ANY,
+    },
+];
+
+const PREFERENCES: &[Preference] = &[
+    // Sundays of Class I are preferred to all feasts, save this one
+    Preference {
+        id: "immaculate-conception",
+        over: RowMatch { ids: &[], id_prefixes: &["sunday-advent-"], ranks: &[], categories: &[Cat::Sunday] },
+        citation: "RG 15",
+    },
+];
+
+const OCCURRENCE: &[OccurrenceRule] = &[
+    OccurrenceRule {
+        winner_rows: &[],
+        loser_rows: &[1, 2, 3],
+        loser_ranks: &[],
+        outcome: Outcome::Commemorated,
+        reason: "Privileged commemoration of a day of Class I",
+        citation: "RG 109",
+    },
+    OccurrenceRule {
+        winner_rows: &[],
+        loser_rows: &[4],
+        loser_ranks: &[],
+        outcome: Outcome::Transferred,
+        reason: "Class I feasts are transferred to the next day not occupied by a Class I or II celebration",
+        citation: "RG 95",
+    },
+    OccurrenceRule {
+        winner_rows: &[],
+        loser_rows: &[],
+        loser_ranks: &[Rank::Feria],
+        outcome: Outcome::Omitted,
+        reason: "Ordinary ferias are not commemorated",
+        citation: "RG 109",
+    },
+    OccurrenceRule {
+        winner_rows: &[],
+        loser_rows: &[],
+        loser_ranks: &[],
+        outcome: Outcome::Commemorated,
+        reason: "Commemorated",
+        citation: "RG 109",
+    },
+];
+
+const CONCURRENCE: &[ConcurrenceRule] = &[
+    // Only Class I celebrations and Sundays have first Vespers
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[3, 5, 7, 8, 9, 11],
+        relation: Relation::Any,
+        vespers: VespersOf::Preceding,
+        commemorate: false,
+        citation: "RG, de concurrentia",
+    },
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[],
+        relation: Relation::FollowingHigher,
+        vespers: VespersOf::Following,
+        commemorate: true,
+        citation: "RG, de concurrentia",
+    },
+    ConcurrenceRule {
+        preceding_rows: &[],
+        following_rows: &[],
+        relation: Relation::Any,
+        vespers: VespersOf::Preceding,
+        commemorate: true,
+        citation: "RG, de concurrentia",
+    },
+];
+
+pub(super) static TABLES: RubricTables = RubricTables {
+    system: RubricalSystem::Rubrics1962,
+    precedence: PRECEDENCE,
+    preferences: PREFERENCES,
+    occurrence: OCCURRENCE,
+    concurrence: CONCURRENCE,
+};
@@ -1,5 +1,6 @@
 use chrono::{Datelike, Duration, NaiveDate, Weekday};
 use std::collections::BTreeMap;
+use std::sync::OnceLock;
 
 use crate::types::*;
 
@@ -9,6 +10,26 @@ pub struct FixedFeast {
     pub month: u32,
     pub day: u32,
     pub celebration: Celebration,
+    /// Common the Mass is taken from, with the name said for its "N."
+    pub common: Option<(Common, SaintName)>,
+}
+
+impl FixedFeast {
+    fn with_common(mut self, common: Common, name: SaintName) -> Self {
+        self.common = Some((common, name));
+        self
+    }
+}
+
+/// The Common a fixed feast takes its Mass from, and the saint's name
+pub fn common_of(celebration_id: &str) -> Option<&'static (Common, SaintName)> {
+    static FEASTS: OnceLock<Vec<FixedFeast>> = OnceLock::new();
+    FEASTS
+        .get_or_init(major_feasts)
+        .iter()
+        .find(|f| f.celebration.id == celebration_id)?
+        .common
+        .as_ref()
 }
 
 /// Build the sanctoral cycle for a given year.
@@ -29,12 +50,11 @@ pub fn build_sanctoral_cycle(year: i32) -> BTreeMap<NaiveDate, Vec<Celebration>>
         .unwrap_or_else(|| NaiveDate::from_ymd_opt(year, 1, 2).unwrap());
     map.entry(holy_name_date).or_default().push(Celebration::new(
         "holy-name-of-jesus",
-        "Ss.mi Nominis Jesu",
+        "Ss.mi Nóminis Jesu",
         "The Most Holy Name of Jesus",
         CelebrationRank::ClassII,
         CelebrationCategory::FeastOfLord,
         LiturgicalColor::White,
-        5,
     ));
 
     // Holy Family: Sunday within Octave of Christmas (Jan 1-5 range in civil year)
@@ -66,18 +86,71 @@ pub fn build_sanctoral_cycle(year: i32) -> BTreeMap<NaiveDate, Vec<Celebration>>
     if holy_family_date.year() == year {
         map.entry(holy_family_date).or_default().push(Celebration::new(
             "holy-family",
-            "Sanctae Familiae",
+            "Sanctae Famíliae",
             "The Holy Family",
             CelebrationRank::ClassI,
             CelebrationCategory::FeastOfLord,
             LiturgicalColor::White,
-            4,
         ));
     }
 
     map
 }
 
+/// Whether a Saturday is free for the Office and Mass of Our Lady: the day
+/// would otherwise be an ordinary (Class IV) feria, and it falls outside
+/// Advent, Lent and Passiontide. Ember days and vigils are never ordinary
+/// ferias; the Vigil of Pentecost, not modeled as a celebration, is
+/// excluded by date.
+pub fn is_free_saturday(
+    date: NaiveDate,
+    season: LiturgicalSeason,
+    winner: &Celebration,
+    mf: &MoveableFeasts,
+) -> bool {
+    date.weekday() == Weekday::Sat
+        && winner.category == CelebrationCategory::Feria
+        && winner.rank == CelebrationRank::Feria
+        && date != mf.pentecost - Duration::days(1)
+        && !matches!(
+            season,
+            LiturgicalSeason::Advent
+                | LiturgicalSeason::Lent
+                | LiturgicalSeason::Passiontide
+                | LiturgicalSeason::HolyWeek
+        )
+}
+
+/// Sancta Maria in Sabbato with the formulary proper to the time of year:
+/// Rorate in Advent, Vultum tuum from Christmas to the Purification, and
+/// Salve Sancta Parens for the rest of the year (with its Paschal form
+/// from Easter to Pentecost). Advent Saturdays are never free, so Rorate
+/// is reached only by calling this directly.
+pub fn saturday_of_our_lady(date: NaiveDate, mf: &MoveableFeasts) -> Celebration {
+    let year = date.year();
+    let christmas = NaiveDate::from_ymd_opt(year, 12, 25).unwrap();
+    let purification = NaiveDate::from_ymd_opt(year, 2, 2).unwrap();
+
+    let (suffix, incipit) = if date >= mf.advent_1 && date < christmas {
+        ("rorate", "Rorate")
+    } else if date >= christmas || date < purification {
+        ("vultum-tuum", "Vultum tuum")
+    } else if date >= mf.easter && date <= mf.pentecost {
+        ("salve-sancta-parens-paschal", "Salve Sancta Parens, Paschaltide")
+    } else {
+        ("salve-sancta-parens", "Salve Sancta Parens")
+    };
+
+    Celebration::new(
+        format!("saturday-of-our-lady-{}", suffix),
+        "Sanctae Mariae in Sabbato",
+        format!("Saturday of Our Lady ({})", incipit),
+        CelebrationRank::ClassIV,
+        CelebrationCategory::Memorial,
+        LiturgicalColor::White,
+    )
+}
+
 fn find_sunday_between(year: i32, m1: u32, d1: u32, m2: u32, d2: u32) -> Option<NaiveDate> {
     let start = NaiveDate::from_ymd_opt(year, m1, d1)?;
     let end = NaiveDate::from_ymd_opt(year, m2, d2)?;
@@ -95,87 +168,97 @@ fn find_sunday_between(year: i32, m1: u32, d1: u32, m2: u32, d2: u32) -> Option<
 fn major_feasts() -> Vec<FixedFeast> {
     vec![
         // January
-        fixed(1, 1, "circumcision", "In Circumcisione Domini", "Circumcision of Our Lord", CelebrationRank::ClassI, CelebrationCategory::FeastOfLord, LiturgicalColor::White, 4),
-        fixed(1, 6, "epiphany", "In Epiphania Domini", "The Epiphany of Our Lord", CelebrationRank::ClassI, CelebrationCategory::FeastOfLord, LiturgicalColor::White, 4),
-        fixed(1, 25, "conversion-of-st-paul", "Conversio S. Pauli", "Conversion of St. Paul", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White, 9),
-        fixed(1, 28, "st-thomas-aquinas", "S. Thomae de Aquino", "St. Thomas Aquinas", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White, 9),
+        fixed(1, 1, "circumcision", "In Circumcisióne Dómini", "Circumcision of Our Lord", CelebrationRank::ClassI, CelebrationCategory::FeastOfLord, LiturgicalColor::White),
+        fixed(1, 6, "epiphany", "In Epiphanía Dómini", "The Epiphany of Our Lord", CelebrationRank::ClassI, CelebrationCategory::FeastOfLord, LiturgicalColor::White),
+        fixed(1, 25, "conversion-of-st-paul", "Convérsio S. Pauli", "Conversion of St. Paul", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(1, 20, "ss-fabian-sebastian", "Ss. Fabiáni et Sebastiáni", "Sts. Fabian and Sebastian, Martyrs", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red)
+            .with_common(Common::Martyrs, name("Fabiánus et Sebastiánus", "Fabiáni et Sebastiáni", "Fabiánum et Sebastiánum", "Fabiáno et Sebastiáno", "Fabian and Sebastian")),
+        fixed(1, 28, "st-thomas-aquinas", "S. Thomae de Aquíno", "St. Thomas Aquinas", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White)
+            .with_common(Common::Doctors, name("Thomas", "Thomae", "Thomam", "Thoma", "Thomas")),
 
         // February
-        fixed(2, 2, "purification-bvm", "In Purificatione B.M.V.", "Purification of the BVM (Candlemas)", CelebrationRank::ClassII, CelebrationCategory::FeastOfLord, LiturgicalColor::White, 5),
-        fixed(2, 22, "chair-of-st-peter", "Cathedra S. Petri", "Chair of St. Peter", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 7),
-        fixed(2, 24, "st-matthias", "S. Matthiae", "St. Matthias, Apostle", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
+        fixed(2, 2, "purification-bvm", "In Purificatióne B.M.V.", "Purification of the BVM (Candlemas)", CelebrationRank::ClassII, CelebrationCategory::FeastOfLord, LiturgicalColor::White),
+        fixed(2, 22, "chair-of-st-peter", "Cáthedra S. Petri", "Chair of St. Peter", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(2, 24, "st-matthias", "S. Matthíae", "St. Matthias, Apostle", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
 
         // March
-        fixed(3, 7, "st-perpetua-felicity", "Ss. Perpetuae et Felicitatis", "Sts. Perpetua and Felicity, Martyrs", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red, 9),
-        fixed(3, 12, "st-gregory-great", "S. Gregorii I Papae", "St. Gregory the Great, Pope and Doctor", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White, 9),
-        fixed(3, 17, "st-patrick", "S. Patricii", "St. Patrick, Bishop and Confessor", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White, 9),
-        fixed(3, 19, "st-joseph", "S. Joseph Sponsi B.M.V.", "St. Joseph, Spouse of the BVM", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White, 4),
-        fixed(3, 25, "annunciation", "In Annuntiatione B.M.V.", "The Annunciation of the BVM", CelebrationRank::ClassI, CelebrationCategory::FeastOfLord, LiturgicalColor::White, 4),
+        fixed(3, 7, "st-perpetua-felicity", "Ss. Perpétuae et Felicitátis", "Sts. Perpetua and Felicity, Martyrs", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(3, 12, "st-gregory-great", "S. Gregórii I Papae", "St. Gregory the Great, Pope and Doctor", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(3, 17, "st-patrick", "S. Patrícii", "St. Patrick, Bishop and Confessor", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White)
+            .with_common(Common::ConfessorBishop, name("Patrícius", "Patrícii", "Patrícium", "Patrício", "Patrick")),
+        fixed(3, 19, "st-joseph", "S. Joseph Sponsi B.M.V.", "St. Joseph, Spouse of the BVM", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White),
+        fixed(3, 25, "annunciation", "In Annuntiatióne B.M.V.", "The Annunciation of the BVM", CelebrationRank::ClassI, CelebrationCategory::FeastOfLord, LiturgicalColor::White),
 
         // April
-        fixed(4, 2, "st-francis-of-paola", "S. Francisci de Paula", "St. Francis of Paola, Confessor", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White, 9),
-        fixed(4, 25, "st-mark", "S. Marci", "St. Mark, Evangelist", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
+        fixed(4, 2, "st-francis-of-paola", "S. Francísci de Paula", "St. Francis of Paola, Confessor", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White)
+            .with_common(Common::Confessor, name("Francíscus", "Francísci", "Francíscum", "Francísco", "Francis")),
+        fixed(4, 25, "st-mark", "S. Marci", "St. Mark, Evangelist", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
 
         // May
-        fixed(5, 1, "st-joseph-worker", "S. Joseph Opificis", "St. Joseph the Worker", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White, 4),
-        fixed(5, 3, "finding-holy-cross", "Inventio S. Crucis", "Finding of the Holy Cross", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red, 9),
-        fixed(5, 11, "ss-philip-james", "Ss. Philippi et Jacobi", "Sts. Philip and James, Apostles", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
-        fixed(5, 31, "queenship-of-mary", "B.M.V. Reginae", "Queenship of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 7),
+        fixed(5, 1, "st-joseph-worker", "S. Joseph Opíficis", "St. Joseph the Worker", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White),
+        fixed(5, 3, "finding-holy-cross", "Invéntio S. Crucis", "Finding of the Holy Cross", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(5, 4, "st-monica", "S. Mónicae Víduae", "St. Monica, Widow", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White)
+            .with_common(Common::HolyWoman, name("Mónica", "Mónicae", "Mónicam", "Mónica", "Monica")),
+        fixed(5, 11, "ss-philip-james", "Ss. Philíppi et Jacóbi", "Sts. Philip and James, Apostles", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(5, 31, "queenship-of-mary", "B.M.V. Regínae", "Queenship of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
 
         // June
-        fixed(6, 24, "nativity-of-st-john-baptist", "In Nativitate S. Joannis Baptistae", "Nativity of St. John the Baptist", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White, 4),
-        fixed(6, 29, "ss-peter-paul", "Ss. Petri et Pauli", "Sts. Peter and Paul, Apostles", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::Red, 4),
+        fixed(6, 24, "nativity-of-st-john-baptist", "In Nativitáte S. Joánnis Baptístae", "Nativity of St. John the Baptist", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White),
+        fixed(6, 29, "ss-peter-paul", "Ss. Petri et Pauli", "Sts. Peter and Paul, Apostles", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::Red),
 
         // July
-        fixed(7, 2, "visitation-bvm", "Visitatio B.M.V.", "Visitation of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 7),
-        fixed(7, 25, "st-james-greater", "S. Jacobi Majoris", "St. James the Greater, Apostle", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
-        fixed(7, 26, "st-anne", "S. Annae Matris B.M.V.", "St. Anne, Mother of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 7),
+        fixed(7, 2, "visitation-bvm", "Visitátio B.M.V.", "Visitation of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(7, 25, "st-james-greater", "S. Jacóbi Majóris", "St. James the Greater, Apostle", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(7, 26, "st-anne", "S. Annae Matris B.M.V.", "St. Anne, Mother of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
 
         // August
-        fixed(8, 6, "transfiguration", "In Transfiguratione Domini", "The Transfiguration of Our Lord", CelebrationRank::ClassII, CelebrationCategory::FeastOfLord, LiturgicalColor::White, 5),
-        fixed(8, 10, "st-lawrence", "S. Laurentii", "St. Lawrence, Martyr", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
-        fixed(8, 15, "assumption-bvm", "In Assumptione B.M.V.", "The Assumption of the BVM", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White, 4),
-        fixed(8, 22, "immaculate-heart-of-mary", "Immaculati Cordis B.M.V.", "Immaculate Heart of Mary", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 7),
-        fixed(8, 24, "st-bartholomew", "S. Bartholomaei", "St. Bartholomew, Apostle", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
-        fixed(8, 28, "st-augustine", "S. Augustini", "St. Augustine, Bishop and Doctor", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White, 9),
-        fixed(8, 29, "beheading-john-baptist", "In Decollatione S. Joannis Baptistae", "Beheading of St. John the Baptist", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red, 9),
+        fixed(8, 6, "transfiguration", "In Transfiguratióne Dómini", "The Transfiguration of Our Lord", CelebrationRank::ClassII, CelebrationCategory::FeastOfLord, LiturgicalColor::White),
+        fixed(8, 10, "st-lawrence", "S. Lauréntii", "St. Lawrence, Martyr", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(8, 15, "assumption-bvm", "In Assumptióne B.M.V.", "The Assumption of the BVM", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White),
+        fixed(8, 22, "immaculate-heart-of-mary", "Immaculáti Cordis B.M.V.", "Immaculate Heart of Mary", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(8, 24, "st-bartholomew", "S. Bartholomáei", "St. Bartholomew, Apostle", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(8, 28, "st-augustine", "S. Augustíni", "St. Augustine, Bishop and Doctor", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White)
+            .with_common(Common::Doctors, name("Augustínus", "Augustíni", "Augustínum", "Augustíno", "Augustine")),
+        fixed(8, 29, "beheading-john-baptist", "In Decollatióne S. Joánnis Baptístae", "Beheading of St. John the Baptist", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red),
 
         // September
-        fixed(9, 8, "nativity-bvm", "In Nativitate B.M.V.", "Nativity of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 7),
-        fixed(9, 14, "exaltation-holy-cross", "In Exaltatione S. Crucis", "Exaltation of the Holy Cross", CelebrationRank::ClassII, CelebrationCategory::FeastOfLord, LiturgicalColor::Red, 5),
-        fixed(9, 15, "seven-sorrows-bvm", "Septem Dolorum B.M.V.", "Seven Sorrows of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 7),
-        fixed(9, 21, "st-matthew", "S. Matthaei", "St. Matthew, Apostle and Evangelist", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
-        fixed(9, 29, "st-michael", "Dedicatio S. Michaelis Archangeli", "St. Michael the Archangel", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White, 4),
+        fixed(9, 8, "nativity-bvm", "In Nativitáte B.M.V.", "Nativity of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(9, 14, "exaltation-holy-cross", "In Exaltatióne S. Crucis", "Exaltation of the Holy Cross", CelebrationRank::ClassII, CelebrationCategory::FeastOfLord, LiturgicalColor::Red),
+        fixed(9, 15, "seven-sorrows-bvm", "Septem Dolórum B.M.V.", "Seven Sorrows of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(9, 21, "st-matthew", "S. Mattháei", "St. Matthew, Apostle and Evangelist", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(9, 29, "st-michael", "Dedicátio S. Michaélis Archángeli", "St. Michael the Archangel", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White),
 
         // October
-        fixed(10, 7, "holy-rosary", "B.M.V. a Rosario", "Our Lady of the Rosary", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 7),
-        fixed(10, 11, "divine-motherhood-bvm", "Maternitatis B.M.V.", "Divine Motherhood of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 7),
-        fixed(10, 18, "st-luke", "S. Lucae", "St. Luke, Evangelist", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
-        fixed(10, 28, "ss-simon-jude", "Ss. Simonis et Judae", "Sts. Simon and Jude, Apostles", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
+        fixed(10, 7, "holy-rosary", "B.M.V. a Rosário", "Our Lady of the Rosary", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(10, 11, "divine-motherhood-bvm", "Maternitátis B.M.V.", "Divine Motherhood of the BVM", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(10, 18, "st-luke", "S. Lucae", "St. Luke, Evangelist", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(10, 28, "ss-simon-jude", "Ss. Simónis et Judae", "Sts. Simon and Jude, Apostles", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
 
         // November
-        fixed(11, 1, "all-saints", "Omnium Sanctorum", "All Saints", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White, 4),
-        fixed(11, 2, "all-souls", "In Commemoratione Omnium Fidelium Defunctorum", "All Souls Day", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::Black, 4),
-        fixed(11, 9, "dedication-lateran", "Dedicatio Archibasilicae Ss.mi Salvatoris", "Dedication of the Lateran Basilica", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 7),
-        fixed(11, 21, "presentation-bvm", "Praesentatio B.M.V.", "Presentation of the BVM", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White, 9),
-        fixed(11, 30, "st-andrew", "S. Andreae", "St. Andrew, Apostle", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
+        fixed(11, 1, "all-saints", "Ómnium Sanctórum", "All Saints", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White),
+        fixed(11, 2, "all-souls", "In Commemoratióne Ómnium Fidélium Defunctórum", "All Souls Day", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::Black),
+        fixed(11, 9, "dedication-lateran", "Dedicátio Archibasílicae Ss.mi Salvatóris", "Dedication of the Lateran Basilica", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(11, 21, "presentation-bvm", "Praesentátio B.M.V.", "Presentation of the BVM", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(11, 30, "st-andrew", "S. Andréae", "St. Andrew, Apostle", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
 
         // November (more)
-        fixed(11, 11, "st-martin-of-tours", "S. Martini Episcopi", "St. Martin of Tours, Bishop", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White, 9),
-        fixed(11, 22, "st-cecilia", "S. Caeciliae", "St. Cecilia, Virgin and Martyr", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red, 9),
-        fixed(11, 25, "st-catherine-of-alexandria", "S. Catharinae", "St. Catherine of Alexandria, Virgin and Martyr", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red, 9),
+        fixed(11, 11, "st-martin-of-tours", "S. Martíni Epíscopi", "St. Martin of Tours, Bishop", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White)
+            .with_common(Common::ConfessorBishop, name("Martínus", "Martíni", "Martínum", "Martíno", "Martin")),
+        fixed(11, 22, "st-cecilia", "S. Caecíliae", "St. Cecilia, Virgin and Martyr", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(11, 25, "st-catherine-of-alexandria", "S. Catharínae", "St. Catherine of Alexandria, Virgin and Martyr", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::Red),
 
         // December
-        fixed(12, 8, "immaculate-conception", "In Conceptione Immaculata B.M.V.", "Immaculate Conception of the BVM", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White, 4),
-        fixed(12, 21, "st-thomas-apostle", "S. Thomae Apostoli", "St. Thomas, Apostle", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 7),
-        fixed(12, 25, "christmas", "In Nativitate Domini", "The Nativity of Our Lord", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White, 1),
-        fixed(12, 26, "st-stephen", "S. Stephani Protomartyris", "St. Stephen, Protomartyr", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 5),
-        fixed(12, 27, "st-john-evangelist", "S. Joannis Apostoli et Evangelistae", "St. John, Apostle and Evangelist", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White, 5),
-        fixed(12, 28, "holy-innocents", "Ss. Innocentium", "Holy Innocents", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red, 5),
-        fixed(12, 31, "st-sylvester", "S. Silvestri I", "St. Sylvester I, Pope", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White, 9),
+        fixed(12, 8, "immaculate-conception", "In Conceptióne Immaculáta B.M.V.", "Immaculate Conception of the BVM", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White),
+        fixed(12, 21, "st-thomas-apostle", "S. Thomae Apóstoli", "St. Thomas, Apostle", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(12, 25, "christmas", "In Nativitáte Dómini", "The Nativity of Our Lord", CelebrationRank::ClassI, CelebrationCategory::Solemnity, LiturgicalColor::White),
+        fixed(12, 26, "st-stephen", "S. Stéphani Protomártyris", "St. Stephen, Protomartyr", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(12, 27, "st-john-evangelist", "S. Joánnis Apóstoli et Evangelístae", "St. John, Apostle and Evangelist", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::White),
+        fixed(12, 28, "holy-innocents", "Ss. Innocéntium", "Holy Innocents", CelebrationRank::ClassII, CelebrationCategory::Feast, LiturgicalColor::Red),
+        fixed(12, 31, "st-sylvester", "S. Silvéstri I", "St. Sylvester I, Pope", CelebrationRank::ClassIII, CelebrationCategory::Feast, LiturgicalColor::White),
     ]
 }
 
+#[allow(clippy::too_many_arguments)]
 fn fixed(
     month: u32,
     day: u32,
@@ -185,12 +268,22 @@ fn fixed(
     rank: CelebrationRank,
     category: CelebrationCategory,
     color: LiturgicalColor,
-    precedence: u8,
 ) -> FixedFeast {
     FixedFeast {
         month,
         day,
-        celebration: Celebration::new(id, title, title_en, rank, category, color, precedence),
+        celebration: Celebration::new(id, title, title_en, rank, category, color),
+        common: None,
+    }
+}
+
+fn name(nominative: &str, genitive: &str, accusative: &str, ablative: &str, english: &str) -> SaintName {
+    SaintName {
+        nominative: nominative.into(),
+        genitive: genitive.into(),
+        accusative: accusative.into(),
+        ablative: ablative.into(),
+        english: english.into(),
     }
 }
 
@@ -220,6 +313,32 @@ mod tests {
         assert!(feasts.len() >= 45, "Expected at least 45 major feasts, got {}", feasts.len());
     }
 
+    #[test]
+    fn test_saturday_of_our_lady_formulary() {
+        let mf = crate::computus::moveable_feasts(2026);
+        let id = |m, d| saturday_of_our_lady(NaiveDate::from_ymd_opt(2026, m, d).unwrap(), &mf).id;
+        assert_eq!(id(1, 17), "saturday-of-our-lady-vultum-tuum");
+        assert_eq!(id(2, 7), "saturday-of-our-lady-salve-sancta-parens");
+        assert_eq!(id(4, 25), "saturday-of-our-lady-salve-sancta-parens-paschal");
+        assert_eq!(id(7, 18), "saturday-of-our-lady-salve-sancta-parens");
+        assert_eq!(id(12, 5), "saturday-of-our-lady-rorate");
+    }
+
+    #[test]
+    fn test_free_saturday_excludes_lent() {
+        let lent = Celebration::feria(LiturgicalSeason::Lent, 2, Weekday::Sat);
+        let sat = NaiveDate::from_ymd_opt(2026, 3, 7).unwrap();
+        let mf = crate::computus::moveable_feasts(2026);
+        assert!(!is_free_saturday(sat, LiturgicalSeason::Lent, &lent, &mf));
+        let per_annum = Celebration::feria(LiturgicalSeason::AfterPentecost, 8, Weekday::Sat);
+        let sat = NaiveDate::from_ymd_opt(2026, 7, 18).unwrap();
+        assert!(is_free_saturday(sat, LiturgicalSeason::AfterPentecost, &per_annum, &mf));
+        // The eve of Pentecost is its Vigil
+        let eve = Celebration::feria(LiturgicalSeason::Ascensiontide, 1, Weekday::Sat);
+        let sat = NaiveDate::from_ymd_opt(2026, 5, 23).unwrap();
+        assert!(!is_free_saturday(sat, LiturgicalSeason::Ascensiontide, &eve, &mf));
+    }
+
     #[test]
     fn test_epiphany_class_i() {
         let cycle = build_sanctoral_cycle(2026);
@@ -0,0 +1,466 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Books of the Bible in the Vulgate canon.
+///
+/// Serialized as the OSIS book code (`"Isa"`, `"1Kgs"`, `"Ps"`), which
+/// Bible software understands regardless of the naming tradition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Book {
+    Genesis, Exodus, Leviticus, Numbers, Deuteronomy, Joshua, Judges, Ruth,
+    Samuel1, Samuel2, Kings1, Kings2, Chronicles1, Chronicles2, Ezra, Nehemiah,
+    Tobit, Judith, Esther, Maccabees1, Maccabees2, Job, Psalms, Proverbs,
+    Ecclesiastes, SongOfSongs, Wisdom, Sirach, Isaiah, Jeremiah, Lamentations,
+    Baruch, Ezekiel, Daniel, Hosea, Joel, Amos, Obadiah, Jonah, Micah, Nahum,
+    Habakkuk, Zephaniah, Haggai, Zechariah, Malachi,
+    Matthew, Mark, Luke, John, Acts, Romans, Corinthians1, Corinthians2,
+    Galatians, Ephesians, Philippians, Colossians, Thessalonians1,
+    Thessalonians2, Timothy1, Timothy2, Titus, Philemon, Hebrews, James,
+    Peter1, Peter2, John1, John2, John3, Jude, Revelation,
+}
+
+/// Book, OSIS code, Douay abbreviation, modern abbreviation and further
+/// names accepted by the parser
+const BOOKS: &[(Book, &str, &str, &str, &[&str])] = &[
+    (Book::Genesis, "Gen", "Gen", "Gen", &["genesis", "gn"]),
+    (Book::Exodus, "Exod", "Exod", "Exod", &["exodus", "ex", "exo"]),
+    (Book::Leviticus, "Lev", "Lev", "Lev", &["leviticus", "lv"]),
+    (Book::Numbers, "Num", "Num", "Num", &["numbers", "numeri", "nm"]),
+    (Book::Deuteronomy, "Deut", "Deut", "Deut", &["deuteronomy", "dt"]),
+    (Book::Joshua, "Josh", "Josue", "Josh", &["joshua", "jos"]),
+    (Book::Judges, "Judg", "Judg", "Judg", &["judges", "jdg"]),
+    (Book::Ruth, "Ruth", "Ruth", "Ruth", &["rt"]),
+    // The Douay "Kings" count four books, the first two being Samuel
+    (Book::Samuel1, "1Sam", "1 Kings", "1 Sam", &["1 samuel", "1 reg", "1 regum", "1 kg"]),
+    (Book::Samuel2, "2Sam", "2 Kings", "2 Sam", &["2 samuel", "2 reg", "2 regum", "2 kg"]),
+    (Book::Kings1, "1Kgs", "3 Kings", "1 Kgs", &["3 reg", "3 regum", "3 kgs", "3 kg"]),
+    (Book::Kings2, "2Kgs", "4 Kings", "2 Kgs", &["4 reg", "4 regum", "4 kgs", "4 kg"]),
+    (Book::Chronicles1, "1Chr", "1 Par", "1 Chr", &["1 paralipomenon", "1 chronicles", "1 chron"]),
+    (Book::Chronicles2, "2Chr", "2 Par", "2 Chr", &["2 paralipomenon", "2 chronicles", "2 chron"]),
+    (Book::Ezra, "Ezra", "1 Esdras", "Ezra", &["1 esd", "esdras"]),
+    (Book::Nehemiah, "Neh", "2 Esdras", "Neh", &["2 esd", "nehemiah", "nehemias"]),
+    (Book::Tobit, "Tob", "Tob", "Tob", &["tobias", "tobit", "tb"]),
+    (Book::Judith, "Jdt", "Judith", "Jdt", &["jdth"]),
+    (Book::Esther, "Esth", "Esther", "Esth", &["est"]),
+    (Book::Maccabees1, "1Macc", "1 Mach", "1 Macc", &["1 machabees", "1 maccabees", "1 mac"]),
+    (Book::Maccabees2, "2Macc", "2 Mach", "2 Macc", &["2 machabees", "2 maccabees", "2 mac"]),
+    (Book::Job, "Job", "Job", "Job", &["jb"]),
+    (Book::Psalms, "Ps", "Ps", "Ps", &["psalm", "psalms", "pss", "psalmus", "psalmi"]),
+    (Book::Proverbs, "Prov", "Prov", "Prov", &["proverbs", "prv"]),
+    (Book::Ecclesiastes, "Eccl", "Eccles", "Eccl", &["ecclesiastes", "qoheleth", "ecc", "qoh"]),
+    (Book::SongOfSongs, "Song", "Cant", "Song", &["canticle of canticles", "canticles", "song of songs", "song of solomon"]),
+    (Book::Wisdom, "Wis", "Wis", "Wis", &["wisdom", "sap", "sapientia"]),
+    (Book::Sirach, "Sir", "Ecclus", "Sir", &["ecclesiasticus", "sirach", "eccli"]),
+    (Book::Isaiah, "Isa", "Isaias", "Isa", &["isaiah", "is"]),
+    (Book::Jeremiah, "Jer", "Jer", "Jer", &["jeremias", "jeremiah"]),
+    (Book::Lamentations, "Lam", "Lam", "Lam", &["lamentations"]),
+    (Book::Baruch, "Bar", "Bar", "Bar", &["baruch"]),
+    (Book::Ezekiel, "Ezek", "Ezech", "Ezek", &["ezechiel", "ezekiel", "ez"]),
+    (Book::Daniel, "Dan", "Dan", "Dan", &["daniel", "dn"]),
+    (Book::Hosea, "Hos", "Osee", "Hos", &["hosea", "os"]),
+    (Book::Joel, "Joel", "Joel", "Joel", &["jl"]),
+    (Book::Amos, "Amos", "Amos", "Amos", &["am"]),
+    (Book::Obadiah, "Obad", "Abdias", "Obad", &["obadiah", "abd"]),
+    (Book::Jonah, "Jonah", "Jonas", "Jonah", &["jon"]),
+    (Book::Micah, "Mic", "Mich", "Mic", &["micheas", "micah"]),
+    (Book::Nahum, "Nah", "Nahum", "Nah", &[]),
+    (Book::Habakkuk, "Hab", "Hab", "Hab", &["habacuc", "habakkuk"]),
+    (Book::Zephaniah, "Zeph", "Soph", "Zeph", &["sophonias", "zephaniah"]),
+    (Book::Haggai, "Hag", "Agg", "Hag", &["aggeus", "haggai"]),
+    (Book::Zechariah, "Zech", "Zach", "Zech", &["zacharias", "zechariah"]),
+    (Book::Malachi, "Mal", "Mal", "Mal", &["malachias", "malachi"]),
+    (Book::Matthew, "Matt", "Matt", "Matt", &["matthew", "mt", "mat"]),
+    (Book::Mark, "Mark", "Mark", "Mark", &["mk", "mc", "marcus"]),
+    (Book::Luke, "Luke", "Luke", "Luke", &["lk", "lc", "lucas"]),
+    (Book::John, "John", "John", "John", &["jn", "joannes"]),
+    (Book::Acts, "Acts", "Acts", "Acts", &["acts of the apostles", "act"]),
+    (Book::Romans, "Rom", "Rom", "Rom", &["romans"]),
+    (Book::Corinthians1, "1Cor", "1 Cor", "1 Cor", &["1 corinthians"]),
+    (Book::Corinthians2, "2Cor", "2 Cor", "2 Cor", &["2 corinthians"]),
+    (Book::Galatians, "Gal", "Gal", "Gal", &["galatians"]),
+    (Book::Ephesians, "Eph", "Eph", "Eph", &["ephesians"]),
+    (Book::Philippians, "Phil", "Phil", "Phil", &["philippians"]),
+    (Book::Colossians, "Col", "Col", "Col", &["colossians"]),
+    (Book::Thessalonians1, "1Thess", "1 Thess", "1 Thess", &["1 thessalonians"]),
+    (Book::Thessalonians2, "2Thess", "2 Thess", "2 Thess", &["2 thessalonians"]),
+    (Book::Timothy1, "1Tim", "1 Tim", "1 Tim", &["1 timothy"]),
+    (Book::Timothy2, "2Tim", "2 Tim", "2 Tim", &["2 timothy"]),
+    (Book::Titus, "Titus", "Titus", "Titus", &["tit"]),
+    (Book::Philemon, "Phlm", "Philem", "Phlm", &["philemon"]),
+    (Book::Hebrews, "Heb", "Heb", "Heb", &["hebrews"]),
+    (Book::James, "Jas", "James", "Jas", &["jac", "jacobi"]),
+    (Book::Peter1, "1Pet", "1 Pet", "1 Pet", &["1 peter", "1 pt"]),
+    (Book::Peter2, "2Pet", "2 Pet", "2 Pet", &["2 peter", "2 pt"]),
+    (Book::John1, "1John", "1 John", "1 John", &["1 jn"]),
+    (Book::John2, "2John", "2 John", "2 John", &["2 jn"]),
+    (Book::John3, "3John", "3 John", "3 John", &["3 jn"]),
+    (Book::Jude, "Jude", "Jude", "Jude", &["jud"]),
+    (Book::Revelation, "Rev", "Apoc", "Rev", &["apocalypse", "revelation", "apc"]),
+];
+
+impl Book {
+    fn entry(self) -> &'static (Book, &'static str, &'static str, &'static str, &'static [&'static str]) {
+        BOOKS.iter().find(|e| e.0 == self).expect("every book is in the table")
+    }
+
+    /// OSIS book code, e.g. `1Kgs`
+    pub fn osis(self) -> &'static str {
+        self.entry().1
+    }
+
+    /// Abbreviation in the Douay-Rheims tradition, e.g. `3 Kings`, `Isaias`
+    pub fn douay(self) -> &'static str {
+        self.entry().2
+    }
+
+    /// Abbreviation in modern English usage, e.g. `1 Kgs`, `Isa`
+    pub fn modern(self) -> &'static str {
+        self.entry().3
+    }
+
+    /// Look a book up by any of its Douay, Vulgate, modern or OSIS names.
+    ///
+    /// Case, full stops and roman ordinals are ignored. "1 Kings" to
+    /// "4 Kings" are read in the Douay sense; use "1 Sam" or "1 Kgs" for
+    /// the modern books.
+    pub fn from_name(name: &str) -> Option<Book> {
+        let key = normalize(name);
+        BOOKS
+            .iter()
+            .find(|(_, osis, douay, modern, aliases)| {
+                [*osis, *douay, *modern].iter().any(|n| normalize(n) == key)
+                    || aliases.iter().any(|a| *a == key)
+            })
+            .map(|e| e.0)
+    }
+}
+
+impl Serialize for Book {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(self.osis())
+    }
+}
+
+impl<'de> Deserialize<'de> for Book {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(d)?;
+        Book::from_name(&name).ok_or_else(|| serde::de::Error::custom(format!("unknown book: {name}")))
+    }
+}
+
+/// Lower-case, drop full stops, separate a leading ordinal ("1Cor",
+/// "I Cor") from the name and collapse whitespace
+fn normalize(name: &str) -> String {
+    let lower = name.to_lowercase().replace('.', " ");
+    let mut words: Vec<String> = lower.split_whitespace().map(String::from).collect();
+    if let Some(first) = words.first().cloned() {
+        let ordinal = match first.as_str() {
+            "i" => Some("1".to_string()),
+            "ii" => Some("2".to_string()),
+            "iii" => Some("3".to_string()),
+            "iv" => Some("4".to_string()),
+            _ => None,
+        };
+        if let (Some(n), true) = (ordinal, words.len() > 1) {
+            words[0] = n;
+        } else if first.len() > 1 && first.as_bytes()[0].is_ascii_digit() && first[1..].chars().all(char::is_alphabetic) {
+            words[0] = first[1..].to_string();
+            words.insert(0, first[..1].to_string());
+        }
+    }
+    words.join(" ")
+}
+
+/// A position in a book; `verse` is `None` for a whole chapter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Verse {
+    pub chapter: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verse: Option<u16>,
+}
+
+/// A continuous run of text from `start` to `end` inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Segment {
+    pub start: Verse,
+    pub end: Verse,
+}
+
+/// A parsed reference into one book, possibly in several segments, as in
+/// "1 Cor 9:24-27; 10:1-5" or "Ps 97:3-4, 2".
+///
+/// Psalms are numbered as written; the Missal uses the Vulgate numbering.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptureRef {
+    pub book: Book,
+    pub segments: Vec<Segment>,
+    /// Trailing remark such as "(Passion)"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// How book names are written when rendering a reference
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookStyle {
+    Douay,
+    Modern,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRefError(String);
+
+impl fmt::Display for ParseRefError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid scripture reference: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseRefError {}
+
+/// Parse a reference that may name several books, as in
+/// "Matt 28:7; John 20:26". A part without a book continues the previous one.
+pub fn parse_all(text: &str) -> Result<Vec<ScriptureRef>, ParseRefError> {
+    let err = || ParseRefError(text.to_string());
+    let (body, note) = match text.trim().split_once('(') {
+        Some((body, rest)) => (body, Some(rest.trim_end_matches(')').trim().to_string())),
+        None => (text, None),
+    };
+
+    let mut refs: Vec<ScriptureRef> = Vec::new();
+    for part in body.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+        let (name, passage) = split_book(part);
+        let book = match name {
+            Some(name) => Book::from_name(name).ok_or_else(err)?,
+            None => refs.last().map(|r| r.book).ok_or_else(err)?,
+        };
+        let segments = parse_segments(passage).ok_or_else(err)?;
+        match refs.last_mut() {
+            Some(last) if name.is_none() => last.segments.extend(segments),
+            _ => refs.push(ScriptureRef { book, segments, note: None }),
+        }
+    }
+    if refs.is_empty() {
+        return Err(err());
+    }
+    if let Some(last) = refs.last_mut() {
+        last.note = note;
+    }
+    Ok(refs)
+}
+
+/// Split "1 Cor 9:24-27" into the book name and the passage
+fn split_book(part: &str) -> (Option<&str>, &str) {
+    let mut seen_letter = false;
+    for (i, c) in part.char_indices() {
+        if c.is_alphabetic() {
+            seen_letter = true;
+        } else if c.is_ascii_digit() && seen_letter {
+            return (Some(part[..i].trim()), &part[i..]);
+        }
+    }
+    if seen_letter {
+        (Some(part.trim()), "")
+    } else {
+        (None, part)
+    }
+}
+
+/// Parse "9:24-27, 30", "18:1–19:42" or "22" into segments
+fn parse_segments(passage: &str) -> Option<Vec<Segment>> {
+    let mut chapter: Option<u16> = None;
+    let mut out = Vec::new();
+    for piece in passage.split(',').map(str::trim) {
+        let (from, to) = match piece.split_once(['-', '–', '—']) {
+            Some((a, b)) => (a.trim(), Some(b.trim())),
+            None => (piece, None),
+        };
+        let start = parse_verse(from, chapter)?;
+        let end = match to {
+            Some(to) => {
+                // "24-27" stays in the chapter; "1–19:42" crosses into another
+                let within = if start.verse.is_some() { Some(start.chapter) } else { None };
+                parse_verse(to, within)?
+            }
+            None => start,
+        };
+        chapter = Some(end.chapter);
+        out.push(Segment { start, end });
+    }
+    Some(out)
+}
+
+/// "3:16", or a bare number read as a verse when a chapter is current and
+/// as a chapter otherwise. Verse-part letters ("5a") are dropped.
+fn parse_verse(text: &str, chapter: Option<u16>) -> Option<Verse> {
+    let number = |s: &str| s.trim().trim_end_matches(|c: char| c.is_ascii_lowercase()).parse::<u16>().ok();
+    match text.split_once(':') {
+        Some((c, v)) => Some(Verse { chapter: number(c)?, verse: Some(number(v)?) }),
+        None => match chapter {
+            Some(chapter) => Some(Verse { chapter, verse: Some(number(text)?) }),
+            None => Some(Verse { chapter: number(text)?, verse: None }),
+        },
+    }
+}
+
+impl FromStr for ScriptureRef {
+    type Err = ParseRefError;
+
+    /// Parse a reference into a single book
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut refs = parse_all(s)?;
+        if refs.len() != 1 {
+            return Err(ParseRefError(s.to_string()));
+        }
+        Ok(refs.remove(0))
+    }
+}
+
+impl ScriptureRef {
+    /// Render with the given book naming, e.g. "Isaias 60:1-6" or "Isa 60:1-6"
+    pub fn render(&self, style: BookStyle) -> String {
+        let name = match style {
+            BookStyle::Douay => self.book.douay(),
+            BookStyle::Modern => self.book.modern(),
+        };
+        let mut out = format!("{name} ");
+        let mut chapter: Option<u16> = None;
+        for (i, seg) in self.segments.iter().enumerate() {
+            if i > 0 {
+                out.push_str(if chapter == Some(seg.start.chapter) && seg.start.verse.is_some() { ", " } else { "; " });
+            }
+            out.push_str(&match seg.start.verse {
+                Some(v) if chapter == Some(seg.start.chapter) => v.to_string(),
+                Some(v) => format!("{}:{}", seg.start.chapter, v),
+                None => seg.start.chapter.to_string(),
+            });
+            if seg.end != seg.start {
+                out.push_str(&match (seg.end.verse, seg.end.chapter == seg.start.chapter) {
+                    (Some(v), true) => format!("-{v}"),
+                    (Some(v), false) => format!("–{}:{}", seg.end.chapter, v),
+                    (None, _) => format!("-{}", seg.end.chapter),
+                });
+            }
+            chapter = Some(seg.end.chapter);
+        }
+        if let Some(note) = &self.note {
+            out.push_str(&format!(" ({note})"));
+        }
+        out
+    }
+
+    /// Renumber Psalms from the Vulgate/LXX to the Hebrew numbering
+    pub fn to_hebrew_psalms(&self) -> ScriptureRef {
+        self.map_psalms(psalm_vulgate_to_hebrew)
+    }
+
+    /// Renumber Psalms from the Hebrew to the Vulgate/LXX numbering
+    pub fn to_vulgate_psalms(&self) -> ScriptureRef {
+        self.map_psalms(psalm_hebrew_to_vulgate)
+    }
+
+    fn map_psalms(&self, f: fn(u16, u16) -> (u16, u16)) -> ScriptureRef {
+        if self.book != Book::Psalms {
+            return self.clone();
+        }
+        let map = |v: Verse| {
+            let (chapter, verse) = f(v.chapter, v.verse.unwrap_or(1));
+            Verse { chapter, verse: v.verse.map(|_| verse) }
+        };
+        ScriptureRef {
+            segments: self.segments.iter().map(|s| Segment { start: map(s.start), end: map(s.end) }).collect(),
+            ..self.clone()
+        }
+    }
+}
+
+impl fmt::Display for ScriptureRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(BookStyle::Douay))
+    }
+}
+
+/// Map a Vulgate psalm and verse to the Hebrew numbering. Psalms 9 and 113
+/// are each two psalms in the Hebrew; 114-115 and 146-147 are one.
+pub fn psalm_vulgate_to_hebrew(psalm: u16, verse: u16) -> (u16, u16) {
+    match psalm {
+        9 if verse >= 22 => (10, verse - 21),
+        113 if verse >= 9 => (115, verse - 8),
+        113 => (114, verse),
+        114 => (116, verse),
+        115 => (116, verse + 9),
+        146 => (147, verse),
+        147 => (147, verse + 11),
+        10..=112 | 116..=145 => (psalm + 1, verse),
+        _ => (psalm, verse),
+    }
+}
+
+/// Inverse of [`psalm_vulgate_to_hebrew`]
+pub fn psalm_hebrew_to_vulgate(psalm: u16, verse: u16) -> (u16, u16) {
+    match psalm {
+        10 => (9, verse + 21),
+        114 => (113, verse),
+        115 => (113, verse + 8),
+        116 if verse >= 10 => (115, verse - 9),
+        116 => (114, verse),
+        147 if verse >= 12 => (147, verse - 11),
+        147 => (146, verse),
+        11..=113 | 117..=146 => (psalm - 1, verse),
+        _ => (psalm, verse),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_book_names() {
+        assert_eq!(Book::from_name("Isaias"), Some(Book::Isaiah));
+        assert_eq!(Book::from_name("Isa."), Some(Book::Isaiah));
+        assert_eq!(Book::from_name("3 Kings"), Some(Book::Kings1));
+        assert_eq!(Book::from_name("1Kgs"), Some(Book::Kings1));
+        assert_eq!(Book::from_name("I Cor"), Some(Book::Corinthians1));
+        assert_eq!(Book::from_name("Ecclus"), Some(Book::Sirach));
+        assert_eq!(Book::from_name("Apoc"), Some(Book::Revelation));
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let r: ScriptureRef = "Ps 97:3-4, 2".parse().unwrap();
+        assert_eq!(r.segments.len(), 2);
+        assert_eq!(r.segments[1].start, Verse { chapter: 97, verse: Some(2) });
+        assert_eq!(r.to_string(), "Ps 97:3-4, 2");
+
+        let r: ScriptureRef = "1 Cor 9:24-27; 10:1-5".parse().unwrap();
+        assert_eq!(r.segments[1].start.chapter, 10);
+        assert_eq!(r.to_string(), "1 Cor 9:24-27; 10:1-5");
+
+        let r: ScriptureRef = "John 18:1–19:42 (Passion)".parse().unwrap();
+        assert_eq!(r.segments[0].end, Verse { chapter: 19, verse: Some(42) });
+        assert_eq!(r.note.as_deref(), Some("Passion"));
+        assert_eq!(r.render(BookStyle::Douay), "John 18:1–19:42 (Passion)");
+
+        let r: ScriptureRef = "Isaias 60:1-6".parse().unwrap();
+        assert_eq!(r.render(BookStyle::Modern), "Isa 60:1-6");
+
+        let all = parse_all("Matt 28:7; John 20:26").unwrap();
+        assert_eq!(all.iter().map(|r| r.book).collect::<Vec<_>>(), vec![Book::Matthew, Book::John]);
+        assert!("Victimae paschali laudes".parse::<ScriptureRef>().is_err());
+    }
+
+    #[test]
+    fn test_psalm_numbering() {
+        let r: ScriptureRef = "Ps 22:1-4".parse().unwrap();
+        assert_eq!(r.to_hebrew_psalms().to_string(), "Ps 23:1-4");
+        assert_eq!(psalm_vulgate_to_hebrew(9, 22), (10, 1));
+        assert_eq!(psalm_vulgate_to_hebrew(115, 1), (116, 10));
+        assert_eq!(psalm_vulgate_to_hebrew(147, 1), (147, 12));
+        for (p, v) in [(9, 30), (50, 3), (113, 12), (114, 2), (146, 5), (150, 1)] {
+            let (hp, hv) = psalm_vulgate_to_hebrew(p, v);
+            assert_eq!(psalm_hebrew_to_vulgate(hp, hv), (p, v));
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::types::MoveableFeasts;
+
+/// Stations from Septuagesima to the Octave of Pentecost, by days from Easter
+const EASTER_CYCLE: &[(i64, &str)] = &[
+    (-63, "S. Laurentius extra muros"),
+    (-56, "S. Paulus"),
+    (-49, "S. Petrus"),
+    // Ash Wednesday and the days after
+    (-46, "S. Sabina"),
+    (-45, "S. Georgius"),
+    (-44, "Ss. Joannes et Paulus"),
+    (-43, "S. Augustinus"),
+    // First week of Lent
+    (-42, "S. Joannes in Laterano"),
+    (-41, "S. Petrus ad Vincula"),
+    (-40, "S. Anastasia"),
+    (-39, "S. Maria Major"),
+    (-38, "S. Laurentius in Panisperna"),
+    (-37, "Ss. XII Apostoli"),
+    (-36, "S. Petrus"),
+    // Second week
+    (-35, "S. Maria in Domnica"),
+    (-34, "S. Clemens"),
+    (-33, "S. Balbina"),
+    (-32, "S. Caecilia"),
+    (-31, "S. Maria trans Tiberim"),
+    (-30, "S. Vitalis"),
+    (-29, "Ss. Marcellinus et Petrus"),
+    // Third week
+    (-28, "S. Laurentius extra muros"),
+    (-27, "S. Marcus"),
+    (-26, "S. Pudentiana"),
+    (-25, "S. Xystus"),
+    (-24, "Ss. Cosmas et Damianus"),
+    (-23, "S. Laurentius in Lucina"),
+    (-22, "S. Susanna"),
+    // Fourth week
+    (-21, "S. Crux in Jerusalem"),
+    (-20, "Ss. Quatuor Coronati"),
+    (-19, "S. Laurentius in Damaso"),
+    (-18, "S. Paulus"),
+    (-17, "Ss. Silvester et Martinus"),
+    (-16, "S. Eusebius"),
+    (-15, "S. Nicolaus in Carcere"),
+    // Passion week
+    (-14, "S. Petrus"),
+    (-13, "S. Chrysogonus"),
+    (-12, "S. Cyriacus"),
+    (-11, "S. Marcellus"),
+    (-10, "S. Apollinaris"),
+    (-9, "S. Stephanus in Monte Caelio"),
+    (-8, "S. Joannes ante Portam Latinam"),
+    // Holy Week
+    (-7, "S. Joannes in Laterano"),
+    (-6, "S. Praxedes"),
+    (-5, "S. Prisca"),
+    (-4, "S. Maria Major"),
+    (-3, "S. Joannes in Laterano"),
+    (-2, "S. Crux in Jerusalem"),
+    (-1, "S. Joannes in Laterano"),
+    // Easter Octave
+    (0, "S. Maria Major"),
+    (1, "S. Petrus"),
+    (2, "S. Paulus"),
+    (3, "S. Laurentius extra muros"),
+    (4, "Ss. XII Apostoli"),
+    (5, "S. Maria ad Martyres"),
+    (6, "S. Joannes in Laterano"),
+    (7, "S. Pancratius"),
+    // Lesser Litanies and Ascension
+    (36, "S. Maria Major"),
+    (37, "S. Joannes in Laterano"),
+    (38, "S. Petrus"),
+    (39, "S. Petrus"),
+    // Vigil and Octave of Pentecost
+    (48, "S. Joannes in Laterano"),
+    (49, "S. Petrus"),
+    (50, "S. Petrus ad Vincula"),
+    (51, "S. Anastasia"),
+    (52, "S. Maria Major"),
+    (53, "S. Laurentius extra muros"),
+    (54, "Ss. XII Apostoli"),
+    (55, "S. Petrus"),
+];
+
+/// The Roman stational church of the day, as marked in the Missal
+/// ("Statio ad S. Sabinam" is given as "S. Sabina").
+///
+/// Stations belong to the day rather than to its Mass, so a feast kept on
+/// a Lenten feria does not remove the station.
+pub fn station(date: NaiveDate, mf: &MoveableFeasts) -> Option<&'static str> {
+    let fixed = match (date.month(), date.day()) {
+        (12, 24) | (12, 25) | (12, 27) => Some("S. Maria Major"),
+        (12, 26) => Some("S. Stephanus in Monte Caelio"),
+        (12, 28) => Some("S. Paulus"),
+        (1, 1) => Some("S. Maria trans Tiberim"),
+        (1, 6) => Some("S. Petrus"),
+        _ => None,
+    };
+    if fixed.is_some() {
+        return fixed;
+    }
+
+    let from_easter = (date - mf.easter).num_days();
+    if let Some((_, church)) = EASTER_CYCLE.iter().find(|(offset, _)| *offset == from_easter) {
+        return Some(church);
+    }
+    // The Greater Litanies, unless in the Easter Octave
+    if (date.month(), date.day()) == (4, 25) {
+        return Some("S. Petrus");
+    }
+
+    // Ember days of September and Advent
+    if mf.ember_days.contains(&date) {
+        return match date.weekday() {
+            Weekday::Wed => Some("S. Maria Major"),
+            Weekday::Fri => Some("Ss. XII Apostoli"),
+            _ => Some("S. Petrus"),
+        };
+    }
+    match (date - mf.advent_1).num_days() {
+        0 => Some("S. Maria Major"),
+        7 => Some("S. Crux in Jerusalem"),
+        14 => Some("S. Petrus"),
+        21 => Some("Ss. XII Apostoli"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::computus::moveable_feasts;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_lenten_stations() {
+        let mf = moveable_feasts(2026);
+        assert_eq!(station(mf.ash_wednesday, &mf), Some("S. Sabina"));
+        assert_eq!(station(date(3, 15), &mf), Some("S. Crux in Jerusalem"));
+        assert_eq!(station(mf.good_friday, &mf), Some("S. Crux in Jerusalem"));
+        assert_eq!(station(mf.easter, &mf), Some("S. Maria Major"));
+    }
+
+    #[test]
+    fn test_ember_and_advent_stations() {
+        let mf = moveable_feasts(2026);
+        // September Ember Saturday, after the third Sunday of September
+        assert_eq!(station(date(9, 26), &mf), Some("S. Petrus"));
+        assert_eq!(station(mf.advent_1, &mf), Some("S. Maria Major"));
+        assert_eq!(station(date(12, 25), &mf), Some("S. Maria Major"));
+        // An ordinary day after Pentecost has no station
+        assert_eq!(station(date(7, 14), &mf), None);
+    }
+}
@@ -2,6 +2,7 @@ use chrono::{Datelike, Duration, NaiveDate, Weekday};
 use std::collections::BTreeMap;
 
 use crate::computus::moveable_feasts;
+use crate::holy_week;
 use crate::types::*;
 
 /// Season and week assignment for a date
@@ -11,9 +12,18 @@ pub struct TemporalEntry {
     pub week: u8,
 }
 
-/// Build the temporal cycle for a given year.
+/// Build the temporal cycle for a given year under the 1962 rubrics.
 /// Returns a map from date -> (season, week, optional special celebration).
 pub fn build_temporal_cycle(year: i32) -> BTreeMap<NaiveDate, (TemporalEntry, Option<Celebration>)> {
+    build_temporal_cycle_for(year, RubricalSystem::default())
+}
+
+/// Build the temporal cycle for a given year under a rubrical system.
+/// Holy Week titles follow the system's Ordo.
+pub fn build_temporal_cycle_for(
+    year: i32,
+    system: RubricalSystem,
+) -> BTreeMap<NaiveDate, (TemporalEntry, Option<Celebration>)> {
     let mf = moveable_feasts(year);
     let prev_mf = moveable_feasts(year - 1);
     let mut map = BTreeMap::new();
@@ -28,7 +38,7 @@ pub fn build_temporal_cycle(year: i32) -> BTreeMap<NaiveDate, (TemporalEntry, Op
 
     let mut date = jan1;
     while date <= dec31 {
-        let (entry, special) = classify_date(date, year, &mf, &prev_mf);
+        let (entry, special) = classify_date(date, year, &mf, &prev_mf, system);
         map.insert(date, (entry, special));
         date += Duration::days(1);
     }
@@ -41,12 +51,13 @@ fn classify_date(
     year: i32,
     mf: &MoveableFeasts,
     _prev_mf: &MoveableFeasts,
+    system: RubricalSystem,
 ) -> (TemporalEntry, Option<Celebration>) {
     let epiphany = NaiveDate::from_ymd_opt(year, 1, 6).unwrap();
     let christmas = NaiveDate::from_ymd_opt(year, 12, 25).unwrap();
 
     // Special fixed celebrations first
-    let special = classify_special(date, year, mf);
+    let special = classify_special(date, year, mf, system);
 
     // Season assignment
     let entry = if date < epiphany {
@@ -140,17 +151,17 @@ fn classify_special(
     date: NaiveDate,
     _year: i32,
     mf: &MoveableFeasts,
+    system: RubricalSystem,
 ) -> Option<Celebration> {
     // Easter
     if date == mf.easter {
         return Some(Celebration::new(
             "easter-sunday",
-            "Dominica Resurrectionis",
+            "Domínica Resurrectiónis",
             "Easter Sunday",
             CelebrationRank::ClassI,
             CelebrationCategory::Solemnity,
             LiturgicalColor::White,
-            1,
         ));
     }
 
@@ -163,7 +174,6 @@ fn classify_special(
             CelebrationRank::ClassI,
             CelebrationCategory::WithinOctave,
             LiturgicalColor::White,
-            1,
         ));
     }
 
@@ -171,12 +181,11 @@ fn classify_special(
     if date == mf.easter + Duration::days(7) {
         return Some(Celebration::new(
             "low-sunday",
-            "Dominica in Albis",
+            "Domínica in Albis",
             "Low Sunday (Octave Day of Easter)",
             CelebrationRank::ClassI,
             CelebrationCategory::OctaveDay,
             LiturgicalColor::White,
-            1,
         ));
     }
 
@@ -184,64 +193,63 @@ fn classify_special(
     if date == mf.ash_wednesday {
         return Some(Celebration::new(
             "ash-wednesday",
-            "Feria IV Cinerum",
+            "Féria IV Cínerum",
             "Ash Wednesday",
             CelebrationRank::ClassI,
             CelebrationCategory::Feria,
             LiturgicalColor::Violet,
-            3,
         ));
     }
 
     // Palm Sunday
     if date == mf.palm_sunday {
+        let (title, title_en) = holy_week::titles("palm-sunday", system).unwrap();
         return Some(Celebration::new(
             "palm-sunday",
-            "Dominica in Palmis",
-            "Palm Sunday",
+            title,
+            title_en,
             CelebrationRank::ClassI,
             CelebrationCategory::Sunday,
             LiturgicalColor::Violet,
-            2,
         ));
     }
 
     // Holy Thursday
     if date == mf.holy_thursday {
+        let (title, title_en) = holy_week::titles("holy-thursday", system).unwrap();
         return Some(Celebration::new(
             "holy-thursday",
-            "Feria V in Cena Domini",
-            "Holy Thursday",
+            title,
+            title_en,
             CelebrationRank::ClassI,
             CelebrationCategory::Solemnity,
             LiturgicalColor::White,
-            1,
         ));
     }
 
     // Good Friday
     if date == mf.good_friday {
+        let (title, title_en) = holy_week::titles("good-friday", system).unwrap();
         return Some(Celebration::new(
             "good-friday",
-            "Feria VI in Parasceve",
-            "Good Friday",
+            title,
+            title_en,
             CelebrationRank::ClassI,
             CelebrationCategory::Solemnity,
             LiturgicalColor::Black,
-            1,
         ));
     }
 
     // Holy Saturday
     if date == mf.holy_saturday {
+        let (title, title_en) = holy_week::titles("holy-saturday", system).unwrap();
         return Some(Celebration::new(
             "holy-saturday",
-            "Sabbato Sancto",
-            "Holy Saturday",
+            title,
+            title_en,
             CelebrationRank::ClassI,
             CelebrationCategory::Solemnity,
             LiturgicalColor::Violet,
-            1,
         ));
     }
 
@@ -249,12 +257,11 @@ fn classify_special(
     if date == mf.ascension {
         return Some(Celebration::new(
             "ascension",
-            "In Ascensione Domini",
+            "In Ascensióne Dómini",
             "The Ascension of Our Lord",
             CelebrationRank::ClassI,
             CelebrationCategory::FeastOfLord,
             LiturgicalColor::White,
-            1,
         ));
     }
 
@@ -262,12 +269,11 @@ fn classify_special(
     if date == mf.pentecost {
         return Some(Celebration::new(
             "pentecost",
-            "Dominica Pentecostes",
+            "Domínica Pentecóstes",
             "Pentecost Sunday",
             CelebrationRank::ClassI,
             CelebrationCategory::Solemnity,
             LiturgicalColor::Red,
-            1,
         ));
     }
 
@@ -280,7 +286,18 @@ fn classify_special(
             CelebrationRank::ClassI,
             CelebrationCategory::WithinOctave,
             LiturgicalColor::Red,
-            1,
+        ));
+    }
+
+    // Trinity Sunday (1st Sunday after Pentecost)
+    if date == mf.pentecost + Duration::days(7) {
+        return Some(Celebration::new(
+            "trinity-sunday",
+            "Ss.mae Trinitátis",
+            "The Most Holy Trinity",
+            CelebrationRank::ClassI,
+            CelebrationCategory::FeastOfLord,
+            LiturgicalColor::White,
         ));
     }
 
@@ -288,12 +305,11 @@ fn classify_special(
     if date == mf.corpus_christi {
         return Some(Celebration::new(
             "corpus-christi",
-            "Ss.mi Corporis Christi",
+            "Ss.mi Córporis Christi",
             "Corpus Christi",
             CelebrationRank::ClassI,
             CelebrationCategory::FeastOfLord,
             LiturgicalColor::White,
-            1,
         ));
     }
 
@@ -306,7 +322,6 @@ fn classify_special(
             CelebrationRank::ClassI,
             CelebrationCategory::FeastOfLord,
             LiturgicalColor::White,
-            4,
         ));
     }
 
@@ -319,7 +334,6 @@ fn classify_special(
             CelebrationRank::ClassI,
             CelebrationCategory::FeastOfLord,
             LiturgicalColor::White,
-            4,
         ));
     }
 
@@ -332,7 +346,6 @@ fn classify_special(
             CelebrationRank::FeriaPrivileged,
             CelebrationCategory::EmberDay,
             LiturgicalColor::Violet,
-            8,
         ));
     }
 
@@ -345,7 +358,6 @@ fn classify_special(
             CelebrationRank::ClassIV,
             CelebrationCategory::RogationDay,
             LiturgicalColor::Violet,
-            11,
         ));
     }
 
@@ -357,12 +369,11 @@ fn classify_special(
     {
         return Some(Celebration::new(
             "last-sunday-after-pentecost",
-            "Dominica Ultima post Pentecosten",
+            "Domínica Última post Pentecósten",
             "Last Sunday after Pentecost",
             CelebrationRank::ClassI,
             CelebrationCategory::Sunday,
             LiturgicalColor::Green,
-            2,
         ));
     }
 
@@ -435,6 +446,18 @@ mod tests {
         assert_eq!(special.as_ref().unwrap().id, "easter-sunday");
     }
 
+    #[test]
+    fn test_holy_week_titles_follow_system() {
+        let good_friday = NaiveDate::from_ymd_opt(2026, 4, 3).unwrap();
+        let cycle = build_temporal_cycle_for(2026, RubricalSystem::PrePius);
+        assert_eq!(cycle[&good_friday].1.as_ref().unwrap().title, "Féria VI in Parascéve");
+        let cycle = build_temporal_cycle(2026);
+        assert_eq!(
+            cycle[&good_friday].1.as_ref().unwrap().title,
+            "Féria VI in Passióne et Morte Dómini"
+        );
+    }
+
     #[test]
     fn test_christmas_season_dec() {
         let cycle = build_temporal_cycle(2026);
@@ -452,4 +475,19 @@ mod tests {
         assert_eq!(entry.season, LiturgicalSeason::Advent);
         assert_eq!(entry.week, 1);
     }
+
+    #[test]
+    fn test_latin_titles_of_generated_days() {
+        let sunday = Celebration::sunday(LiturgicalSeason::AfterPentecost, 6);
+        assert_eq!(sunday.title, "Domínica V post Pentecósten");
+        assert_eq!(sunday.title_vernacular.as_deref(), Some("5th Sunday of the Time after Pentecost"));
+        let feria = Celebration::feria(LiturgicalSeason::Lent, 2, Weekday::Tue);
+        assert_eq!(feria.title, "Féria III infra Hebdómadam II Quadragésimae");
+        let cycle = build_temporal_cycle(2026);
+        let quinquagesima = NaiveDate::from_ymd_opt(2026, 2, 15).unwrap();
+        assert_eq!(cycle[&quinquagesima].1.as_ref().unwrap().title, "Domínica in Quinquagésima");
+        let after_ash = Celebration::feria(LiturgicalSeason::Lent, 0, Weekday::Sat);
+        assert_eq!(after_ash.title, "Sábbato post Cíneres");
+        assert_eq!(Celebration::sunday(LiturgicalSeason::Easter, 4).title, "Domínica III post Pascha");
+    }
 }
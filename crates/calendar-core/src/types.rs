@@ -1,21 +1,18 @@
 use chrono::{NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
 
+use crate::scripture::{self, ScriptureRef};
+
 /// Rubrical system selector
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RubricalSystem {
+    #[default]
     Rubrics1962,
     Rubrics1955,
     PrePius,
 }
 
-impl Default for RubricalSystem {
-    fn default() -> Self {
-        Self::Rubrics1962
-    }
-}
-
 /// Liturgical seasons in the traditional Roman calendar
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -99,8 +96,6 @@ pub struct Celebration {
     pub rank: CelebrationRank,
     pub category: CelebrationCategory,
     pub color: LiturgicalColor,
-    /// Precedence number in the 1962 table (1-11, lower wins)
-    pub precedence: u8,
 }
 
 impl Celebration {
@@ -111,7 +106,6 @@ impl Celebration {
         rank: CelebrationRank,
         category: CelebrationCategory,
         color: LiturgicalColor,
-        precedence: u8,
     ) -> Self {
         Self {
             id: id.into(),
@@ -120,101 +114,104 @@ impl Celebration {
             rank,
             category,
             color,
-            precedence,
         }
     }
 
     pub fn feria(season: LiturgicalSeason, week: u8, day: Weekday) -> Self {
-        let (color, rank, precedence) = match season {
-            LiturgicalSeason::Advent => (LiturgicalColor::Violet, CelebrationRank::FeriaPrivileged, 8),
+        let (color, rank) = match season {
+            LiturgicalSeason::Advent => (LiturgicalColor::Violet, CelebrationRank::FeriaPrivileged),
             LiturgicalSeason::Lent | LiturgicalSeason::Passiontide => {
-                (LiturgicalColor::Violet, CelebrationRank::FeriaPrivileged, 8)
+                (LiturgicalColor::Violet, CelebrationRank::FeriaPrivileged)
             }
-            LiturgicalSeason::HolyWeek => (LiturgicalColor::Violet, CelebrationRank::ClassI, 3),
+            LiturgicalSeason::HolyWeek => (LiturgicalColor::Violet, CelebrationRank::ClassI),
             LiturgicalSeason::Christmas | LiturgicalSeason::AfterEpiphany => {
-                (LiturgicalColor::White, CelebrationRank::Feria, 11)
+                (LiturgicalColor::White, CelebrationRank::Feria)
             }
-            LiturgicalSeason::Septuagesima => (LiturgicalColor::Violet, CelebrationRank::Feria, 11),
+            LiturgicalSeason::Septuagesima => (LiturgicalColor::Violet, CelebrationRank::Feria),
             LiturgicalSeason::Easter | LiturgicalSeason::Ascensiontide => {
-                (LiturgicalColor::White, CelebrationRank::Feria, 11)
+                (LiturgicalColor::White, CelebrationRank::Feria)
             }
-            LiturgicalSeason::AfterPentecost => (LiturgicalColor::Green, CelebrationRank::Feria, 11),
+            LiturgicalSeason::AfterPentecost => (LiturgicalColor::Green, CelebrationRank::Feria),
         };
         let day_name = format!("{:?}", day);
         let id = format!("feria-{}-week-{}-{}", season_id(season), week, day_name.to_lowercase());
-        let title = format!("Feria {} of {} Week {}", day_name, season_name(season), week);
+        let title_en = format!("Feria {} of {} Week {}", day_name, season_name(season), week);
         Self {
             id,
-            title: title.clone(),
-            title_vernacular: Some(title),
+            title: latin_feria_title(season, week, day),
+            title_vernacular: Some(title_en),
             rank,
             category: CelebrationCategory::Feria,
             color,
-            precedence,
         }
     }
 
     pub fn sunday(season: LiturgicalSeason, week: u8) -> Self {
-        let (color, rank, precedence) = match season {
+        let (color, rank) = match season {
             LiturgicalSeason::Advent => {
                 if week == 1 {
-                    (LiturgicalColor::Violet, CelebrationRank::ClassI, 2)
+                    (LiturgicalColor::Violet, CelebrationRank::ClassI)
                 } else if week == 3 {
                     // Gaudete Sunday
-                    (LiturgicalColor::Rose, CelebrationRank::ClassI, 6)
+                    (LiturgicalColor::Rose, CelebrationRank::ClassI)
                 } else {
-                    (LiturgicalColor::Violet, CelebrationRank::ClassI, 6)
+                    (LiturgicalColor::Violet, CelebrationRank::ClassI)
                 }
             }
             LiturgicalSeason::Christmas | LiturgicalSeason::AfterEpiphany => {
-                (LiturgicalColor::White, CelebrationRank::ClassII, 6)
+                (LiturgicalColor::White, CelebrationRank::ClassII)
             }
             LiturgicalSeason::Septuagesima => {
-                (LiturgicalColor::Violet, CelebrationRank::ClassII, 6)
+                (LiturgicalColor::Violet, CelebrationRank::ClassII)
             }
             LiturgicalSeason::Lent => {
                 if week == 1 {
-                    (LiturgicalColor::Violet, CelebrationRank::ClassI, 2)
+                    (LiturgicalColor::Violet, CelebrationRank::ClassI)
                 } else if week == 4 {
                     // Laetare Sunday
-                    (LiturgicalColor::Rose, CelebrationRank::ClassI, 6)
+                    (LiturgicalColor::Rose, CelebrationRank::ClassI)
                 } else {
-                    (LiturgicalColor::Violet, CelebrationRank::ClassI, 6)
+                    (LiturgicalColor::Violet, CelebrationRank::ClassI)
                 }
             }
             LiturgicalSeason::Passiontide => {
                 // Passion Sunday = week 1 of Passiontide
-                (LiturgicalColor::Violet, CelebrationRank::ClassI, 2)
+                (LiturgicalColor::Violet, CelebrationRank::ClassI)
             }
             LiturgicalSeason::HolyWeek => {
                 // Palm Sunday
-                (LiturgicalColor::Violet, CelebrationRank::ClassI, 2)
+                (LiturgicalColor::Violet, CelebrationRank::ClassI)
             }
             LiturgicalSeason::Easter => {
                 if week == 1 {
                     // Easter Sunday itself handled separately
-                    (LiturgicalColor::White, CelebrationRank::ClassI, 1)
+                    (LiturgicalColor::White, CelebrationRank::ClassI)
                 } else {
-                    (LiturgicalColor::White, CelebrationRank::ClassII, 6)
+                    (LiturgicalColor::White, CelebrationRank::ClassII)
                 }
             }
             LiturgicalSeason::Ascensiontide => {
-                (LiturgicalColor::White, CelebrationRank::ClassII, 6)
+                (LiturgicalColor::White, CelebrationRank::ClassII)
             }
             LiturgicalSeason::AfterPentecost => {
-                (LiturgicalColor::Green, CelebrationRank::ClassII, 6)
+                (LiturgicalColor::Green, CelebrationRank::ClassII)
             }
         };
         let id = format!("sunday-{}-{}", season_id(season), week);
-        let title = format!("{} Sunday of {}", ordinal(week), season_name(season));
+        // Ids count the Octave of Pentecost as the 1st week after Pentecost;
+        // the Missal numbers its Sundays from the following one
+        let number = match season {
+            LiturgicalSeason::AfterPentecost => week.saturating_sub(1),
+            _ => week,
+        };
+        let title_en = format!("{} Sunday of {}", ordinal(number), season_name(season));
         Self {
             id,
-            title: title.clone(),
-            title_vernacular: Some(title),
+            title: latin_sunday_title(season, week),
+            title_vernacular: Some(title_en),
             rank,
             category: CelebrationCategory::Sunday,
             color,
-            precedence,
         }
     }
 }
@@ -226,8 +223,15 @@ pub struct LiturgicalDay {
     pub season: LiturgicalSeason,
     pub week: u8,
     pub day_of_week: String,
+    /// Roman date with its ferial name and year, e.g. "Feria IV, IV Nonas
+    /// Martii, Anno Domini MMXXVI"; set only in Latin output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_latin: Option<String>,
     pub celebration: Celebration,
     pub commemorations: Vec<Celebration>,
+    /// Other Masses the rubrics permit in place of the celebration
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<Celebration>,
     pub color: LiturgicalColor,
     /// Optional reading references (Epistle, Gospel, etc.)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -235,19 +239,450 @@ pub struct LiturgicalDay {
     /// Optional special notes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
+    /// Holy Week ceremonies under the calendar's rubrical system
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rite: Option<HolyWeekRite>,
+    /// Chants between the Epistle and the Gospel, by season and rubrics
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chants: Vec<Chant>,
+    /// Roman stational church, e.g. "S. Sabina" on Ash Wednesday
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub station: Option<String>,
+    /// Gloria, Credo and Preface; `None` when no Mass is said (Good Friday)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ordinary: Option<Ordinary>,
+    /// Anniversary collects and orationes imperatae added after the commemorations
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub orations: Vec<AddedOration>,
+    /// How precedence was decided for this day
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<PrecedenceTrace>,
+}
+
+/// What became of a candidate celebration on a given day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Celebrated,
+    Commemorated,
+    Omitted,
+    Transferred,
+}
+
+/// One candidate considered by the precedence resolver
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceCandidate {
+    pub id: String,
+    pub title: String,
+    pub rank: CelebrationRank,
+    /// Row of the table of precedence the candidate matched
+    pub precedence: u8,
+    pub row: String,
+    pub outcome: Outcome,
+    pub reason: String,
+    /// Rubricae Generales number the decision rests on, e.g. "RG 91"
+    pub citation: String,
+}
+
+/// Whose Vespers are said when two offices concur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VespersOf {
+    /// Second Vespers of the preceding day
+    Preceding,
+    /// First Vespers of the following day
+    Following,
+    /// Of the preceding day until the chapter, then of the following
+    SplitAtChapter,
+}
+
+/// Outcome of concurrence between the office of a day and the next
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vespers {
+    pub of: VespersOf,
+    /// Id of the celebration whose Vespers are said
+    pub celebration: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commemoration: Option<String>,
+    pub citation: String,
+}
+
+/// Decision trace of the precedence resolver, winner first
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrecedenceTrace {
+    pub candidates: Vec<TraceCandidate>,
+}
+
+/// Ceremonies of a Holy Week day, which differ between rubrical systems
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HolyWeekRite {
+    /// Ceremonies in the order they are performed
+    pub ceremonies: Vec<String>,
+    /// When the principal service is held
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timing: Option<String>,
+    /// Lessons (prophecies) of the Easter Vigil
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lessons: Vec<String>,
+}
+
+/// Kind of oration added to the Mass by local authority or anniversary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrationKind {
+    PopeAnniversary,
+    BishopAnniversary,
+    Imperata,
+}
+
+/// An oration said after the collect and commemorations of the day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddedOration {
+    pub kind: OrationKind,
+    pub title: String,
+    pub title_vernacular: Option<String>,
+    /// Texts of an imperata from the registry; those of the anniversaries
+    /// are fixed in the Missal (`propers`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collect: Option<TextPair>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<TextPair>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postcommunion: Option<TextPair>,
+}
+
+/// Kind of a reading or chant in the Mass of the Catechumens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadingKind {
+    /// A lesson from the Old Testament or Acts
+    Lesson,
+    Epistle,
+    Gradual,
+    Tract,
+    Alleluia,
+    Sequence,
+    Gospel,
+}
+
+impl ReadingKind {
+    pub fn is_chant(&self) -> bool {
+        matches!(
+            self,
+            ReadingKind::Gradual | ReadingKind::Tract | ReadingKind::Alleluia | ReadingKind::Sequence
+        )
+    }
 }
 
-/// Scripture reading references for a liturgical day
+/// One reading or chant, in the order of the Missal
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadingItem {
+    pub kind: ReadingKind,
+    pub reference: String,
+    /// `reference` parsed into books, chapters and verses; empty for
+    /// chants cited by name
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parsed: Vec<ScriptureRef>,
+}
+
+/// Readings and chants of a Mass as an ordered list, so that Masses with
+/// several lessons (Ember days, Good Friday, the Easter Vigil) are
+/// represented as the Missal has them.
+///
+/// Serialized with `items` alongside the single `old_testament`, `epistle`,
+/// `gradual` and `gospel` fields of earlier versions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "ReadingsJson", into = "ReadingsJson")]
 pub struct Readings {
+    pub items: Vec<ReadingItem>,
+}
+
+impl Readings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, kind: ReadingKind, reference: impl Into<String>) -> Self {
+        let reference = reference.into();
+        let parsed = scripture::parse_all(&reference).unwrap_or_default();
+        self.items.push(ReadingItem { kind, reference, parsed });
+        self
+    }
+
+    pub fn lesson(self, reference: impl Into<String>) -> Self {
+        self.with(ReadingKind::Lesson, reference)
+    }
+
+    pub fn epistle(self, reference: impl Into<String>) -> Self {
+        self.with(ReadingKind::Epistle, reference)
+    }
+
+    pub fn gradual(self, reference: impl Into<String>) -> Self {
+        self.with(ReadingKind::Gradual, reference)
+    }
+
+    pub fn tract(self, reference: impl Into<String>) -> Self {
+        self.with(ReadingKind::Tract, reference)
+    }
+
+    pub fn alleluia(self, reference: impl Into<String>) -> Self {
+        self.with(ReadingKind::Alleluia, reference)
+    }
+
+    pub fn sequence(self, reference: impl Into<String>) -> Self {
+        self.with(ReadingKind::Sequence, reference)
+    }
+
+    pub fn gospel(self, reference: impl Into<String>) -> Self {
+        self.with(ReadingKind::Gospel, reference)
+    }
+
+    /// Items of one kind, in order
+    pub fn of_kind(&self, kind: ReadingKind) -> impl Iterator<Item = &ReadingItem> {
+        self.items.iter().filter(move |i| i.kind == kind)
+    }
+
+    /// The reading in the place of the Epistle: the last lesson or epistle
+    /// before the Gospel
+    pub fn epistle_reference(&self) -> Option<&str> {
+        self.items
+            .iter()
+            .rev()
+            .find(|i| matches!(i.kind, ReadingKind::Lesson | ReadingKind::Epistle))
+            .map(|i| i.reference.as_str())
+    }
+
+    /// The first lesson, where it precedes the reading in the Epistle's place
+    pub fn first_lesson(&self) -> Option<&str> {
+        let first = self
+            .items
+            .iter()
+            .find(|i| matches!(i.kind, ReadingKind::Lesson | ReadingKind::Epistle))?;
+        if Some(first.reference.as_str()) == self.epistle_reference() {
+            return None;
+        }
+        Some(first.reference.as_str())
+    }
+
+    pub fn gospel_reference(&self) -> Option<&str> {
+        self.of_kind(ReadingKind::Gospel).next().map(|i| i.reference.as_str())
+    }
+
+    /// The first chant between the readings
+    pub fn chant_reference(&self) -> Option<&str> {
+        self.items
+            .iter()
+            .find(|i| i.kind.is_chant() && i.kind != ReadingKind::Sequence)
+            .map(|i| i.reference.as_str())
+    }
+}
+
+/// Form of a chant between the readings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChantForm {
+    Gradual,
+    Alleluia,
+    /// Two Alleluia verses in place of the Gradual, in Paschaltide
+    GreaterAlleluia,
+    Tract,
+    Sequence,
+}
+
+/// A chant the rubrics call for between the Epistle and the Gospel
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Chant {
+    pub form: ChantForm,
+    /// Incipit of a Sequence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Preface of the Mass
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Preface {
+    Common,
+    Trinity,
+    Christmas,
+    Epiphany,
+    Lent,
+    /// Of the Holy Cross, also said in Passiontide
+    Cross,
+    Easter,
+    Ascension,
+    HolySpirit,
+    SacredHeart,
+    ChristTheKing,
+    BlessedVirgin,
+    StJoseph,
+    Apostles,
+    Dead,
+}
+
+/// Whether the Gloria and Credo are said, and which Preface
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ordinary {
+    pub gloria: bool,
+    pub credo: bool,
+    pub preface: Preface,
+}
+
+/// JSON form of `Readings`, keeping the single-reading fields of earlier versions
+#[derive(Serialize, Deserialize)]
+struct ReadingsJson {
+    #[serde(default)]
+    items: Vec<ReadingItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    epistle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gospel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old_testament: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gradual: Option<String>,
+}
+
+impl From<Readings> for ReadingsJson {
+    fn from(r: Readings) -> Self {
+        Self {
+            epistle: r.epistle_reference().map(String::from),
+            gospel: r.gospel_reference().map(String::from),
+            old_testament: r.first_lesson().map(String::from),
+            gradual: r.chant_reference().map(String::from),
+            items: r.items,
+        }
+    }
+}
+
+impl From<ReadingsJson> for Readings {
+    fn from(j: ReadingsJson) -> Self {
+        if !j.items.is_empty() {
+            // Rebuild so that the parsed references are always present
+            return j.items.into_iter().fold(Readings::new(), |r, item| r.with(item.kind, item.reference));
+        }
+        // Documents written before `items` existed
+        let mut r = Readings::new();
+        if let Some(ot) = j.old_testament {
+            r = r.lesson(ot);
+        }
+        if let Some(ep) = j.epistle {
+            r = r.epistle(ep);
+        }
+        if let Some(gr) = j.gradual {
+            r = r.gradual(gr);
+        }
+        if let Some(gos) = j.gospel {
+            r = r.gospel(gos);
+        }
+        r
+    }
+}
+
+/// A liturgical text in Latin with an optional vernacular translation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextPair {
+    /// Scripture or psalm reference, where the text has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    pub latin: String,
+    pub vernacular: Option<String>,
+}
+
+/// The proper texts of a Mass, Introit through Last Gospel
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProperTexts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub introit: Option<TextPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collect: Option<TextPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epistle: Option<TextPair>,
+    /// Gradual, Tract or Alleluia
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gradual: Option<TextPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<TextPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gospel: Option<TextPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offertory: Option<TextPair>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub epistle: Option<String>,
+    pub secret: Option<TextPair>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub gospel: Option<String>,
+    pub communion: Option<TextPair>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub old_testament: Option<String>,
+    pub postcommunion: Option<TextPair>,
+    /// Usually John 1:1-14; `None` where the rubrics omit it
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub gradual: Option<String>,
+    pub last_gospel: Option<TextPair>,
+}
+
+/// Orations of a commemoration or added oration, said after those of the day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommemorationOrations {
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collect: Option<TextPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<TextPair>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postcommunion: Option<TextPair>,
+}
+
+/// A Common of the Saints, from which a feast takes the parts it lacks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Common {
+    /// Commune Confessoris Pontificis (Statuit)
+    ConfessorBishop,
+    /// Commune Doctorum (In medio)
+    Doctors,
+    /// Commune Confessoris non Pontificis (Os justi)
+    Confessor,
+    /// Commune Virginum non Martyrum (Dilexisti)
+    Virgin,
+    /// Commune plurimorum Martyrum (Intret)
+    Martyrs,
+    /// Commune non Virginum, for a holy woman neither virgin nor martyr (Cognovi)
+    HolyWoman,
+}
+
+impl Common {
+    pub fn title(&self) -> &'static str {
+        match self {
+            Common::ConfessorBishop => "Commune Confessoris Pontificis",
+            Common::Doctors => "Commune Doctorum",
+            Common::Confessor => "Commune Confessoris non Pontificis",
+            Common::Virgin => "Commune Virginum non Martyrum",
+            Common::Martyrs => "Commune plurimorum Martyrum",
+            Common::HolyWoman => "Commune non Virginum nec Martyrum",
+        }
+    }
+}
+
+/// A saint's name in the Latin cases the orations of the Commons say it
+/// in, and in English, put for their "N."; for two saints, both names
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaintName {
+    pub nominative: String,
+    pub genitive: String,
+    pub accusative: String,
+    pub ablative: String,
+    pub english: String,
+}
+
+/// Full texts of the Mass of a liturgical day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MassTexts {
+    pub celebration_id: String,
+    /// Common from which parts not proper to the feast are taken
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub common: Option<Common>,
+    pub propers: ProperTexts,
+    /// In the order said after the collect, secret and postcommunion of the day
+    pub commemorations: Vec<CommemorationOrations>,
 }
 
 /// Moveable feast dates for a given year
@@ -271,6 +706,38 @@ pub struct MoveableFeasts {
     pub rogation_days: Vec<NaiveDate>,
 }
 
+/// The tables printed at the front of a Missal or Martyrology for a year
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComputusData {
+    pub year: i32,
+    /// Place of the year in the 19-year lunar cycle, 1 to 19
+    pub golden_number: u8,
+    /// Age of the moon on the eve of the year, 0 to 29, by the Lilian tables
+    pub epact: u8,
+    /// The epact as the calendarium writes it: "*" for 0, "xi", or the
+    /// Arabic "25" that is read as xxvi in the hollow lunations
+    pub epact_label: String,
+    /// One letter, or two in a leap year (the second from the 25th of February)
+    pub dominical_letters: String,
+    /// Place of the year in the 28-year solar cycle, 1 to 28
+    pub solar_cycle: u8,
+    /// Roman indiction, 1 to 15
+    pub indiction: u8,
+    /// The ecclesiastical full moon on or after the 21st of March
+    pub paschal_full_moon: NaiveDate,
+    pub easter: NaiveDate,
+}
+
+/// A season from its first to its last day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeasonRange {
+    pub season: LiturgicalSeason,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// Sundays within the range
+    pub weeks: u8,
+}
+
 // Helper functions
 
 fn season_id(s: LiturgicalSeason) -> &'static str {
@@ -311,3 +778,82 @@ fn ordinal(n: u8) -> String {
         _ => format!("{}th", n),
     }
 }
+
+/// Roman numeral for small positive numbers
+pub(crate) fn roman(n: u32) -> String {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"),
+        (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+    ];
+    let mut n = n;
+    let mut out = String::new();
+    for (value, numeral) in NUMERALS {
+        while n >= value {
+            out.push_str(numeral);
+            n -= value;
+        }
+    }
+    out
+}
+
+/// Title of a Sunday as the Missal gives it, e.g. "Dominica V post Pentecosten"
+fn latin_sunday_title(season: LiturgicalSeason, week: u8) -> String {
+    let n = roman(week as u32);
+    match season {
+        LiturgicalSeason::Advent => format!("Domínica {n} Advéntus"),
+        LiturgicalSeason::Christmas if week == 1 => "Domínica infra Octávam Nativitátis".into(),
+        LiturgicalSeason::Christmas => format!("Domínica {n} post Nativitátem"),
+        LiturgicalSeason::AfterEpiphany => format!("Domínica {n} post Epiphaníam"),
+        LiturgicalSeason::Septuagesima => match week {
+            1 => "Domínica in Septuagésima".into(),
+            2 => "Domínica in Sexagésima".into(),
+            _ => "Domínica in Quinquagésima".into(),
+        },
+        LiturgicalSeason::Lent => format!("Domínica {n} in Quadragésima"),
+        LiturgicalSeason::Passiontide => "Domínica I Passiónis".into(),
+        LiturgicalSeason::HolyWeek => "Domínica II Passiónis seu in Palmis".into(),
+        LiturgicalSeason::Easter => match week {
+            1 => "Domínica Resurrectiónis".into(),
+            2 => "Domínica in Albis".into(),
+            _ => format!("Domínica {} post Pascha", roman(week as u32 - 1)),
+        },
+        LiturgicalSeason::Ascensiontide => "Domínica post Ascensiónem".into(),
+        LiturgicalSeason::AfterPentecost => format!("Domínica {} post Pentecósten", roman(week as u32 - 1)),
+    }
+}
+
+/// Title of a feria, e.g. "Féria III infra Hebdómadam II Quadragésimae"
+fn latin_feria_title(season: LiturgicalSeason, week: u8, day: Weekday) -> String {
+    let feria = match day {
+        Weekday::Mon => "Féria II",
+        Weekday::Tue => "Féria III",
+        Weekday::Wed => "Féria IV",
+        Weekday::Thu => "Féria V",
+        Weekday::Fri => "Féria VI",
+        Weekday::Sat => "Sábbato",
+        Weekday::Sun => "Domínica",
+    };
+    let n = roman(week as u32);
+    let when = match season {
+        LiturgicalSeason::Advent => format!("infra Hebdómadam {n} Advéntus"),
+        LiturgicalSeason::Christmas => "témporis Nativitátis".into(),
+        LiturgicalSeason::AfterEpiphany => format!("infra Hebdómadam {n} post Epiphaníam"),
+        LiturgicalSeason::Septuagesima => match week {
+            1 => "infra Hebdómadam Septuagésimae".into(),
+            2 => "infra Hebdómadam Sexagésimae".into(),
+            _ => "infra Hebdómadam Quinquagésimae".into(),
+        },
+        LiturgicalSeason::Lent if week == 0 => "post Cíneres".into(),
+        LiturgicalSeason::Lent => format!("infra Hebdómadam {n} Quadragésimae"),
+        LiturgicalSeason::Passiontide => "infra Hebdómadam Passiónis".into(),
+        LiturgicalSeason::HolyWeek => "Hebdómadae Sanctae".into(),
+        LiturgicalSeason::Easter if week <= 1 => "infra Octávam Paschae".into(),
+        LiturgicalSeason::Easter => format!("infra Hebdómadam {} post Pascha", roman(week as u32 - 1)),
+        LiturgicalSeason::Ascensiontide => "post Ascensiónem".into(),
+        LiturgicalSeason::AfterPentecost if week <= 1 => "infra Octávam Pentecóstes".into(),
+        LiturgicalSeason::AfterPentecost => {
+            format!("infra Hebdómadam {} post Pentecósten", roman(week as u32 - 1))
+        }
+    };
+    format!("{feria} {when}")
+}
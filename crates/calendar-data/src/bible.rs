@@ -0,0 +1,206 @@
+//! Verse texts of the Clementine Vulgate and the Douay-Rheims (Challoner).
+//!
+//! The embedded corpus is partial: it holds the passages listed at the top
+//! of `data/vulgate.txt` and grows as texts are checked against printed
+//! editions. A complete text in the same one-verse-per-line format can be
+//! installed at startup with [`load_dir`]; the API does so from the
+//! directory named by `BIBLE_DIR`. Lookups for passages that are not fully
+//! present return `None` rather than a fragment. Both texts follow the
+//! Vulgate numbering of the Psalms.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use calendar_core::scripture::{self, Book, ScriptureRef, Verse};
+
+const VULGATE: &str = include_str!("../data/vulgate.txt");
+const DOUAY: &str = include_str!("../data/douay.txt");
+
+/// Verses in the chapters of the embedded corpus, in the Vulgate
+/// versification shared by both texts
+const CHAPTER_LENGTHS: &[(Book, u16, u16)] = &[
+    (Book::Mark, 16, 20),
+    (Book::Luke, 2, 52),
+    (Book::Luke, 21, 38),
+    (Book::John, 1, 51),
+    (Book::Romans, 13, 14),
+    (Book::Corinthians1, 5, 13),
+    (Book::Titus, 2, 15),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Translation {
+    Vulgate,
+    DouayRheims,
+}
+
+/// One verse of a resolved passage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerseText {
+    pub book: Book,
+    pub verse: Verse,
+    pub text: &'static str,
+}
+
+struct Corpus {
+    verses: HashMap<(Book, u16, u16), &'static str>,
+    /// Last verse of each chapter, where known
+    chapters: HashMap<(Book, u16), u16>,
+}
+
+impl Corpus {
+    /// The embedded partial corpus, whose chapter ends come from
+    /// `CHAPTER_LENGTHS`
+    fn embedded(source: &'static str) -> Corpus {
+        let verses = parse(source).expect("embedded corpus is well formed");
+        let chapters = CHAPTER_LENGTHS.iter().map(|&(b, c, n)| ((b, c), n)).collect();
+        Corpus { verses, chapters }
+    }
+
+    /// A complete text, whose chapters end at their last verse present
+    fn complete(source: &'static str) -> Result<Corpus, String> {
+        let verses = parse(source)?;
+        let mut chapters = HashMap::new();
+        for &(book, c, v) in verses.keys() {
+            let last = chapters.entry((book, c)).or_insert(v);
+            *last = (*last).max(v);
+        }
+        Ok(Corpus { verses, chapters })
+    }
+}
+
+fn parse(source: &'static str) -> Result<HashMap<(Book, u16, u16), &'static str>, String> {
+    source
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+        .map(|line| {
+            let bad = || format!("bad verse line: {}", line);
+            let (reference, text) = line.split_once('\t').ok_or_else(bad)?;
+            let (book, cv) = reference.rsplit_once(' ').ok_or_else(bad)?;
+            let (c, v) = cv.split_once(':').ok_or_else(bad)?;
+            let book = Book::from_name(book).ok_or_else(bad)?;
+            let (c, v) = (c.parse().map_err(|_| bad())?, v.parse().map_err(|_| bad())?);
+            Ok(((book, c, v), text))
+        })
+        .collect()
+}
+
+static VULGATE_CORPUS: OnceLock<Corpus> = OnceLock::new();
+static DOUAY_CORPUS: OnceLock<Corpus> = OnceLock::new();
+
+fn corpus(translation: Translation) -> &'static Corpus {
+    match translation {
+        Translation::Vulgate => VULGATE_CORPUS.get_or_init(|| Corpus::embedded(VULGATE)),
+        Translation::DouayRheims => DOUAY_CORPUS.get_or_init(|| Corpus::embedded(DOUAY)),
+    }
+}
+
+/// Use complete texts from `vulgate.txt` and `douay.txt` in `dir` instead
+/// of the embedded corpus. Must be called before the first lookup.
+pub fn load_dir(dir: impl AsRef<Path>) -> Result<(), String> {
+    let read = |name: &str| -> Result<Corpus, String> {
+        let path = dir.as_ref().join(name);
+        let source = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        // Lives as long as the process, like the embedded texts
+        Corpus::complete(Box::leak(source.into_boxed_str()))
+    };
+    let (vulgate, douay) = (read("vulgate.txt")?, read("douay.txt")?);
+    VULGATE_CORPUS.set(vulgate).map_err(|_| "Bible corpus already in use".to_string())?;
+    DOUAY_CORPUS.set(douay).map_err(|_| "Bible corpus already in use".to_string())?;
+    Ok(())
+}
+
+/// The verses of a reference in order, or `None` unless every verse is in
+/// the corpus
+pub fn verses(reference: &ScriptureRef, translation: Translation) -> Option<Vec<VerseText>> {
+    verses_in(corpus(translation), reference)
+}
+
+fn verses_in(corpus: &Corpus, reference: &ScriptureRef) -> Option<Vec<VerseText>> {
+    let mut out = Vec::new();
+    for seg in &reference.segments {
+        let (first, last) = (seg.start.verse?, seg.end.verse?);
+        let mut chapter = seg.start.chapter;
+        let mut verse = first;
+        loop {
+            let text = corpus.verses.get(&(reference.book, chapter, verse))?;
+            out.push(VerseText {
+                book: reference.book,
+                verse: Verse { chapter, verse: Some(verse) },
+                text,
+            });
+            if (chapter, verse) == (seg.end.chapter, last) {
+                break;
+            }
+            // Run on into the next chapter only past this one's last verse
+            if chapter < seg.end.chapter && corpus.chapters.get(&(reference.book, chapter)) == Some(&verse) {
+                chapter += 1;
+                verse = 1;
+            } else {
+                verse += 1;
+            }
+        }
+    }
+    Some(out)
+}
+
+/// The text of a reference as one paragraph
+pub fn text(reference: &ScriptureRef, translation: Translation) -> Option<String> {
+    let verses = verses(reference, translation)?;
+    Some(verses.iter().map(|v| v.text).collect::<Vec<_>>().join(" "))
+}
+
+/// Resolve a reference string such as "Titus 2:11-15", which may name
+/// several books
+pub fn resolve(reference: &str, translation: Translation) -> Option<String> {
+    let refs = scripture::parse_all(reference).ok()?;
+    let texts = refs.iter().map(|r| text(r, translation)).collect::<Option<Vec<_>>>()?;
+    Some(texts.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corpus_is_parallel() {
+        let vulgate = corpus(Translation::Vulgate);
+        let douay = corpus(Translation::DouayRheims);
+        assert_eq!(vulgate.verses.len(), douay.verses.len());
+        assert!(vulgate.verses.keys().all(|k| douay.verses.contains_key(k)));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let latin = resolve("Titus 2:11-15", Translation::Vulgate).unwrap();
+        assert!(latin.starts_with("Apparuit enim gratia Dei"));
+        assert!(latin.ends_with("Nemo te contemnat."));
+        let english = resolve("1 Cor 5:7-8", Translation::DouayRheims).unwrap();
+        assert!(english.starts_with("Purge out the old leaven"));
+        // Discontinuous segments
+        let r: ScriptureRef = "Luke 2:14, 11".parse().unwrap();
+        let v = verses(&r, Translation::Vulgate).unwrap();
+        assert_eq!(v[1].verse.verse, Some(11));
+    }
+
+    #[test]
+    fn test_missing_passages() {
+        // Partly present: Luke 2:15 is not in the corpus
+        assert!(resolve("Luke 2:1-15", Translation::Vulgate).is_none());
+        assert!(resolve("Gen 1:1", Translation::Vulgate).is_none());
+        assert!(resolve("Victimae paschali laudes", Translation::Vulgate).is_none());
+    }
+
+    #[test]
+    fn test_chapter_boundaries() {
+        let r: ScriptureRef = "Luke 1:78-2:2".parse().unwrap();
+        let whole = Corpus::complete("Luke 1:78\ta\nLuke 1:79\tb\nLuke 1:80\tc\nLuke 2:1\td\nLuke 2:2\te").unwrap();
+        assert_eq!(verses_in(&whole, &r).unwrap().len(), 5);
+        // A missing verse inside a chapter is not skipped over into the next
+        let gap = Corpus::complete("Luke 1:78\ta\nLuke 1:80\tc\nLuke 2:1\td\nLuke 2:2\te").unwrap();
+        assert!(verses_in(&gap, &r).is_none());
+        // The embedded corpus runs past Luke 2:14 only after verse 52
+        assert!(resolve("Luke 2:14-3:1", Translation::Vulgate).is_none());
+    }
+}
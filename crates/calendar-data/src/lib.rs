@@ -1,6 +1,12 @@
-/// Calendar data crate - will hold TOML data files and parsing logic.
-/// Phase 1: data is embedded directly in calendar-core's sanctoral module.
-/// Phase 2+ will move data here as TOML files.
+//! Calendar data crate - will hold TOML data files and parsing logic.
+//! Phase 1: data is embedded directly in calendar-core's sanctoral module.
+//! Phase 2+ will move data here as TOML files.
+//!
+//! Holds the partial Vulgate and Douay-Rheims corpus (`bible`) and the
+//! Roman Martyrology (`martyrology`).
+
+pub mod bible;
+pub mod martyrology;
 
 pub fn version() -> &'static str {
     "0.1.0"
@@ -0,0 +1,196 @@
+//! The Roman Martyrology, read at Prime on the eve of the day it announces.
+//!
+//! Each day opens with the Roman date and the age of the moon, then any
+//! announcement of a moveable feast (or the proclamation of Christmas),
+//! then the elogia of `data/martyrology.txt`, which is partial, and the
+//! closing sentence read every day.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use calendar_core::computus::{lunar_age, moveable_feasts};
+use calendar_core::latin::{self, LatinStyle};
+use calendar_core::latin_date;
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::Serialize;
+
+const MARTYROLOGY: &str = include_str!("../data/martyrology.txt");
+
+/// Read at the end of every day's Martyrology
+pub const CLOSING: &str =
+    "Et álibi aliórum plurimórum sanctórum Mártyrum et Confessórum, atque sanctárum Vírginum.";
+
+/// Proclamation of the Nativity, which opens the Martyrology of Christmas
+/// Day, sung on Christmas Eve
+pub const CHRISTMAS: &str = "Anno a creatióne mundi, quando in princípio Deus creávit caelum et terram, \
+quínquies millésimo centésimo nonagésimo nono; a dilúvio autem, anno bis millésimo nongentésimo \
+quinquagésimo séptimo; a nativitáte Ábrahae, anno bis millésimo quinto décimo; a Móyse et egréssu \
+pópuli Israël de Aegýpto, anno millésimo quingentésimo décimo; ab unctióne David in regem, anno \
+millésimo trigésimo secúndo; hebdómada sexagésima quinta, juxta Daniélis prophetíam; Olympíade \
+centésima nonagésima quarta; ab Urbe Roma cóndita, anno septingentésimo quinquagésimo secúndo; anno \
+Impérii Octaviáni Augústi quadragésimo secúndo, toto orbe in pace compósito, sexta mundi aetáte, \
+Jesus Christus, aetérnus Deus aeterníque Patris Fílius, mundum volens advéntu suo piíssimo \
+consecráre, de Spíritu Sancto concéptus, novémque post conceptiónem decúrsis ménsibus, in Béthlehem \
+Judae náscitur ex María Vírgine factus Homo. Natívitas Dómini nostri Jesu Christi secúndum carnem.";
+
+/// The Martyrology of one day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Martyrology {
+    pub date: NaiveDate,
+    /// Roman date and age of the moon, e.g. "IV Nonas Martii. Luna decima."
+    pub heading: String,
+    /// Moveable feasts and the Christmas proclamation, read before the elogia
+    pub announcements: Vec<String>,
+    pub elogia: Vec<String>,
+    pub closing: String,
+}
+
+type Elogia = HashMap<(u32, u32), Vec<&'static str>>;
+
+fn elogia_table() -> &'static Elogia {
+    static TABLE: OnceLock<Elogia> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = Elogia::new();
+        for line in MARTYROLOGY.lines().filter(|l| !l.trim().is_empty() && !l.starts_with('#')) {
+            let (day, text) = line.split_once('\t').expect("elogium line has a tab");
+            let (m, d) = day.split_once('-').expect("elogium line has MM-DD");
+            table.entry((m.parse().unwrap(), d.parse().unwrap())).or_default().push(text);
+        }
+        table
+    })
+}
+
+/// The elogia read on a date. In a leap year those of the 24th to the 28th
+/// of February move a day later, so the bissextile 24th has none of its own.
+fn elogia(date: NaiveDate) -> &'static [&'static str] {
+    let (month, mut day) = (date.month(), date.day());
+    if month == 2 && date.leap_year() && day >= 24 {
+        if day == 24 {
+            return &[];
+        }
+        day -= 1;
+    }
+    elogia_table().get(&(month, day)).map(Vec::as_slice).unwrap_or(&[])
+}
+
+/// "Luna decima quarta": the age of the moon in words
+pub fn luna(age: u8) -> String {
+    const ORDINALS: [&str; 10] = [
+        "", "prima", "secúnda", "tértia", "quarta", "quinta", "sexta", "séptima", "octáva", "nona",
+    ];
+    let age = age as usize;
+    let words = match age {
+        10 => "décima".to_string(),
+        11 => "undécima".to_string(),
+        12 => "duodécima".to_string(),
+        13..=19 => format!("décima {}", ORDINALS[age - 10]),
+        20 => "vigésima".to_string(),
+        21..=29 => format!("vigésima {}", ORDINALS[age - 20]),
+        30 => "trigésima".to_string(),
+        _ => ORDINALS[age.min(9)].to_string(),
+    };
+    format!("Luna {words}")
+}
+
+/// Announcements of the moveable feasts that fall on a date
+fn announcements(date: NaiveDate) -> Vec<&'static str> {
+    let mf = moveable_feasts(date.year());
+    let trinity = mf.pentecost + Duration::days(7);
+    let mut out = Vec::new();
+    let moveable = [
+        (mf.septuagesima, "Domínica in Septuagésima, qua depónitur cánticum Dómini, Allelúja."),
+        (mf.ash_wednesday, "Féria quarta Cínerum, et inítium sacratíssimi jejúnii quadragesimális."),
+        (mf.palm_sunday, "Domínica in Palmis, quando Dóminus noster Jesus Christus, juxta Zacharíae prophetíam, \
+sedens super pullum ásinae, Jerúsalem ingréssus est, et óbviam ei turbae cum ramis palmárum processérunt."),
+        (mf.holy_thursday, "Féria quinta in Coena Dómini, quando Christus Jesus sacraméntum Córporis et Sánguinis sui instítuit."),
+        (mf.good_friday, "Hierosólymis Pássio sanctíssima Dómini nostri Jesu Christi."),
+        (mf.easter, "Hac die, quam fecit Dóminus, Solémnitas solemnitátum, et Pascha nostrum: \
+Resurréctio Salvatóris nostri Jesu Christi secúndum carnem."),
+        (mf.ascension, "Ascénsio Dómini nostri Jesu Christi."),
+        (mf.pentecost, "Dies Pentecóstes, quando Spíritus Sanctus in linguis ígneis super Apóstolos descéndit."),
+        (trinity, "Festum sanctíssimae Trinitátis."),
+        (mf.corpus_christi, "Festum sanctíssimi Córporis Christi."),
+        (mf.sacred_heart, "Festum sanctíssimi Cordis Jesu."),
+        (mf.christ_the_king, "Festum Dómini nostri Jesu Christi Regis."),
+    ];
+    out.extend(moveable.iter().filter(|(d, _)| *d == date).map(|(_, text)| *text));
+    if (date.month(), date.day()) == (12, 25) {
+        out.push(CHRISTMAS);
+    }
+    out
+}
+
+/// The Martyrology of a date, in a Latin style
+pub fn martyrology(date: NaiveDate, style: LatinStyle) -> Martyrology {
+    let heading = format!(
+        "{}. {}.",
+        latin_date::roman_day(date, style),
+        latin::render(&luna(lunar_age(date)), style)
+    );
+    let render = |text: &&str| latin::render(text, style);
+    Martyrology {
+        date,
+        heading,
+        announcements: announcements(date).iter().map(render).collect(),
+        elogia: elogia(date).iter().map(render).collect(),
+        closing: latin::render(CLOSING, style),
+    }
+}
+
+/// The Martyrology read at Prime on a date: that of the following day
+pub fn read_at_prime(date: NaiveDate, style: LatinStyle) -> Option<Martyrology> {
+    Some(martyrology(date.succ_opt()?, style))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn test_heading_and_elogia() {
+        let m = martyrology(d(2026, 3, 7), LatinStyle::Classical);
+        assert_eq!(m.heading, "Nonis Martii. Luna decima octava.");
+        assert_eq!(m.elogia.len(), 2);
+        assert!(m.elogia[0].starts_with("Apud Fossam Novam"));
+        assert!(m.announcements.is_empty());
+        let ligature = martyrology(d(2026, 3, 7), LatinStyle::Ligature);
+        assert!(ligature.elogia[1].ends_with("Perpetuæ et Felicitatis."));
+    }
+
+    #[test]
+    fn test_hiatus_and_accents() {
+        // Vowels said apart are never joined in a ligature
+        let michael = martyrology(d(2026, 9, 29), LatinStyle::Ligature);
+        assert_eq!(michael.elogia[0], "Dedicatio Basilicæ sancti Michaelis Archangeli.");
+        let accented = martyrology(d(2026, 9, 29), LatinStyle::Accented);
+        assert_eq!(accented.elogia[0], "Dedicátio Basílicæ sancti Michaélis Archángeli.");
+        let christmas = martyrology(d(2026, 12, 25), LatinStyle::Accented);
+        assert!(christmas.announcements[0].contains("pópuli Israël de Ægýpto"));
+        let christmas = martyrology(d(2026, 12, 25), LatinStyle::Ligature);
+        assert!(christmas.announcements[0].contains("populi Israel de Ægypto"));
+    }
+
+    #[test]
+    fn test_moveable_announcements() {
+        // Read on Holy Saturday, before the elogia of the 5th of April
+        let easter = read_at_prime(d(2026, 4, 4), LatinStyle::Classical).unwrap();
+        assert_eq!(easter.date, d(2026, 4, 5));
+        assert!(easter.announcements[0].starts_with("Hac die, quam fecit Dominus"));
+        let christmas = martyrology(d(2026, 12, 25), LatinStyle::Classical);
+        assert_eq!(christmas.heading.split(". ").next(), Some("VIII Kalendas Januarii"));
+        assert!(christmas.announcements[0].ends_with("Nativitas Domini nostri Jesu Christi secundum carnem."));
+    }
+
+    #[test]
+    fn test_leap_february() {
+        let matthias = "In Judaea natalis sancti Matthiae";
+        assert!(martyrology(d(2026, 2, 24), LatinStyle::Classical).elogia[0].starts_with(matthias));
+        assert!(martyrology(d(2028, 2, 24), LatinStyle::Classical).elogia.is_empty());
+        assert!(martyrology(d(2028, 2, 25), LatinStyle::Classical).elogia[0].starts_with(matthias));
+        assert_eq!(luna(14), "Luna décima quarta");
+    }
+}
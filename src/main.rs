@@ -1,7 +1,22 @@
-use tracing_subscriber;
+use calendar_core::designation;
+use calendar_core::OrationRegistry;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    // liturgical-calendar resolve "3rd Sunday of Advent 2027"
+    if args.first().map(String::as_str) == Some("resolve") {
+        let today = chrono::Local::now().date_naive();
+        match designation::resolve(&args[1..].join(" "), today) {
+            Ok(r) => println!("{}\t{}\t{}", r.date, r.id, r.title),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        return Ok(());
+    }
+
     tracing_subscriber::fmt::init();
 
     let port: u16 = std::env::var("PORT")
@@ -9,6 +24,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .and_then(|p| p.parse().ok())
         .unwrap_or(3000);
 
+    // Anniversaries of the Pope and the bishop, and commanded collects
+    let orations = match std::env::var("ORATIONS_FILE") {
+        Ok(path) => calendar_api::load_orations(&path)?,
+        Err(_) => OrationRegistry::default(),
+    };
+
+    // Complete Vulgate and Douay-Rheims texts for /readings
+    if let Ok(dir) = std::env::var("BIBLE_DIR") {
+        calendar_api::load_bible(&dir)?;
+    }
+
     println!("Starting Liturgical Calendar API on port {}...", port);
-    calendar_api::serve(port).await
+    calendar_api::serve(port, orations).await
 }
@@ -9,6 +9,10 @@ fn cal(year: i32) -> Calendar {
     Calendar::new(year)
 }
 
+fn row(c: &Celebration) -> u8 {
+    rubrics::tables(RubricalSystem::Rubrics1962).row(c).row
+}
+
 // ============================================================
 // Easter dates (Computus verification)
 // ============================================================
@@ -130,7 +134,7 @@ fn easter_sunday_2026() {
     assert_eq!(day.celebration.id, "easter-sunday");
     assert_eq!(day.color, LiturgicalColor::White);
     assert_eq!(day.celebration.rank, CelebrationRank::ClassI);
-    assert_eq!(day.celebration.precedence, 1);
+    assert_eq!(row(&day.celebration), 1);
 }
 
 #[test]
@@ -296,7 +300,7 @@ fn christmas_2026() {
     assert_eq!(day.celebration.id, "christmas");
     assert_eq!(day.celebration.rank, CelebrationRank::ClassI);
     assert_eq!(day.color, LiturgicalColor::White);
-    assert_eq!(day.celebration.precedence, 1);
+    assert_eq!(row(&day.celebration), 1);
 }
 
 #[test]
@@ -463,7 +467,7 @@ fn every_sunday_has_sunday_or_feast() {
         if date.weekday() == chrono::Weekday::Sun {
             // Either the celebration is a Sunday, or a feast that outranks it
             let is_sunday = day.celebration.category == CelebrationCategory::Sunday;
-            let is_high_feast = day.celebration.precedence <= 5;
+            let is_high_feast = row(&day.celebration) <= 5;
             assert!(is_sunday || is_high_feast,
                 "Sunday {} has neither Sunday nor high feast: {:?}", date, day.celebration);
         }
@@ -478,6 +482,307 @@ fn easter_never_has_commemorations_from_sanctoral() {
         let day = c.get(easter).unwrap();
         assert_eq!(day.celebration.id, "easter-sunday");
         // No sanctoral commemorations should override Easter
-        assert_eq!(day.celebration.precedence, 1);
+        assert_eq!(row(&day.celebration), 1);
+    }
+}
+
+// ============================================================
+// Temporal readings
+// ============================================================
+
+#[test]
+fn every_temporal_day_has_readings() {
+    for year in 2024..=2030 {
+        let c = cal(year);
+        for (date, day) in c.days() {
+            let temporal = matches!(
+                day.celebration.category,
+                CelebrationCategory::Sunday
+                    | CelebrationCategory::Feria
+                    | CelebrationCategory::EmberDay
+                    | CelebrationCategory::RogationDay
+                    | CelebrationCategory::WithinOctave
+                    // Sancta Maria in Sabbato, which displaces the feria
+                    | CelebrationCategory::Memorial
+            );
+            if temporal {
+                assert!(day.readings.is_some(), "No readings on {} ({})", date, day.celebration.id);
+            }
+        }
+    }
+}
+
+#[test]
+fn trinity_sunday_and_first_sunday_after_pentecost() {
+    let c = cal(2026);
+    // Trinity Sunday 2026 = May 31; its week takes the Mass of the 1st Sunday
+    let trinity = c.get(d(2026, 5, 31)).unwrap();
+    assert_eq!(trinity.celebration.id, "trinity-sunday");
+    let monday = c.get(d(2026, 6, 1)).unwrap();
+    assert_eq!(monday.readings.as_ref().unwrap().gospel_reference(), Some("Luke 6:36-42"));
+}
+
+#[test]
+fn lenten_ferias_have_proper_readings() {
+    let c = cal(2026);
+    // Friday after Laetare Sunday: the raising of Lazarus
+    let day = c.get(d(2026, 3, 20)).unwrap();
+    assert_eq!(day.readings.as_ref().unwrap().gospel_reference(), Some("John 11:1-45"));
+    // Ember Saturday of Lent
+    let day = c.get(d(2026, 2, 28)).unwrap();
+    let readings = day.readings.as_ref().unwrap();
+    assert_eq!(readings.epistle_reference(), Some("1 Thess 5:14-23"));
+    assert_eq!(readings.of_kind(ReadingKind::Lesson).count(), 5);
+}
+
+#[test]
+fn resumed_sundays_after_epiphany() {
+    let c = cal(2026);
+    // 26 Sundays after Pentecost in 2026: the 24th and 25th take the
+    // 5th and 6th Sundays after Epiphany
+    let day = c.get(d(2026, 11, 8)).unwrap();
+    assert_eq!(day.celebration.id, "sunday-after-pentecost-25");
+    assert_eq!(day.readings.as_ref().unwrap().gospel_reference(), Some("Matt 13:24-30"));
+    let day = c.get(d(2026, 11, 15)).unwrap();
+    assert_eq!(day.readings.as_ref().unwrap().gospel_reference(), Some("Matt 13:31-35"));
+}
+
+#[test]
+fn sunday_readings_by_id_match_the_day() {
+    for year in [2026, 2027] {
+        let c = cal(year);
+        for day in c.days().values().filter(|day| day.date.weekday() == chrono::Weekday::Sun) {
+            let id = &day.celebration.id;
+            match readings::get_readings(id) {
+                Some(r) => assert_eq!(Some(&r), day.readings.as_ref(), "{id} on {}", day.date),
+                // Only the resumed Sundays after Epiphany depend on the year
+                None => {
+                    let n = id.strip_prefix("sunday-after-pentecost-").and_then(|n| n.parse::<u8>().ok());
+                    assert!(n.is_some_and(|n| n >= 25), "{id} on {}", day.date);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn ferias_take_mass_of_preceding_sunday() {
+    let c = cal(2026);
+    // Tuesday after the 7th Sunday after Pentecost (Jul 12)
+    let day = c.get(d(2026, 7, 14)).unwrap();
+    assert_eq!(day.celebration.category, CelebrationCategory::Feria);
+    assert_eq!(day.readings.as_ref().unwrap().gospel_reference(), Some("Matt 7:15-21"));
+}
+
+// ---------- Gloria, Credo and Preface ----------
+
+#[test]
+fn ordinary_of_the_mass() {
+    let c = cal(2026);
+    let get = |m, dd| c.get(d(2026, m, dd)).unwrap().ordinary;
+    // Good Friday: no Mass
+    assert!(get(4, 3).is_none());
+    // Christmas: Gloria, Credo, Preface of the Nativity
+    let o = get(12, 25).unwrap();
+    assert_eq!((o.gloria, o.credo, o.preface), (true, true, Preface::Christmas));
+    // Trinity Sunday
+    assert_eq!(get(5, 31).unwrap().preface, Preface::Trinity);
+    // St. Andrew, Apostle: Credo and Preface of the Apostles
+    let o = get(11, 30).unwrap();
+    assert_eq!((o.gloria, o.credo, o.preface), (true, true, Preface::Apostles));
+    // All Souls: Requiem
+    let o = get(11, 2).unwrap();
+    assert_eq!((o.gloria, o.credo, o.preface), (false, false, Preface::Dead));
+    // Within the Octave of Christmas the saints take its Credo and Preface
+    for dd in [26, 27, 28] {
+        let o = get(12, dd).unwrap();
+        assert_eq!((o.credo, o.preface), (true, Preface::Christmas), "Dec {dd}");
+    }
+}
+
+#[test]
+fn apostles_are_found_by_id() {
+    let c = cal(2027);
+    // Conversion of St. Paul (Class III): Preface of the Apostles, no Credo
+    let o = c.get(d(2027, 1, 25)).unwrap().ordinary.unwrap();
+    assert_eq!((o.credo, o.preface), (false, Preface::Apostles));
+    // Chair of St. Peter in Lent
+    let day = c.get(d(2027, 2, 22)).unwrap();
+    assert_eq!(day.season, LiturgicalSeason::Lent);
+    let o = day.ordinary.as_ref().unwrap();
+    assert_eq!((o.credo, o.preface), (true, Preface::Apostles));
+}
+
+#[test]
+fn every_reading_reference_parses() {
+    for year in 2024..=2030 {
+        for day in cal(year).days().values() {
+            for item in day.readings.iter().flat_map(|r| &r.items) {
+                if item.kind == ReadingKind::Sequence {
+                    continue;
+                }
+                assert!(!item.parsed.is_empty(), "{}: {:?} {}", day.date, item.kind, item.reference);
+                assert_eq!(
+                    item.parsed.iter().map(|r| r.to_string()).collect::<Vec<_>>().join("; "),
+                    item.reference,
+                );
+            }
+        }
     }
 }
+
+// ---------- Stations ----------
+
+#[test]
+fn station_days() {
+    let c = cal(2026);
+    assert_eq!(c.get(d(2026, 2, 18)).unwrap().station.as_deref(), Some("S. Sabina"));
+    // A feast on a Lenten feria keeps the station of the day
+    let joseph = c.get(d(2026, 3, 19)).unwrap();
+    assert_eq!(joseph.celebration.id, "st-joseph");
+    assert_eq!(joseph.station.as_deref(), Some("Ss. Silvester et Martinus"));
+    assert!(c.get(d(2026, 8, 4)).unwrap().station.is_none());
+}
+
+// ---------- Localization ----------
+
+#[test]
+fn localized_titles_cover_the_year() {
+    use calendar_core::i18n::{localize, Locale};
+
+    let c = cal(2026);
+    let locales = [Locale::Fr, Locale::De, Locale::Es, Locale::Pt, Locale::It, Locale::Pl];
+    for day in c.days().values() {
+        // A missing translation falls back to English in every locale
+        let untranslated = locales.iter().all(|&locale| {
+            localize(day, locale).celebration.title_vernacular == day.celebration.title_vernacular
+        });
+        assert!(!untranslated, "{} {}", day.date, day.celebration.id);
+    }
+    let pentecost = localize(c.get(d(2026, 5, 24)).unwrap(), Locale::Pl);
+    assert_eq!(pentecost.celebration.title_vernacular.as_deref(), Some("Zesłanie Ducha Świętego"));
+    assert_eq!(pentecost.celebration.title, "Dominica Pentecostes");
+}
+
+// ---------- Latin orthography ----------
+
+#[test]
+fn latin_styles_from_one_canonical_form() {
+    let with = |latin| Calendar::with_options(2026, CalendarOptions { latin, ..Default::default() });
+    let date = d(2026, 3, 4);
+    let titles: Vec<String> = [LatinStyle::Classical, LatinStyle::Ligature, LatinStyle::Accented]
+        .into_iter()
+        .map(|style| with(style).get(date).unwrap().celebration.title.clone())
+        .collect();
+    assert_eq!(titles, [
+        "Feria IV infra Hebdomadam II Quadragesimae",
+        "Feria IV infra Hebdomadam II Quadragesimæ",
+        "Féria IV infra Hebdómadam II Quadragésimæ",
+    ]);
+
+    let accented = with(LatinStyle::Accented);
+    let aquinas = accented.get(d(2026, 1, 28)).unwrap();
+    assert_eq!(aquinas.celebration.title, "S. Thomæ de Aquíno");
+    let texts = propers::mass_texts_in(aquinas, LatinStyle::Accented).unwrap();
+    assert!(texts.propers.introit.unwrap().latin.starts_with("In médio Ecclésiæ"));
+    // The default calendar keeps the classical spelling
+    assert_eq!(cal(2026).get(d(2026, 1, 28)).unwrap().celebration.title, "S. Thomae de Aquino");
+}
+
+#[test]
+fn roman_date_in_latin_output() {
+    let c = cal(2026);
+    let day = c.get(d(2026, 1, 31)).unwrap();
+    assert_eq!(day.date_latin, None);
+    let latin = i18n::localize(day, Locale::La);
+    assert_eq!(latin.date_latin.as_deref(), Some("Sabbato, Pridie Kalendas Februarii, Anno Domini MMXXVI"));
+    assert_eq!(i18n::localize(day, Locale::Fr).date_latin, None);
+    let easter = i18n::localize_in(c.get(d(2026, 4, 5)).unwrap(), Locale::La, LatinStyle::Accented);
+    assert_eq!(easter.date_latin.as_deref(), Some("Domínica, Nonis Aprílis, Anno Dómini MMXXVI"));
+}
+
+#[test]
+fn computus_tables_for_the_year() {
+    let c = cal(2026).computus();
+    assert_eq!((c.golden_number, c.epact_label.as_str(), c.dominical_letters.as_str()), (13, "xi", "D"));
+    assert_eq!((c.solar_cycle, c.indiction), (19, 4));
+    assert_eq!(c.easter, cal(2026).moveable_feasts().easter);
+    assert_eq!(cal(2028).computus().dominical_letters, "BA");
+}
+
+#[test]
+fn julian_reckoning_before_the_reform() {
+    // Roman and Orthodox Easter a week apart in 2026
+    assert_eq!(easter(2026), d(2026, 4, 5));
+    assert_eq!(julian::orthodox_easter(2026), d(2026, 4, 12));
+    // Easter 1500 was kept on the 19th of April (Julian), not the Gregorian date
+    let kept = julian::historical_easter(1500);
+    assert_eq!(julian::JulianDate::from_gregorian(kept).to_string(), "1500-04-19");
+    assert_ne!(kept, easter(1500));
+    assert_eq!(julian::historical_easter(1583), easter(1583));
+}
+
+#[test]
+fn designations_resolve_to_dates() {
+    let today = d(2026, 10, 18);
+    let at = |text: &str| designation::resolve(text, today).unwrap().date;
+    assert_eq!(at("Dominica III Adventus 2027"), d(2027, 12, 12));
+    assert_eq!(at("Ember Saturday of September 2030"), d(2030, 9, 21));
+    assert_eq!(at("5th Sunday after Pentecost next year"), d(2027, 6, 20));
+    // Holy Week impedes St. Joseph in 2035; he is kept after Low Sunday,
+    // the day after the Annunciation
+    assert_eq!(at("Feast of St. Joseph 2035 (as transferred)"), d(2035, 4, 3));
+    assert_eq!(at("st-joseph 2029"), d(2029, 3, 19));
+}
+
+#[test]
+fn st_joseph_in_holy_week() {
+    let options = CalendarOptions::default();
+    let years: Vec<i32> = occurrences::occurrences("st-joseph", 2024, 2060, &options)
+        .into_iter()
+        .filter(|o| o.season == LiturgicalSeason::HolyWeek)
+        .inspect(|o| assert_eq!(o.outcome, Outcome::Transferred))
+        .map(|o| o.year)
+        .collect();
+    // Easter no later than the 26th of March
+    assert_eq!(years, [2035, 2046]);
+}
+
+#[test]
+fn season_ranges_2027() {
+    let cal = Calendar::new(2027);
+    let civil = cal.seasons();
+    // Civil and liturgical lists each cover every day without gaps
+    for ranges in [&civil, &cal.liturgical_seasons()] {
+        for pair in ranges.windows(2) {
+            assert_eq!(pair[0].end.succ_opt(), Some(pair[1].start));
+            assert_ne!(pair[0].season, pair[1].season);
+        }
+    }
+    assert_eq!((civil[0].start, civil.last().unwrap().end), (d(2027, 1, 1), d(2027, 12, 31)));
+    let easter = civil.iter().find(|r| r.season == LiturgicalSeason::Easter).unwrap();
+    // Easter Day on the 28th of March
+    assert_eq!(easter.start, d(2027, 3, 28));
+    let advent = civil.iter().find(|r| r.season == LiturgicalSeason::Advent).unwrap();
+    assert_eq!((advent.start, advent.end, advent.weeks), (d(2027, 11, 28), d(2027, 12, 24), 4));
+}
+
+#[test]
+fn st_joseph_on_third_sunday_of_lent() {
+    // 2028: the 19th of March is the Third Sunday of Lent, of Class I
+    let cal = Calendar::new(2028);
+    let sunday = cal.get(d(2028, 3, 19)).unwrap();
+    assert_eq!(sunday.celebration.id, "sunday-lent-3");
+    let joseph = sunday.trace.as_ref().unwrap().candidates.iter().find(|c| c.id == "st-joseph").unwrap();
+    assert_eq!(joseph.outcome, Outcome::Transferred);
+    assert_eq!(cal.get(d(2028, 3, 20)).unwrap().celebration.id, "st-joseph");
+}
+
+#[test]
+fn immaculate_conception_on_second_sunday_of_advent() {
+    // 2024: the 8th of December is the Second Sunday of Advent
+    let day = Calendar::new(2024).get(d(2024, 12, 8)).cloned().unwrap();
+    assert_eq!(day.celebration.id, "immaculate-conception");
+    assert_eq!(day.commemorations[0].id, "sunday-advent-2");
+    assert_eq!(day.trace.unwrap().candidates[0].citation, "RG 15");
+}